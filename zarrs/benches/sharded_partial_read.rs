@@ -1,4 +1,4 @@
-//! Benchmark sharded array reads: full vs partial shard reads.
+//! Benchmark sharded array reads: full vs partial shard reads, and 3D region reads for each subchunk write order.
 //!
 //! Array data is written to `benches/data/sharded_partial_read/` on the first run and reused
 //! on subsequent runs. Delete that directory to regenerate the data.
@@ -11,7 +11,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
-use zarrs::array::ArraySubset;
+use zarrs::array::codec::{ShardingCodecOptions, SubchunkWriteOrder};
+use zarrs::array::{ArraySubset, CodecSpecificOptions};
 use zarrs::filesystem::FilesystemStore;
 use zarrs::storage::store::MemoryStore;
 use zarrs::storage::{ReadableStorage, ReadableWritableListableStorage};
@@ -28,6 +29,10 @@ const CHUNK_SHAPE: [u64; 4] = [1, 1, 128, 128];
 const SHARD_ELEMENTS: u64 = SHARD_SHAPE[0] * SHARD_SHAPE[1] * SHARD_SHAPE[2] * SHARD_SHAPE[3];
 const SHARD_BYTES: u64 = SHARD_ELEMENTS * 8;
 
+// 3D array for comparing subchunk write orders: one shard of 16x16x16 subchunks
+const REGION_SHAPE: [u64; 3] = [256, 256, 256];
+const REGION_SUBCHUNK_SHAPE: [u64; 3] = [16, 16, 16];
+
 fn data_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("benches")
@@ -157,10 +162,96 @@ fn bench_read_partial_unaligned(c: &mut Criterion) {
     group.finish();
 }
 
+fn subchunk_write_order_name(order: SubchunkWriteOrder) -> &'static str {
+    match order {
+        SubchunkWriteOrder::C => "c",
+        SubchunkWriteOrder::Morton => "morton",
+        SubchunkWriteOrder::Hilbert => "hilbert",
+        _ => "unordered",
+    }
+}
+
+/// Returns a store loaded with a 3D array with subchunks in `order`, writing it first if needed.
+fn open_store_subchunk_write_order(order: SubchunkWriteOrder) -> ReadableStorage {
+    let populate = |store: ReadableWritableListableStorage| {
+        let array = zarrs::array::ArrayBuilder::new(
+            REGION_SHAPE.to_vec(),
+            REGION_SHAPE.to_vec(),
+            zarrs::array::data_type::float64(),
+            0.0f64,
+        )
+        .subchunk_shape(REGION_SUBCHUNK_SHAPE.to_vec())
+        .codec_specific_options(
+            CodecSpecificOptions::default()
+                .with_option(ShardingCodecOptions::default().with_subchunk_write_order(order)),
+        )
+        .build(store, "/")
+        .unwrap();
+        array.store_metadata().unwrap();
+        let data = random_data(REGION_SHAPE.iter().product::<u64>() as usize);
+        array
+            .store_array_subset(
+                &ArraySubset::new_with_shape(REGION_SHAPE.to_vec()),
+                data.as_slice(),
+            )
+            .unwrap();
+    };
+
+    if USE_MEMORY_STORE {
+        let store: ReadableWritableListableStorage = Arc::new(MemoryStore::new());
+        populate(Arc::clone(&store));
+        store
+    } else {
+        let path = data_path()
+            .join("subchunk_write_order")
+            .join(subchunk_write_order_name(order));
+        if !path.join("zarr.json").exists() {
+            std::fs::create_dir_all(&path).unwrap();
+            populate(Arc::new(FilesystemStore::new(&path).unwrap()));
+        }
+        Arc::new(FilesystemStore::new(path).unwrap())
+    }
+}
+
+/// 3D region reads within a shard for each subchunk write order.
+///
+/// Curve orders place the subchunks of a compact region closer together within the shard.
+/// Each subchunk is still read with its own byte range, so any difference comes from the locality of those reads.
+fn bench_read_region_subchunk_write_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sharded_partial_read_region");
+    for order in [
+        SubchunkWriteOrder::C,
+        SubchunkWriteOrder::Morton,
+        SubchunkWriteOrder::Hilbert,
+    ] {
+        let array = zarrs::array::Array::open(open_store_subchunk_write_order(order), "/").unwrap();
+        for region_size in [32u64, 64, 128] {
+            let start = 64;
+            let region = [
+                start..start + region_size,
+                start..start + region_size,
+                start..start + region_size,
+            ];
+            group.throughput(Throughput::Bytes(region_size.pow(3) * 8));
+            group.bench_function(
+                format!("{}/{region_size}", subchunk_write_order_name(order)),
+                |b| {
+                    b.iter(|| {
+                        let _: zarrs::array::ArrayBytes =
+                            array.retrieve_array_subset(&region).unwrap();
+                    });
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_read_full,
     bench_read_partial_aligned,
     bench_read_partial_unaligned,
+    bench_read_region_subchunk_write_order,
 );
 criterion_main!(benches);
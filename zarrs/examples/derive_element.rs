@@ -0,0 +1,105 @@
+//! Mapping Rust structs to `struct` array elements with `#[derive(Element)]`.
+//!
+//! The data type of the array is derived from the struct definition.
+//! Nested structs are mapped to nested `struct` fields and fixed size arrays are mapped to subarray fields.
+//! The bytes codec serialises each field in the requested endianness.
+
+use std::sync::Arc;
+
+use num::complex::Complex32;
+use zarrs::array::codec::BytesCodec;
+use zarrs::array::{ArrayBuilder, ArraySubset, Element, ElementStructField, FillValue};
+use zarrs::metadata::Endianness;
+use zarrs::storage::ReadableStorageTraits;
+use zarrs::storage::store::MemoryStore;
+
+#[derive(Element, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Element, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Particle {
+    id: u16,
+    position: Position,
+    velocity: [f64; 3],
+    active: bool,
+    #[zarrs(rename = "phase")]
+    wave: Complex32,
+}
+
+fn main() {
+    let store = Arc::new(MemoryStore::default());
+    let array_path = "/array";
+    let data_type = Particle::data_type();
+    let fill_value = Particle {
+        id: 0,
+        position: Position { x: 0.0, y: 0.0 },
+        velocity: [0.0; 3],
+        active: false,
+        wave: Complex32::new(0.0, 0.0),
+    };
+    let fill_value = FillValue::new(
+        Particle::to_array_bytes(&data_type, &[fill_value])
+            .unwrap()
+            .into_fixed()
+            .unwrap()
+            .into_owned(),
+    );
+    let array = ArrayBuilder::new(
+        vec![4], // array shape
+        vec![2], // regular chunk shape
+        data_type,
+        fill_value,
+    )
+    .array_to_bytes_codec(Arc::new(BytesCodec::new(Some(Endianness::Big))))
+    .build(store.clone(), array_path)
+    .unwrap();
+    println!("{}", array.metadata().to_string_pretty());
+
+    let data = [
+        Particle {
+            id: 1,
+            position: Position { x: 1.0, y: -1.0 },
+            velocity: [0.5, 1.5, 2.5],
+            active: true,
+            wave: Complex32::new(1.0, 2.0),
+        },
+        Particle {
+            id: 2,
+            position: Position { x: 2.0, y: -2.0 },
+            velocity: [3.5, 4.5, 5.5],
+            active: false,
+            wave: Complex32::new(3.0, 4.0),
+        },
+    ];
+    array.store_chunk(&[0], &data).unwrap();
+
+    // The encoded chunk is packed and big endian
+    let encoded = store.get(&array.chunk_key(&[0])).unwrap().unwrap().to_vec();
+    assert_eq!(encoded.len(), 2 * Particle::SIZE);
+    assert_eq!(&encoded[..2], &1u16.to_be_bytes());
+    assert_eq!(&encoded[2..6], &1.0f32.to_be_bytes());
+
+    let retrieved: Vec<Particle> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+    assert_eq!(&retrieved[..2], &data);
+    assert_eq!(retrieved[2].id, 0);
+    println!("{retrieved:#?}");
+
+    // Individual fields can be retrieved as arrays
+    let velocity: Vec<f64> = array
+        .retrieve_array_subset_field(
+            &ArraySubset::new_with_start_shape(vec![0], vec![2]).unwrap(),
+            "velocity",
+        )
+        .unwrap();
+    assert_eq!(velocity, [0.5, 1.5, 2.5, 3.5, 4.5, 5.5]);
+}
+
+#[test]
+fn derive_element() {
+    main()
+}
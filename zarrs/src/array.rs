@@ -23,9 +23,15 @@
 
 mod array_bytes_internal;
 mod array_cached;
+mod array_codec_tuner;
 mod array_errors;
+mod array_fragmentation;
 mod array_metadata_options;
 mod array_ops;
+mod array_shard_writer;
+mod array_struct_field;
+#[cfg(feature = "zstd")]
+mod array_zstd_dictionary;
 mod element;
 mod from_array_bytes;
 mod into_array_bytes;
@@ -40,6 +46,8 @@ pub mod concurrency;
 pub mod data_type;
 pub mod storage_transformer;
 
+#[cfg(feature = "arrow")]
+mod array_arrow_ext;
 #[cfg(feature = "dlpack")]
 mod array_dlpack_ext;
 
@@ -47,11 +55,18 @@ use std::borrow::Cow;
 use std::sync::Arc;
 
 pub use self::array_cached::ArrayCached;
+pub use self::array_codec_tuner::{
+    CodecChainCandidateReport, CodecChainTuner, CodecChainTuningConstraint, CodecChainTuningReport,
+};
+pub use self::array_fragmentation::{ArrayFragmentationReport, ChunkFragmentation};
+pub use self::array_shard_writer::{ArrayShardWriter, ArrayShardWriterOptions};
 pub use self::builder::ArrayBuilder;
 use self::chunk_grid::RegularBoundedChunkGridConfiguration;
 use self::chunk_key_encoding::V2ChunkKeyEncoding;
 use crate::config::{MetadataEraseVersion, global_config};
-use crate::convert::{ArrayMetadataV2ToV3Error, array_metadata_v2_to_v3};
+use crate::convert::{
+    ArrayMetadataV2ToV3Error, array_metadata_v2_to_v3, list_data_type_metadata_v2_to_v3,
+};
 use crate::node::NodePath;
 pub use zarrs_chunk_grid::{
     ArrayIndices, ArrayIndicesTinyVec, ArrayShape, ArraySubset, ArraySubsetError,
@@ -100,11 +115,18 @@ pub use self::array_ops::{ArrayMutOps, ArrayOps, ArrayReadOps, ArrayUpdateOps, A
 pub use self::array_ops::{AsyncArrayReadOps, AsyncArrayUpdateOps, AsyncArrayWriteOps};
 use self::chunk_grid::RegularChunkGrid;
 pub use self::codec::{CodecChain, CodecChainBound};
-pub use self::element::{Element, ElementError, ElementOwned};
+pub use self::element::{Element, ElementError, ElementOwned, ElementStructField, List};
+#[doc(hidden)]
+pub use self::element::{
+    struct_element_from_array_bytes, struct_element_to_array_bytes,
+    struct_element_validate_data_type,
+};
 pub use self::from_array_bytes::FromArrayBytes;
 pub use self::into_array_bytes::IntoArrayBytes;
 pub use self::storage_transformer::{StorageTransformerChain, StorageTransformerTraits};
 pub use self::tensor::{Tensor, TensorError};
+#[cfg(feature = "derive")]
+pub use zarrs_derive::Element;
 
 /// Convert a [`ChunkShape`] reference to an [`ArrayShape`].
 #[must_use]
@@ -610,8 +632,15 @@ impl<TStorage: ?Sized> Array<TStorage> {
         use zarrs_metadata::v2::data_type_metadata_v2_to_endianness;
 
         // Create data type from V2 metadata directly using the plugin system
-        let data_type =
-            DataType::from_metadata(&v2.dtype).map_err(ArrayCreateError::DataTypeCreateError)?;
+        // Object arrays with a `vlen-array` filter are lists of the filter `dtype`
+        let list_data_type = list_data_type_metadata_v2_to_v3(&v2.dtype, v2.filters.as_deref())
+            .map_err(|e| ArrayCreateError::UnsupportedZarrV2Array(e.to_string()))?;
+        let data_type = if let Some(list_data_type) = &list_data_type {
+            DataType::from_metadata(list_data_type)
+        } else {
+            DataType::from_metadata(&v2.dtype)
+        }
+        .map_err(ArrayCreateError::DataTypeCreateError)?;
 
         // Create chunk grid from V2 chunks
         let chunk_grid = ChunkGrid::new(
@@ -803,6 +832,33 @@ impl<TStorage: ?Sized> Array<TStorage> {
             .expect("data type and fill value are compatible")
     }
 
+    /// Return the sharding codec of the array if it is not followed by bytes-to-bytes codecs, so shards are stored as is.
+    pub(in crate::array) fn sharding_codec_without_bytes_to_bytes_codecs(
+        &self,
+    ) -> Option<&codec::ShardingCodecBound> {
+        if self.codecs_bound.bytes_to_bytes_codecs().is_empty() {
+            self.codecs_bound
+                .array_to_bytes_codec()
+                .as_any()
+                .downcast_ref::<codec::ShardingCodecBound>()
+        } else {
+            None
+        }
+    }
+
+    /// Invalidate the cached shard index of a chunk if the array uses a sharding codec with a [`ShardIndexCache`](codec::ShardIndexCache).
+    pub(in crate::array) fn invalidate_shard_index_cache(&self, chunk_indices: &[u64]) {
+        if let Some(shard_index_cache) = self
+            .codecs_bound
+            .array_to_bytes_codec()
+            .as_any()
+            .downcast_ref::<codec::ShardingCodecBound>()
+            .and_then(|sharding_codec| sharding_codec.options().shard_index_cache())
+        {
+            shard_index_cache.invalidate(&self.chunk_key(chunk_indices));
+        }
+    }
+
     /// Convert the array to Zarr V3.
     ///
     /// # Errors
@@ -1392,6 +1448,365 @@ mod tests {
         }
     }
 
+    #[test]
+    fn array_v2_structured() {
+        use zarrs_metadata::v2::ArrayMetadataV2;
+        use zarrs_storage::ReadableStorageTraits;
+
+        let metadata: ArrayMetadataV2 = serde_json::from_str(
+            r#"{
+                "zarr_format": 2,
+                "shape": [4],
+                "chunks": [3],
+                "dtype": [["x", ">u2"], ["y", ">f4", [2]]],
+                "fill_value": null,
+                "order": "C",
+                "compressor": null,
+                "filters": null
+            }"#,
+        )
+        .unwrap();
+        let store = Arc::new(MemoryStore::new());
+        let array =
+            Array::new_with_metadata(store.clone(), "/", ArrayMetadata::V2(metadata)).unwrap();
+        assert!(array.data_type().is::<data_type::StructDataType>());
+        assert_eq!(array.fill_value().as_ne_bytes(), [0; 10]);
+        array.store_metadata().unwrap();
+
+        let elements: Vec<u8> = (0..4u16)
+            .flat_map(|i| {
+                let mut element = i.to_ne_bytes().to_vec();
+                element.extend_from_slice(&f32::from(i).to_ne_bytes());
+                element.extend_from_slice(&(-f32::from(i)).to_ne_bytes());
+                element
+            })
+            .collect();
+        array
+            .store_array_subset(
+                &ArraySubset::new_with_shape(vec![4]),
+                ArrayBytes::from(elements),
+            )
+            .unwrap();
+
+        // The chunks are encoded big endian
+        let chunk = store.get(&"0".try_into().unwrap()).unwrap().unwrap();
+        assert_eq!(&chunk[..10], [0, 0, 0, 0, 0, 0, 128, 0, 0, 0]);
+
+        let array = Array::open(store, "/").unwrap();
+        let subset = ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap();
+        let x: Vec<u16> = array.retrieve_array_subset_field(&subset, "x").unwrap();
+        assert_eq!(x, [1, 2]);
+        let y: Vec<f32> = array.retrieve_array_subset_field(&subset, "y").unwrap();
+        assert_eq!(y, [1.0, -1.0, 2.0, -2.0]);
+        assert!(
+            array
+                .retrieve_array_subset_field::<Vec<u16>>(&subset, "z")
+                .is_err()
+        );
+
+        // The structured dtype round trips and converts to a V3 struct data type
+        let ArrayMetadata::V2(metadata) = array.metadata() else {
+            panic!("expected V2 metadata");
+        };
+        assert_eq!(
+            serde_json::to_string(&metadata.dtype).unwrap(),
+            r#"[["x",">u2"],["y",">f4",[2]]]"#
+        );
+        let array_v3 = array.to_v3().unwrap();
+        let ArrayMetadata::V3(metadata) = array_v3.metadata() else {
+            panic!("expected V3 metadata");
+        };
+        assert_eq!(
+            serde_json::to_string(&metadata.data_type).unwrap(),
+            r#"{"name":"struct","configuration":{"fields":[{"name":"x","data_type":"uint16"},{"name":"y","data_type":"float32","shape":[2]}]}}"#
+        );
+        assert_eq!(
+            metadata.fill_value,
+            serde_json::from_str::<FillValueMetadata>(r#"{"x":0,"y":[0.0,0.0]}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn array_v2_null_terminated_bytes() {
+        use zarrs_metadata::v2::ArrayMetadataV2;
+        use zarrs_storage::ReadableStorageTraits;
+
+        let metadata: ArrayMetadataV2 = serde_json::from_str(
+            r#"{
+                "zarr_format": 2,
+                "shape": [4],
+                "chunks": [4],
+                "dtype": "|S4",
+                "fill_value": "eg==",
+                "order": "C",
+                "compressor": null,
+                "filters": null
+            }"#,
+        )
+        .unwrap();
+        let store = Arc::new(MemoryStore::new());
+        let array =
+            Array::new_with_metadata(store.clone(), "/", ArrayMetadata::V2(metadata)).unwrap();
+        assert!(
+            array
+                .data_type()
+                .is::<data_type::NullTerminatedBytesDataType>()
+        );
+        assert_eq!(array.fill_value().as_ne_bytes(), b"z\0\0\0");
+        array.store_metadata().unwrap();
+
+        let subset = ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap();
+        array.store_array_subset(&subset, &["ab", "abcd"]).unwrap();
+        let chunk = store.get(&"0".try_into().unwrap()).unwrap().unwrap();
+        assert_eq!(&chunk[..], b"z\0\0\0ab\0\0abcdz\0\0\0");
+
+        let array = Array::open(store, "/").unwrap();
+        let strings: Vec<String> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(strings, ["z", "ab", "abcd", "z"]);
+        let bytes: Vec<[u8; 4]> = array.retrieve_array_subset(&subset).unwrap();
+        assert_eq!(bytes, [*b"ab\0\0", *b"abcd"]);
+
+        let array_v3 = array.to_v3().unwrap();
+        let ArrayMetadata::V3(metadata) = array_v3.metadata() else {
+            panic!("expected V3 metadata");
+        };
+        assert_eq!(
+            serde_json::to_string(&metadata.data_type).unwrap(),
+            r#"{"name":"null_terminated_bytes","configuration":{"length_bytes":4}}"#
+        );
+        assert_eq!(metadata.fill_value, FillValueMetadata::from("eg=="));
+    }
+
+    #[test]
+    fn array_categorical_sharded() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(
+            vec![8],
+            vec![8],
+            data_type::categorical(vec!["none".into(), "low".into(), "high".into()]).unwrap(),
+            "none",
+        )
+        .subchunk_shape(vec![2])
+        .build(store.clone(), "/")
+        .unwrap();
+        array.store_metadata().unwrap();
+
+        let subset = ArraySubset::new_with_start_shape(vec![2], vec![4]).unwrap();
+        array
+            .store_array_subset(&subset, &["low", "high", "high", "low"])
+            .unwrap();
+
+        let array = Array::open(store, "/").unwrap();
+        let strings: Vec<String> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(
+            strings,
+            ["none", "none", "low", "high", "high", "low", "none", "none"]
+        );
+        let subset = ArraySubset::new_with_start_shape(vec![3], vec![2]).unwrap();
+        let strings: Vec<String> = array.retrieve_array_subset(&subset).unwrap();
+        assert_eq!(strings, ["high", "high"]);
+        let codes: Vec<u8> = array.retrieve_array_subset(&subset).unwrap();
+        assert_eq!(codes, [2, 2]);
+        assert!(
+            array
+                .store_array_subset(&subset, &["medium", "low"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn array_list() {
+        use self::codec::VlenArrayCodec;
+
+        for vlen_array in [false, true] {
+            let store = Arc::new(MemoryStore::new());
+            let mut builder = ArrayBuilder::new(
+                vec![6],
+                vec![4],
+                data_type::list(data_type::float32()).unwrap(),
+                FillValueMetadata::Array(vec![]),
+            );
+            if vlen_array {
+                builder.array_to_bytes_codec(Arc::new(VlenArrayCodec::new()));
+            }
+            let array = builder.build(store.clone(), "/").unwrap();
+            array.store_metadata().unwrap();
+
+            let subset = ArraySubset::new_with_start_shape(vec![2], vec![3]).unwrap();
+            array
+                .store_array_subset(
+                    &subset,
+                    &[List(vec![1.0f32, 2.0]), List(vec![]), List(vec![3.0])],
+                )
+                .unwrap();
+
+            let array = Array::open(store, "/").unwrap();
+            let lists: Vec<List<f32>> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+            assert_eq!(
+                lists.into_iter().map(List::into_inner).collect::<Vec<_>>(),
+                [vec![], vec![], vec![1.0, 2.0], vec![], vec![3.0], vec![]]
+            );
+            assert!(
+                array
+                    .retrieve_array_subset::<Vec<List<f64>>>(&subset)
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn array_list_vlen_array_dtype() {
+        let array_metadata = |dtype: &str| -> ArrayMetadata {
+            let metadata: ArrayMetadataV3 = serde_json::from_str(&format!(
+                r#"{{
+                    "zarr_format": 3,
+                    "node_type": "array",
+                    "shape": [3],
+                    "data_type": {{"name": "zarrs.list", "configuration": {{"data_type": "int8"}}}},
+                    "chunk_grid": {{"name": "regular", "configuration": {{"chunk_shape": [3]}}}},
+                    "chunk_key_encoding": {{"name": "default"}},
+                    "fill_value": [],
+                    "codecs": [{{"name": "vlen-array", "configuration": {{"dtype": "{dtype}"}}}}]
+                }}"#
+            ))
+            .unwrap();
+            ArrayMetadata::V3(metadata)
+        };
+
+        let store = Arc::new(MemoryStore::new());
+        let array = Array::new_with_metadata(store.clone(), "/", array_metadata("|i1")).unwrap();
+        assert_eq!(
+            serde_json::to_string(
+                &array
+                    .codecs()
+                    .create_metadatas(&zarrs_codec::CodecMetadataOptions::default())
+            )
+            .unwrap(),
+            r#"[{"name":"vlen-array","configuration":{"dtype":"|i1"}}]"#
+        );
+        assert!(Array::new_with_metadata(store.clone(), "/", array_metadata("|u1")).is_err());
+        assert!(Array::new_with_metadata(store, "/", array_metadata("<f8")).is_err());
+    }
+
+    #[test]
+    fn array_v2_vlen_array() {
+        use zarrs_metadata::v2::ArrayMetadataV2;
+        use zarrs_storage::{ReadableStorageTraits, WritableStorageTraits};
+
+        let metadata: ArrayMetadataV2 = serde_json::from_str(
+            r#"{
+                "zarr_format": 2,
+                "shape": [3],
+                "chunks": [3],
+                "dtype": "|O",
+                "fill_value": null,
+                "order": "C",
+                "compressor": null,
+                "filters": [{"id": "vlen-array", "dtype": "<i2"}]
+            }"#,
+        )
+        .unwrap();
+        let store = Arc::new(MemoryStore::new());
+        let array =
+            Array::new_with_metadata(store.clone(), "/", ArrayMetadata::V2(metadata)).unwrap();
+        assert_eq!(
+            array.data_type(),
+            &data_type::list(data_type::int16()).unwrap()
+        );
+        assert!(array.fill_value().as_ne_bytes().is_empty());
+        array.store_metadata().unwrap();
+
+        // A chunk encoded by `numcodecs.VLenArray("<i2")`
+        let chunk: Vec<u8> = [
+            &3u32.to_le_bytes()[..],
+            &4u32.to_le_bytes(),
+            &1i16.to_le_bytes(),
+            &(-2i16).to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &2u32.to_le_bytes(),
+            &3i16.to_le_bytes(),
+        ]
+        .concat();
+        store
+            .set(&"0".try_into().unwrap(), chunk.clone().into())
+            .unwrap();
+
+        let array = Array::open(store.clone(), "/").unwrap();
+        let lists: Vec<List<i16>> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(lists, [List(vec![1, -2]), List(vec![]), List(vec![3])]);
+
+        array
+            .store_array_subset(&array.subset_all(), &lists)
+            .unwrap();
+        let stored = store.get(&"0".try_into().unwrap()).unwrap().unwrap();
+        assert_eq!(&stored[..], &chunk[..]);
+
+        let array_v3 = array.to_v3().unwrap();
+        let ArrayMetadata::V3(metadata) = array_v3.metadata() else {
+            panic!("expected V3 metadata");
+        };
+        assert_eq!(
+            serde_json::to_string(&metadata.data_type).unwrap(),
+            r#"{"name":"zarrs.list","configuration":{"data_type":"int16"}}"#
+        );
+        assert_eq!(metadata.fill_value, FillValueMetadata::Array(vec![]));
+        assert_eq!(
+            serde_json::to_string(&metadata.codecs).unwrap(),
+            r#"[{"name":"vlen-array","configuration":{"dtype":"<i2"}}]"#
+        );
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn array_v2_vlen_array_big_endian() {
+        use zarrs_metadata::v2::ArrayMetadataV2;
+        use zarrs_storage::{ReadableStorageTraits, WritableStorageTraits};
+
+        let metadata: ArrayMetadataV2 = serde_json::from_str(
+            r#"{
+                "zarr_format": 2,
+                "shape": [2],
+                "chunks": [2],
+                "dtype": "|O",
+                "fill_value": null,
+                "order": "C",
+                "compressor": null,
+                "filters": [{"id": "vlen-array", "dtype": ">i2"}]
+            }"#,
+        )
+        .unwrap();
+        let store = Arc::new(MemoryStore::new());
+        let array =
+            Array::new_with_metadata(store.clone(), "/", ArrayMetadata::V2(metadata)).unwrap();
+
+        // A chunk encoded by `numcodecs.VLenArray(">i2")`, with big-endian items
+        let chunk: Vec<u8> = [
+            &2u32.to_le_bytes()[..],
+            &4u32.to_le_bytes(),
+            &258i16.to_be_bytes(),
+            &(-2i16).to_be_bytes(),
+            &2u32.to_le_bytes(),
+            &3i16.to_be_bytes(),
+        ]
+        .concat();
+        store
+            .set(&"0".try_into().unwrap(), chunk.clone().into())
+            .unwrap();
+
+        let lists: Vec<List<i16>> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(lists, [List(vec![258, -2]), List(vec![3])]);
+        let lists_partial: Vec<List<i16>> = array
+            .retrieve_array_subset(&ArraySubset::new_with_ranges(&[1..2]))
+            .unwrap();
+        assert_eq!(lists_partial, [List(vec![3])]);
+
+        array
+            .store_array_subset(&array.subset_all(), &lists)
+            .unwrap();
+        let stored = store.get(&"0".try_into().unwrap()).unwrap().unwrap();
+        assert_eq!(&stored[..], &chunk[..]);
+    }
+
     #[test]
     fn array_set_shape_and_chunk_grid() {
         use self::chunk_grid::RectangularChunkGridConfiguration;
@@ -1519,6 +1934,74 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn array_nalgebra() {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(vec![4, 4], vec![2, 2], data_type::int32(), 0i32)
+            .build(store, "/array")
+            .unwrap();
+
+        let matrix = nalgebra::DMatrix::from_row_slice(2, 3, &[1i32, 2, 3, 4, 5, 6]);
+        array.store_array_subset(&[1..3, 0..3], &matrix).unwrap();
+        let column = nalgebra::DMatrix::from_column_slice(2, 1, &[7i32, 8]);
+        array.store_array_subset(&[2..4, 3..4], column).unwrap();
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<i32>>(&array.subset_all())
+                .unwrap(),
+            vec![0, 0, 0, 0, 1, 2, 3, 0, 4, 5, 6, 7, 0, 0, 0, 8]
+        );
+
+        let retrieved = array
+            .retrieve_array_subset::<nalgebra::DMatrix<i32>>(&[1..3, 0..3])
+            .unwrap();
+        assert_eq!(retrieved, matrix);
+        // A matrix cannot be retrieved from a 1D subset
+        #[expect(clippy::single_range_in_vec_init)]
+        let subset_1d = [1..3];
+        assert!(
+            array
+                .retrieve_array_subset::<nalgebra::DMatrix<i32>>(&subset_1d)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn array_faer() {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(vec![4, 4], vec![2, 2], data_type::float64(), 0f64)
+            .build(store, "/array")
+            .unwrap();
+
+        let matrix = faer::mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        array.store_array_subset(&[1..3, 0..3], &matrix).unwrap();
+        let column = faer::mat![[7.0], [8.0]];
+        array.store_array_subset(&[2..4, 3..4], column).unwrap();
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<f64>>(&array.subset_all())
+                .unwrap(),
+            vec![
+                0., 0., 0., 0., 1., 2., 3., 0., 4., 5., 6., 7., 0., 0., 0., 8.
+            ]
+        );
+
+        let retrieved = array
+            .retrieve_array_subset::<faer::Mat<f64>>(&[1..3, 0..3])
+            .unwrap();
+        assert_eq!(retrieved, matrix);
+        // A matrix cannot be retrieved from a 1D subset
+        #[expect(clippy::single_range_in_vec_init)]
+        let subset_1d = [1..3];
+        assert!(
+            array
+                .retrieve_array_subset::<faer::Mat<f64>>(&subset_1d)
+                .is_err()
+        );
+    }
+
     #[allow(dead_code)]
     fn array_v2_to_v3(path_in: &str, path_out: &str) {
         let store = Arc::new(FilesystemStore::new(path_in).unwrap());
@@ -0,0 +1,396 @@
+//! [`FromArrayBytes`] and [`IntoArrayBytes`] implementations for Apache Arrow arrays.
+//!
+//! - [`PrimitiveArray`]s are supported for any data type compatible with their native type (e.g. [`Int32Array`](arrow_array::Int32Array) for `int32`).
+//! - [`StringArray`](arrow_array::StringArray) and [`LargeStringArray`](arrow_array::LargeStringArray) are supported for data types compatible with [`&str`] (e.g. `string`).
+//! - [`BinaryArray`](arrow_array::BinaryArray) and [`LargeBinaryArray`](arrow_array::LargeBinaryArray) are supported for data types compatible with `&[u8]` (e.g. `bytes`).
+//!
+//! Arrays with nulls map to the validity mask of the `optional` data type.
+//! The values and offsets of variable-length arrays are borrowed where possible when storing.
+
+use std::borrow::Cow;
+
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{
+    Array as _, GenericBinaryArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray,
+};
+use arrow_buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer, ToByteSlice};
+
+use super::element::{Element, ElementOwned};
+use super::{
+    ArrayBytes, ArrayBytesOffsets, ArrayError, DataType, ElementError, FromArrayBytes,
+    IntoArrayBytes,
+};
+
+/// Encode an arrow array with `nulls` to [`ArrayBytes`].
+///
+/// The data type must be optional if there are any nulls.
+/// The values are encoded with the (inner) data type by `encode`.
+fn encode_with_nulls<'a>(
+    data_type: &DataType,
+    nulls: Option<&NullBuffer>,
+    len: usize,
+    encode: impl FnOnce(&DataType) -> Result<ArrayBytes<'a>, ElementError>,
+) -> Result<ArrayBytes<'a>, ElementError> {
+    if let Some(optional) = data_type.as_optional() {
+        let data = encode(optional.data_type())?;
+        let mask: Vec<u8> = match nulls {
+            Some(nulls) => nulls.iter().map(u8::from).collect(),
+            None => vec![1; len],
+        };
+        Ok(data.with_optional_mask(mask))
+    } else if nulls.is_some_and(|nulls| nulls.null_count() > 0) {
+        Err(ElementError::Other(
+            "arrow array has nulls but the data type is not optional".to_string(),
+        ))
+    } else {
+        encode(data_type)
+    }
+}
+
+/// Decode [`ArrayBytes`] into its values, their data type, and arrow nulls if the data type is optional.
+fn decode_nulls<'a, 'd>(
+    bytes: ArrayBytes<'a>,
+    data_type: &'d DataType,
+) -> Result<(ArrayBytes<'a>, &'d DataType, Option<NullBuffer>), ElementError> {
+    if let Some(optional) = data_type.as_optional() {
+        let (data, mask) = bytes.into_optional()?.into_parts();
+        let nulls: NullBuffer = mask.iter().map(|&valid| valid != 0).collect();
+        Ok((*data, optional.data_type(), Some(nulls)))
+    } else {
+        Ok((bytes, data_type, None))
+    }
+}
+
+/// Encode arrow elements with a fixed size data type (e.g. `zarrs.categorical`).
+///
+/// Null elements are encoded as a valid element, since their arrow values may not be valid for the data type.
+fn encode_fixed<T: Element + Copy>(
+    data_type: &DataType,
+    elements: impl Iterator<Item = Option<T>> + Clone,
+    len: usize,
+) -> Result<ArrayBytes<'static>, ElementError> {
+    let Some(placeholder) = elements.clone().flatten().next() else {
+        // Every element is null
+        let size = data_type.fixed_size().unwrap_or_default();
+        return Ok(ArrayBytes::new_flen(vec![0; len * size]));
+    };
+    let elements: Vec<T> = elements
+        .map(|element| element.unwrap_or(placeholder))
+        .collect();
+    Ok(T::to_array_bytes(data_type, &elements)?.into_owned())
+}
+
+/// Convert arrow value offsets to [`ArrayBytesOffsets`].
+///
+/// 64-bit offsets starting at zero are borrowed on 64-bit targets.
+fn array_bytes_offsets<O: OffsetSizeTrait>(offsets: &[O]) -> ArrayBytesOffsets<'_> {
+    let first = offsets.first().map_or(0, |offset| offset.as_usize());
+    let offsets = if first == 0
+        && size_of::<O>() == size_of::<usize>()
+        && let Ok(offsets) = bytemuck::try_cast_slice::<u8, usize>(offsets.to_byte_slice())
+    {
+        Cow::Borrowed(offsets)
+    } else {
+        Cow::Owned(
+            offsets
+                .iter()
+                .map(|offset| offset.as_usize() - first)
+                .collect(),
+        )
+    };
+    unsafe {
+        // SAFETY: Arrow offsets are monotonically increasing.
+        ArrayBytesOffsets::new_unchecked(offsets)
+    }
+}
+
+/// Convert variable-length arrow values to [`ArrayBytes`] without copying the values.
+fn byte_array_to_array_bytes<'a, O: OffsetSizeTrait>(
+    offsets: &'a [O],
+    values: &'a [u8],
+) -> ArrayBytes<'a> {
+    let first = offsets.first().map_or(0, |offset| offset.as_usize());
+    let last = offsets.last().map_or(0, |offset| offset.as_usize());
+    unsafe {
+        // SAFETY: The last offset is the length of the values.
+        ArrayBytes::new_vlen_unchecked(&values[first..last], array_bytes_offsets(offsets))
+    }
+}
+
+/// Convert variable-length [`ArrayBytes`] to arrow value offsets and values.
+fn array_bytes_to_byte_array<O: OffsetSizeTrait>(
+    bytes: ArrayBytes<'_>,
+) -> Result<(OffsetBuffer<O>, Buffer), ArrayError> {
+    let (bytes, offsets) = bytes.into_variable()?.into_parts();
+    let offsets = offsets
+        .iter()
+        .map(|&offset| {
+            O::from_usize(offset).ok_or_else(|| {
+                ArrayError::Other(format!(
+                    "offset {offset} exceeds the maximum arrow offset {}",
+                    O::MAX_OFFSET
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((
+        OffsetBuffer::new(ScalarBuffer::from(offsets)),
+        Buffer::from_vec(bytes.into_owned()),
+    ))
+}
+
+impl<'a, T> IntoArrayBytes<'a> for &'a PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Element,
+{
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'a>, ElementError> {
+        encode_with_nulls(data_type, self.nulls(), self.len(), |data_type| {
+            T::Native::to_array_bytes(data_type, self.values())
+        })
+    }
+}
+
+impl<T> IntoArrayBytes<'static> for PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Element,
+{
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok((&self).into_array_bytes(data_type)?.into_owned())
+    }
+}
+
+impl<T> FromArrayBytes for PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: ElementOwned,
+{
+    fn from_array_bytes(
+        bytes: ArrayBytes<'static>,
+        _shape: &[u64],
+        data_type: &DataType,
+    ) -> Result<Self, ArrayError> {
+        let (bytes, data_type, nulls) = decode_nulls(bytes, data_type)?;
+        let values = <T::Native as ElementOwned>::from_array_bytes(data_type, bytes)?;
+        Self::try_new(ScalarBuffer::from(values), nulls)
+            .map_err(|err| ArrayError::Other(err.to_string()))
+    }
+}
+
+impl<'a, O: OffsetSizeTrait> IntoArrayBytes<'a> for &'a GenericStringArray<O> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'a>, ElementError> {
+        encode_with_nulls(data_type, self.nulls(), self.len(), |data_type| {
+            <&str>::validate_data_type(data_type)?;
+            if data_type.is_fixed() {
+                encode_fixed(data_type, self.iter(), self.len())
+            } else {
+                Ok(byte_array_to_array_bytes(
+                    self.value_offsets(),
+                    self.value_data(),
+                ))
+            }
+        })
+    }
+}
+
+impl<O: OffsetSizeTrait> IntoArrayBytes<'static> for GenericStringArray<O> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok((&self).into_array_bytes(data_type)?.into_owned())
+    }
+}
+
+impl<O: OffsetSizeTrait> FromArrayBytes for GenericStringArray<O> {
+    fn from_array_bytes(
+        bytes: ArrayBytes<'static>,
+        _shape: &[u64],
+        data_type: &DataType,
+    ) -> Result<Self, ArrayError> {
+        let (bytes, data_type, nulls) = decode_nulls(bytes, data_type)?;
+        String::validate_data_type(data_type)?;
+        let (offsets, values) = if data_type.is_fixed() {
+            let elements = <String as ElementOwned>::from_array_bytes(data_type, bytes)?;
+            let (offsets, values, _) = Self::from_iter_values(elements).into_parts();
+            (offsets, values)
+        } else {
+            array_bytes_to_byte_array(bytes)?
+        };
+        Self::try_new(offsets, values, nulls).map_err(|err| ArrayError::Other(err.to_string()))
+    }
+}
+
+impl<'a, O: OffsetSizeTrait> IntoArrayBytes<'a> for &'a GenericBinaryArray<O> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'a>, ElementError> {
+        encode_with_nulls(data_type, self.nulls(), self.len(), |data_type| {
+            <&[u8]>::validate_data_type(data_type)?;
+            if data_type.is_fixed() {
+                encode_fixed(data_type, self.iter(), self.len())
+            } else {
+                Ok(byte_array_to_array_bytes(
+                    self.value_offsets(),
+                    self.value_data(),
+                ))
+            }
+        })
+    }
+}
+
+impl<O: OffsetSizeTrait> IntoArrayBytes<'static> for GenericBinaryArray<O> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok((&self).into_array_bytes(data_type)?.into_owned())
+    }
+}
+
+impl<O: OffsetSizeTrait> FromArrayBytes for GenericBinaryArray<O> {
+    fn from_array_bytes(
+        bytes: ArrayBytes<'static>,
+        _shape: &[u64],
+        data_type: &DataType,
+    ) -> Result<Self, ArrayError> {
+        let (bytes, data_type, nulls) = decode_nulls(bytes, data_type)?;
+        Vec::<u8>::validate_data_type(data_type)?;
+        let (offsets, values) = if data_type.is_fixed() {
+            let elements = <Vec<u8> as ElementOwned>::from_array_bytes(data_type, bytes)?;
+            let (offsets, values, _) = Self::from_iter_values(elements).into_parts();
+            (offsets, values)
+        } else {
+            array_bytes_to_byte_array(bytes)?
+        };
+        Self::try_new(offsets, values, nulls).map_err(|err| ArrayError::Other(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{
+        Array as _, BinaryArray, Float32Array, Int16Array, LargeStringArray, StringArray,
+    };
+
+    use crate::array::{ArrayBuilder, ArrayBytes, ArraySubset, IntoArrayBytes, data_type};
+    use zarrs_metadata::FillValueMetadata;
+    use zarrs_storage::store::MemoryStore;
+
+    #[test]
+    fn array_arrow_ext_primitive() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(vec![6], vec![4], data_type::float32(), 0.0f32)
+            .build(store, "/")
+            .unwrap();
+        let subset = ArraySubset::new_with_start_shape(vec![1], vec![4]).unwrap();
+        array
+            .store_array_subset(&subset, Float32Array::from(vec![1.0, 2.0, 3.0, 4.0]))
+            .unwrap();
+        let values: Float32Array = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(
+            values,
+            Float32Array::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 0.0])
+        );
+
+        assert!(
+            array
+                .store_array_subset(&subset, Int16Array::from(vec![1, 2, 3, 4]))
+                .is_err()
+        );
+        assert!(
+            array
+                .store_array_subset(
+                    &subset,
+                    Float32Array::from(vec![Some(1.0), None, Some(3.0), Some(4.0)])
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn array_arrow_ext_nulls() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(
+            vec![4],
+            vec![2],
+            data_type::int16().to_optional(),
+            FillValueMetadata::Null,
+        )
+        .build(store, "/")
+        .unwrap();
+        let input = Int16Array::from(vec![Some(1), None, Some(3)]);
+        let subset = ArraySubset::new_with_start_shape(vec![0], vec![3]).unwrap();
+        array.store_array_subset(&subset, &input).unwrap();
+        let values: Int16Array = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(values, Int16Array::from(vec![Some(1), None, Some(3), None]));
+
+        let elements: Vec<Option<i16>> = array.retrieve_array_subset(&subset).unwrap();
+        assert_eq!(elements, [Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn array_arrow_ext_string() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(vec![4], vec![2], data_type::string(), "")
+            .build(store, "/")
+            .unwrap();
+        let input = StringArray::from(vec!["a", "bb", "ccc"]);
+        let sliced = input.slice(1, 2);
+        let bytes = (&sliced).into_array_bytes(array.data_type()).unwrap();
+        let ArrayBytes::Variable(bytes) = &bytes else {
+            panic!("expected variable length bytes");
+        };
+        assert_eq!(&**bytes.bytes(), b"bbccc");
+        assert_eq!(**bytes.offsets(), [0, 2, 5]);
+
+        let subset = ArraySubset::new_with_start_shape(vec![1], vec![2]).unwrap();
+        array.store_array_subset(&subset, &sliced).unwrap();
+        let values: StringArray = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(values, StringArray::from(vec!["", "bb", "ccc", ""]));
+        let values: LargeStringArray = array.retrieve_array_subset(&subset).unwrap();
+        assert_eq!(values, LargeStringArray::from(vec!["bb", "ccc"]));
+
+        array
+            .store_array_subset(&subset, LargeStringArray::from(vec!["d", "e"]))
+            .unwrap();
+        let values: Vec<String> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(values, ["", "d", "e", ""]);
+    }
+
+    #[test]
+    fn array_arrow_ext_string_optional_categorical() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(
+            vec![3],
+            vec![3],
+            data_type::categorical(vec!["x".into(), "y".into()])
+                .unwrap()
+                .to_optional(),
+            FillValueMetadata::Null,
+        )
+        .build(store, "/")
+        .unwrap();
+        let input = StringArray::from(vec![Some("y"), None, Some("x")]);
+        array
+            .store_array_subset(&array.subset_all(), &input)
+            .unwrap();
+        let values: StringArray = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(values, input);
+        assert!(values.is_null(1));
+    }
+
+    #[test]
+    fn array_arrow_ext_binary() {
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(vec![3], vec![2], data_type::bytes(), Vec::<u8>::new())
+            .build(store, "/")
+            .unwrap();
+        let input = BinaryArray::from(vec![&b"ab"[..], b"", b"cde"]);
+        array
+            .store_array_subset(&array.subset_all(), &input)
+            .unwrap();
+        let values: BinaryArray = array.retrieve_array_subset(&array.subset_all()).unwrap();
+        assert_eq!(values, input);
+        assert!(
+            array
+                .store_array_subset(&array.subset_all(), StringArray::from(vec!["a", "b", "c"]))
+                .is_err()
+        );
+    }
+}
@@ -24,7 +24,11 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Array<TStorage>, ArrayCreateError> {
         let metadata = Self::async_open_metadata(storage.clone(), path, version).await?;
         Self::validate_metadata(&metadata)?;
-        Self::new_with_metadata(storage, path, metadata)
+        #[allow(unused_mut)]
+        let mut array = Self::new_with_metadata(storage, path, metadata)?;
+        #[cfg(feature = "zstd")]
+        array.async_load_zstd_dictionaries().await?;
+        Ok(array)
     }
 
     async fn async_open_metadata(
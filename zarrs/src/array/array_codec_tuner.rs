@@ -0,0 +1,351 @@
+//! Selection of codec chains by benchmarking candidates on sample chunks.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::{
+    Array, ArrayBytes, ArrayError, ArrayIndices, ChunkShape, CodecChain, DataType, FillValue,
+};
+use crate::IntoConcurrentLimitIterator;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::iter::ParallelIterator;
+use zarrs_codec::{ArrayToBytesCodecTraits, CodecOptions};
+use zarrs_storage::ReadableStorageTraits;
+
+/// The performance of a candidate codec chain on the sample chunks of a [`CodecChainTuner`].
+#[derive(Clone, Debug)]
+pub struct CodecChainCandidateReport {
+    codec_chain: Arc<CodecChain>,
+    decoded_size: u64,
+    encoded_size: u64,
+    encode_duration: Duration,
+    decode_duration: Duration,
+}
+
+impl CodecChainCandidateReport {
+    /// Return the candidate codec chain.
+    #[must_use]
+    pub fn codec_chain(&self) -> &Arc<CodecChain> {
+        &self.codec_chain
+    }
+
+    /// Return the total size in bytes of the decoded sample chunks.
+    #[must_use]
+    pub fn decoded_size(&self) -> u64 {
+        self.decoded_size
+    }
+
+    /// Return the total size in bytes of the encoded sample chunks.
+    #[must_use]
+    pub fn encoded_size(&self) -> u64 {
+        self.encoded_size
+    }
+
+    /// Return the compression ratio (decoded size / encoded size).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compression_ratio(&self) -> f64 {
+        if self.encoded_size == 0 {
+            f64::INFINITY
+        } else {
+            self.decoded_size as f64 / self.encoded_size as f64
+        }
+    }
+
+    /// Return the time taken to encode the sample chunks.
+    #[must_use]
+    pub fn encode_duration(&self) -> Duration {
+        self.encode_duration
+    }
+
+    /// Return the time taken to decode the sample chunks.
+    #[must_use]
+    pub fn decode_duration(&self) -> Duration {
+        self.decode_duration
+    }
+
+    /// Return the encode throughput in decoded bytes per second.
+    #[must_use]
+    pub fn encode_throughput(&self) -> f64 {
+        throughput(self.decoded_size, self.encode_duration)
+    }
+
+    /// Return the decode throughput in decoded bytes per second.
+    #[must_use]
+    pub fn decode_throughput(&self) -> f64 {
+        throughput(self.decoded_size, self.decode_duration)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn throughput(size: u64, duration: Duration) -> f64 {
+    let seconds = duration.as_secs_f64();
+    if seconds == 0.0 {
+        f64::INFINITY
+    } else {
+        size as f64 / seconds
+    }
+}
+
+/// A constraint for selecting the best candidate of a [`CodecChainTuningReport`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CodecChainTuningConstraint {
+    /// Select the candidate with the highest compression ratio.
+    None,
+    /// Select the candidate with the highest compression ratio and an encode throughput (bytes per second) of at least this value.
+    MinEncodeThroughput(f64),
+    /// Select the candidate with the highest compression ratio and a decode throughput (bytes per second) of at least this value.
+    MinDecodeThroughput(f64),
+    /// Select the candidate with the shortest combined encode and decode duration and a compression ratio of at least this value.
+    MinCompressionRatio(f64),
+}
+
+/// A report of the performance of candidate codec chains on sample chunks.
+///
+/// Created with [`CodecChainTuner::tune`] or [`CodecChainTuner::tune_array`].
+#[derive(Clone, Debug, Default)]
+pub struct CodecChainTuningReport {
+    candidates: Vec<CodecChainCandidateReport>,
+}
+
+impl CodecChainTuningReport {
+    /// Return the report of each candidate, in the order they were supplied to the [`CodecChainTuner`].
+    #[must_use]
+    pub fn candidates(&self) -> &[CodecChainCandidateReport] {
+        &self.candidates
+    }
+
+    /// Return the best candidate satisfying `constraint`, or [`None`] if no candidate satisfies the constraint.
+    ///
+    /// Ties are resolved in favour of the earliest candidate.
+    #[must_use]
+    pub fn best(
+        &self,
+        constraint: CodecChainTuningConstraint,
+    ) -> Option<&CodecChainCandidateReport> {
+        let by_ratio = |a: &&CodecChainCandidateReport, b: &&CodecChainCandidateReport| {
+            a.compression_ratio().total_cmp(&b.compression_ratio())
+        };
+        let candidates = self.candidates.iter();
+        match constraint {
+            CodecChainTuningConstraint::None => candidates.rev().max_by(by_ratio),
+            CodecChainTuningConstraint::MinEncodeThroughput(min) => candidates
+                .rev()
+                .filter(|candidate| candidate.encode_throughput() >= min)
+                .max_by(by_ratio),
+            CodecChainTuningConstraint::MinDecodeThroughput(min) => candidates
+                .rev()
+                .filter(|candidate| candidate.decode_throughput() >= min)
+                .max_by(by_ratio),
+            CodecChainTuningConstraint::MinCompressionRatio(min) => candidates
+                .filter(|candidate| candidate.compression_ratio() >= min)
+                .min_by_key(|candidate| candidate.encode_duration + candidate.decode_duration),
+        }
+    }
+}
+
+/// Benchmarks candidate codec chains on sample chunks to guide the choice of codecs and their parameters.
+///
+/// Each candidate encodes and then decodes the sample chunks, with chunks processed in parallel.
+/// The encoded size and the encode and decode durations of each candidate are reported in a [`CodecChainTuningReport`].
+/// The best candidate under a [`CodecChainTuningConstraint`] can be used to create a new array with [`ArrayBuilder::codec_chain`](crate::array::ArrayBuilder::codec_chain).
+///
+/// Durations are measured with wall clock time, so other work on the system affects the reported throughput.
+/// Lossy codecs are not checked for decoding to the original sample.
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs::array::{ArrayBuilder, CodecChain, CodecChainTuner, CodecChainTuningConstraint, data_type};
+/// # use zarrs::array::codec::{BytesCodec, GzipCodec};
+/// # let store = Arc::new(zarrs_storage::store::MemoryStore::new());
+/// # let array = ArrayBuilder::new(vec![8, 8], vec![4, 4], data_type::uint16(), 0u16).build(store.clone(), "/array")?;
+/// # array.store_array_subset(&array.subset_all(), vec![1u16; 64])?;
+/// let candidates = [1, 5, 9].map(|level| {
+///     Arc::new(CodecChain::new(
+///         vec![],
+///         Arc::new(BytesCodec::little()),
+///         vec![Arc::new(GzipCodec::new(level).unwrap())],
+///     ))
+/// });
+/// let tuner = CodecChainTuner::new(candidates.to_vec());
+/// let report = tuner.tune_array(&array, &[vec![0, 0], vec![1, 1]])?;
+/// let best = report
+///     .best(CodecChainTuningConstraint::MinEncodeThroughput(1e6))
+///     .or_else(|| report.best(CodecChainTuningConstraint::None))
+///     .unwrap();
+/// let tuned_array = ArrayBuilder::from_array(&array)
+///     .codec_chain(best.codec_chain())
+///     .build(store, "/array_tuned")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct CodecChainTuner {
+    candidates: Vec<Arc<CodecChain>>,
+    codec_options: CodecOptions,
+}
+
+impl CodecChainTuner {
+    /// Create a new codec chain tuner with the candidate codec chains.
+    #[must_use]
+    pub fn new(candidates: Vec<Arc<CodecChain>>) -> Self {
+        Self {
+            candidates,
+            codec_options: CodecOptions::default(),
+        }
+    }
+
+    /// Set the codec options used for encoding and decoding.
+    #[must_use]
+    pub fn with_codec_options(mut self, codec_options: CodecOptions) -> Self {
+        self.codec_options = codec_options;
+        self
+    }
+
+    /// Return the candidate codec chains.
+    #[must_use]
+    pub fn candidates(&self) -> &[Arc<CodecChain>] {
+        &self.candidates
+    }
+
+    /// Benchmark the candidate codec chains on sample chunks with the given shapes and decoded bytes.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if a candidate does not support the data type or fill value, or if a sample chunk fails to encode or decode.
+    pub fn tune(
+        &self,
+        data_type: &DataType,
+        fill_value: &FillValue,
+        chunks: &[(ChunkShape, ArrayBytes<'_>)],
+    ) -> Result<CodecChainTuningReport, ArrayError> {
+        let options = &self.codec_options;
+        let decoded_size = chunks
+            .iter()
+            .map(|(_, bytes)| bytes.size() as u64)
+            .sum::<u64>();
+        let candidates = self
+            .candidates
+            .iter()
+            .map(|codec_chain| {
+                let codecs = codec_chain.with_context(data_type.clone(), fill_value.clone())?;
+
+                let start = Instant::now();
+                let encoded = chunks
+                    .concurrent_limit(options.concurrent_target())
+                    .map(|(shape, bytes)| codecs.encode(bytes.clone(), shape, options))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let encode_duration = start.elapsed();
+                let encoded_size = encoded
+                    .iter()
+                    .map(|encoded| encoded.len() as u64)
+                    .sum::<u64>();
+
+                let start = Instant::now();
+                encoded
+                    .into_iter()
+                    .zip(chunks)
+                    .collect::<Vec<_>>()
+                    .concurrent_limit(options.concurrent_target())
+                    .map(|(encoded, (shape, _))| codecs.decode(encoded, shape, options).map(drop))
+                    .collect::<Result<Vec<()>, _>>()?;
+                let decode_duration = start.elapsed();
+
+                Ok(CodecChainCandidateReport {
+                    codec_chain: codec_chain.clone(),
+                    decoded_size,
+                    encoded_size,
+                    encode_duration,
+                    decode_duration,
+                })
+            })
+            .collect::<Result<Vec<_>, ArrayError>>()?;
+        Ok(CodecChainTuningReport { candidates })
+    }
+
+    /// Benchmark the candidate codec chains on the chunks of `array` at `chunk_indices`.
+    ///
+    /// Chunks that do not exist are sampled as the fill value.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if a chunk cannot be retrieved or on any error of [`tune`](Self::tune).
+    pub fn tune_array<TStorage: ?Sized + ReadableStorageTraits + 'static>(
+        &self,
+        array: &Array<TStorage>,
+        chunk_indices: &[ArrayIndices],
+    ) -> Result<CodecChainTuningReport, ArrayError> {
+        let chunks = chunk_indices
+            .iter()
+            .map(|chunk_indices| {
+                Ok((
+                    array.chunk_shape(chunk_indices)?,
+                    array.retrieve_chunk::<ArrayBytes<'static>>(chunk_indices)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ArrayError>>()?;
+        self.tune(array.data_type(), array.fill_value(), &chunks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::codec::{BytesCodec, GzipCodec};
+    use crate::array::{Element, data_type};
+
+    #[test]
+    fn codec_chain_tuner() {
+        let uncompressed = Arc::new(CodecChain::new(
+            vec![],
+            Arc::new(BytesCodec::little()),
+            vec![],
+        ));
+        let gzip = Arc::new(CodecChain::new(
+            vec![],
+            Arc::new(BytesCodec::little()),
+            vec![Arc::new(GzipCodec::new(9).unwrap())],
+        ));
+        let tuner = CodecChainTuner::new(vec![uncompressed.clone(), gzip.clone()]);
+
+        let data_type = data_type::uint32();
+        let fill_value = FillValue::from(0u32);
+        let elements = vec![7u32; 1024];
+        let bytes = u32::to_array_bytes(&data_type, &elements).unwrap();
+        let shape: ChunkShape = vec![1024.try_into().unwrap()];
+        let chunks = [(shape.clone(), bytes.clone()), (shape, bytes)];
+        let report = tuner.tune(&data_type, &fill_value, &chunks).unwrap();
+
+        let candidates = report.candidates();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].decoded_size(), 2 * 4 * 1024);
+        assert_eq!(candidates[0].encoded_size(), 2 * 4 * 1024);
+        assert!((candidates[0].compression_ratio() - 1.0).abs() < f64::EPSILON);
+        assert!(candidates[1].encoded_size() < candidates[0].encoded_size());
+
+        let best = report.best(CodecChainTuningConstraint::None).unwrap();
+        assert!(Arc::ptr_eq(best.codec_chain(), &gzip));
+        let best = report
+            .best(CodecChainTuningConstraint::MinCompressionRatio(10.0))
+            .unwrap();
+        assert!(Arc::ptr_eq(best.codec_chain(), &gzip));
+        assert!(
+            report
+                .best(CodecChainTuningConstraint::MinCompressionRatio(
+                    f64::INFINITY
+                ))
+                .is_none()
+        );
+        assert!(
+            report
+                .best(CodecChainTuningConstraint::MinEncodeThroughput(
+                    f64::INFINITY
+                ))
+                .is_none_or(|best| best.encode_throughput().is_infinite())
+        );
+
+        assert!(matches!(
+            tuner.tune(&data_type::string(), &FillValue::from(""), &[]),
+            Err(ArrayError::CodecCreateError(_))
+        ));
+    }
+}
@@ -93,6 +93,9 @@ pub enum ArrayError {
     /// A codec error.
     #[error(transparent)]
     CodecError(#[from] CodecError),
+    /// A codec could not be created or bound to a data type and fill value.
+    #[error(transparent)]
+    CodecCreateError(#[from] CodecCreateError),
     // /// Invalid array indices.
     // #[error(transparent)]
     // InvalidArrayIndicesError(#[from] InvalidArrayIndicesError),
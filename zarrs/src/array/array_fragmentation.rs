@@ -0,0 +1,119 @@
+//! Fragmentation reports of encoded chunks.
+
+use super::ArrayIndices;
+
+/// The fragmentation of an encoded chunk.
+///
+/// Partially encoding a shard with [`experimental_partial_encoding`](crate::config::Config::experimental_partial_encoding) appends updated subchunks to the end of the shard.
+/// The bytes of the replaced subchunks remain in the shard but are no longer referenced by its index.
+/// These bytes are reported as wasted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChunkFragmentation {
+    chunk_indices: ArrayIndices,
+    encoded_size: u64,
+    wasted_size: u64,
+}
+
+impl ChunkFragmentation {
+    /// Create a new chunk fragmentation record.
+    #[must_use]
+    pub fn new(chunk_indices: ArrayIndices, encoded_size: u64, wasted_size: u64) -> Self {
+        Self {
+            chunk_indices,
+            encoded_size,
+            wasted_size,
+        }
+    }
+
+    /// Return the chunk indices.
+    #[must_use]
+    pub fn chunk_indices(&self) -> &[u64] {
+        &self.chunk_indices
+    }
+
+    /// Return the size in bytes of the encoded chunk in the store.
+    #[must_use]
+    pub fn encoded_size(&self) -> u64 {
+        self.encoded_size
+    }
+
+    /// Return the number of bytes of the encoded chunk that are not referenced.
+    #[must_use]
+    pub fn wasted_size(&self) -> u64 {
+        self.wasted_size
+    }
+
+    /// Return the fraction of the encoded chunk that is wasted, in the range `[0, 1]`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn wasted_ratio(&self) -> f64 {
+        if self.encoded_size == 0 {
+            0.0
+        } else {
+            self.wasted_size as f64 / self.encoded_size as f64
+        }
+    }
+}
+
+/// A fragmentation report of the encoded chunks of an array.
+///
+/// Created with [`ArrayReadOps::fragmentation_report`](crate::array::ArrayReadOps::fragmentation_report).
+/// Chunks that do not exist are not included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArrayFragmentationReport {
+    chunks: Vec<ChunkFragmentation>,
+}
+
+impl ArrayFragmentationReport {
+    /// Create a new fragmentation report from the fragmentation of individual chunks.
+    #[must_use]
+    pub fn new(chunks: Vec<ChunkFragmentation>) -> Self {
+        Self { chunks }
+    }
+
+    /// Return the fragmentation of each existing chunk.
+    #[must_use]
+    pub fn chunks(&self) -> &[ChunkFragmentation] {
+        &self.chunks
+    }
+
+    /// Return the total size in bytes of the encoded chunks.
+    #[must_use]
+    pub fn encoded_size(&self) -> u64 {
+        self.chunks
+            .iter()
+            .map(ChunkFragmentation::encoded_size)
+            .sum()
+    }
+
+    /// Return the total number of wasted bytes of the encoded chunks.
+    #[must_use]
+    pub fn wasted_size(&self) -> u64 {
+        self.chunks
+            .iter()
+            .map(ChunkFragmentation::wasted_size)
+            .sum()
+    }
+
+    /// Return the fraction of the encoded chunks that is wasted, in the range `[0, 1]`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn wasted_ratio(&self) -> f64 {
+        let encoded_size = self.encoded_size();
+        if encoded_size == 0 {
+            0.0
+        } else {
+            self.wasted_size() as f64 / encoded_size as f64
+        }
+    }
+
+    /// Return an iterator over the chunks with wasted bytes and a [wasted ratio](ChunkFragmentation::wasted_ratio) of at least `wasted_ratio_threshold`.
+    pub fn fragmented_chunks(
+        &self,
+        wasted_ratio_threshold: f64,
+    ) -> impl Iterator<Item = &ChunkFragmentation> {
+        self.chunks.iter().filter(move |chunk| {
+            chunk.wasted_size() > 0 && chunk.wasted_ratio() >= wasted_ratio_threshold
+        })
+    }
+}
@@ -52,12 +52,12 @@ use std::sync::Arc;
 
 use super::chunk_cache::ChunkCache;
 use super::{
-    Array, ArrayBuilder, ArrayCached, ArrayCreateError, ArrayError, ArrayIndices, ArrayMetadata,
-    ArrayMetadataOptions, ArrayShape, ArraySubset, ArraySubsetTraits, ChunkGrid, ChunkGridDecoded,
-    ChunkGridDecodedRef, ChunkKeyEncoding, ChunkShape, ChunkShapeTraits, CodecChain,
-    CodecChainBound, CodecCreateError, CodecOptions, CodecSpecificOptions, DataType, DimensionName,
-    FillValue, FromArrayBytes, IncompatibleDimensionalityError, IntoArrayBytes, NodePath,
-    StorageTransformerChain,
+    Array, ArrayBuilder, ArrayCached, ArrayCreateError, ArrayError, ArrayFragmentationReport,
+    ArrayIndices, ArrayMetadata, ArrayMetadataOptions, ArrayShape, ArraySubset, ArraySubsetTraits,
+    ChunkGrid, ChunkGridDecoded, ChunkGridDecodedRef, ChunkKeyEncoding, ChunkShape,
+    ChunkShapeTraits, CodecChain, CodecChainBound, CodecCreateError, CodecOptions,
+    CodecSpecificOptions, DataType, DimensionName, FillValue, FromArrayBytes,
+    IncompatibleDimensionalityError, IntoArrayBytes, NodePath, StorageTransformerChain,
 };
 use crate::config::MetadataEraseVersion;
 use zarrs_codec::{ArrayCodecTraits, RecommendedConcurrency};
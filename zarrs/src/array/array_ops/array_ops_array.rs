@@ -10,7 +10,6 @@ use crate::config::MetadataConvertVersion;
 use crate::convert::array_metadata_v2_to_v3;
 use crate::node::data_key;
 use zarrs_codec::ChunkGridDecoded;
-use zarrs_metadata::v2::DataTypeMetadataV2;
 use zarrs_metadata::v3::MetadataV3;
 use zarrs_plugin::ZarrVersion;
 
@@ -227,14 +226,8 @@ impl<TStorage: ?Sized> ArrayOps for Array<TStorage> {
                             codec_default_name(&compressor_metadata, ZarrVersion::V2).into_owned();
                         compressor.set_id(name);
                     }
-                    match &mut metadata.dtype {
-                        DataTypeMetadataV2::Simple(dtype) => {
-                            *dtype = data_type::data_type_v2_default_name(dtype).into_owned();
-                        }
-                        DataTypeMetadataV2::Structured(_) => {
-                            // FIXME: structured data type support
-                        }
-                    }
+                    metadata.dtype =
+                        data_type::data_type_metadata_v2_default_names(&metadata.dtype);
                 }
             }
         }
@@ -255,6 +255,23 @@ pub trait ArrayReadOps: ArrayOps + MaybeSync {
         chunk_indices: &[u64],
     ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, ArrayError>;
 
+    /// Return a fragmentation report of the existing encoded chunks in `chunks`.
+    ///
+    /// Only the size and the shard index of each chunk are read.
+    /// Chunks of arrays that do not use the `sharding_indexed` codec (or apply bytes-to-bytes codecs after it) have no wasted bytes.
+    ///
+    /// Use [`ArrayOps::chunk_grid_shape`] to create a subset covering all chunks.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - any chunk indices in `chunks` are invalid,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    fn fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     /// Return the chunk-local subchunk grid for a chunk, if available.
     ///
     /// The returned grid is relative to the decoded chunk at `chunk_indices`.
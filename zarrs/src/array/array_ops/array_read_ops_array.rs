@@ -10,7 +10,7 @@ use super::super::concurrency::concurrency_chunks_and_codec;
 use super::super::{ArrayBytesFixedDisjointView, ArrayIndicesTinyVec};
 use super::{ArrayReadOps, *};
 use crate::IntoConcurrentLimitIterator;
-use crate::array::{ArrayBytes, ChunkShapeTraits};
+use crate::array::{ArrayBytes, ChunkFragmentation, ChunkShapeTraits};
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::iter::ParallelIterator;
 use unsafe_cell_slice::UnsafeCellSlice;
@@ -259,6 +259,50 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayReadOps for Array<
     ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, ArrayError> {
         self.partial_decoder_with_options(chunk_indices, self.codec_options())
     }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError> {
+        let options = self.codec_options();
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_readable_transformer(storage_handle)?;
+        let sharding_codec = self.sharding_codec_without_bytes_to_bytes_codecs();
+
+        let chunk_fragmentation =
+            |chunk_indices: ArrayIndicesTinyVec| -> Result<Option<ChunkFragmentation>, ArrayError> {
+                let chunk_shape = self.chunk_shape(&chunk_indices)?;
+                let key = self.chunk_key(&chunk_indices);
+                let Some(encoded_size) = storage_transformer.size_key(&key)? else {
+                    return Ok(None);
+                };
+                let referenced_size = if let Some(sharding_codec) = sharding_codec {
+                    let input_handle = (storage_transformer.clone(), key);
+                    sharding_codec
+                        .shard_referenced_size(&input_handle, &chunk_shape, options)?
+                        .unwrap_or(encoded_size)
+                } else {
+                    encoded_size
+                };
+                Ok(Some(ChunkFragmentation::new(
+                    chunk_indices.to_vec(),
+                    encoded_size,
+                    encoded_size.saturating_sub(referenced_size),
+                )))
+            };
+
+        let chunks = chunks
+            .indices()
+            .concurrent_limit(options.concurrent_target())
+            .map(chunk_fragmentation)
+            .collect::<Result<Vec<_>, ArrayError>>()?;
+        Ok(ArrayFragmentationReport::new(
+            chunks.into_iter().flatten().collect(),
+        ))
+    }
 }
 
 impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
@@ -419,6 +419,14 @@ where
         C::Value::partial_decoder(self.cache(), self.array(), chunk_indices, options)
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub fn fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError> {
+        self.array().fragmentation_report(chunks)
+    }
+
     #[allow(clippy::missing_errors_doc)]
     pub fn local_subchunk_grid(
         &self,
@@ -1,4 +1,8 @@
+use super::super::concurrency::concurrency_chunks_and_codec;
 use super::*;
+use crate::IntoConcurrentLimitIterator;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::iter::ParallelIterator;
 use zarrs_codec::ArrayPartialEncoderTraits;
 
 /// Synchronous array read/write update operations.
@@ -55,6 +59,53 @@ pub trait ArrayUpdateOps: ArrayReadOps + ArrayWriteOps {
     ///  - an underlying store error.
     fn compact_chunk(&self, chunk_indices: &[u64]) -> Result<bool, ArrayError>;
 
+    /// Compact the existing chunks in `chunks` with a wasted ratio of at least `wasted_ratio_threshold`.
+    ///
+    /// The chunks are selected with a [fragmentation report](ArrayReadOps::fragmentation_report) and [compacted](ArrayUpdateOps::compact_chunk) concurrently.
+    /// Chunks without wasted bytes are never compacted, so a threshold of `0.0` compacts all fragmented chunks.
+    ///
+    /// Returns the fragmentation of the compacted chunks prior to compaction.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - any chunk indices in `chunks` are invalid,
+    ///  - there is a codec error, or
+    ///  - an underlying store error.
+    fn compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>
+    where
+        Self: Sized,
+    {
+        let fragmented_chunks = self
+            .fragmentation_report(chunks)?
+            .fragmented_chunks(wasted_ratio_threshold)
+            .cloned()
+            .collect::<Vec<_>>();
+        if fragmented_chunks.is_empty() {
+            return Ok(ArrayFragmentationReport::new(fragmented_chunks));
+        }
+
+        // Calculate chunk/codec concurrency
+        let options = self.codec_options();
+        let chunk_shape = self.chunk_shape(fragmented_chunks[0].chunk_indices())?;
+        let codec_concurrency = recommended_codec_concurrency(self, &chunk_shape)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            fragmented_chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+        let array = self.with_codec_options(options);
+
+        (&fragmented_chunks)
+            .concurrent_limit(chunk_concurrent_limit)
+            .try_for_each(|chunk| array.compact_chunk(chunk.chunk_indices()).map(|_| ()))?;
+        Ok(ArrayFragmentationReport::new(fragmented_chunks))
+    }
+
     /// Return a read-only instantiation of the array.
     fn readable(&self) -> Array<dyn ReadableStorageTraits>;
 
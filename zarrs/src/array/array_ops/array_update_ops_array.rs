@@ -136,6 +136,13 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> ArrayUpdateOps
         }
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub fn compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     pub fn readable(&self) -> Array<dyn ReadableStorageTraits> {
         self.with_storage(self.storage.clone().readable())
     }
@@ -135,6 +135,13 @@ where
         Ok(compacted)
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub fn compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     pub fn readable(&self) -> Array<dyn ReadableStorageTraits> {
         self.array().readable()
     }
@@ -143,7 +143,9 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> ArrayWriteOps for Array
         let storage_transformer = self
             .storage_transformers()
             .create_writable_transformer(storage_handle)?;
-        storage_transformer.erase(&self.chunk_key(chunk_indices))
+        storage_transformer.erase(&self.chunk_key(chunk_indices))?;
+        self.invalidate_shard_index_cache(chunk_indices);
+        Ok(())
     }
 
     pub fn erase_chunks(&self, chunks: &dyn ArraySubsetTraits) -> Result<(), StorageError> {
@@ -152,7 +154,9 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> ArrayWriteOps for Array
             .storage_transformers()
             .create_writable_transformer(storage_handle)?;
         let erase_chunk = |chunk_indices: ArrayIndicesTinyVec| {
-            storage_transformer.erase(&self.chunk_key(&chunk_indices))
+            storage_transformer.erase(&self.chunk_key(&chunk_indices))?;
+            self.invalidate_shard_index_cache(&chunk_indices);
+            Ok::<_, StorageError>(())
         };
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -174,6 +178,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> ArrayWriteOps for Array
             .storage_transformers()
             .create_writable_transformer(storage_handle)?;
         storage_transformer.set(&self.chunk_key(chunk_indices), encoded_chunk_bytes)?;
+        self.invalidate_shard_index_cache(chunk_indices);
 
         Ok(())
     }
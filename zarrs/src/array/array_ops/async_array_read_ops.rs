@@ -162,6 +162,13 @@ pub trait AsyncArrayReadOps: ArrayOps {
         chunk_indices: &[u64],
     ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, ArrayError>;
 
+    /// Async variant of [`ArrayReadOps::fragmentation_report`].
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     /// Async variant of [`ArrayReadOps::local_subchunk_grid`].
     #[allow(clippy::missing_errors_doc)]
     async fn async_local_subchunk_grid(
@@ -13,7 +13,7 @@ use super::super::concurrency::concurrency_chunks_and_codec;
 use super::super::{ArrayBytesFixedDisjointView, ArrayIndicesTinyVec};
 use super::async_array_read_ops_common::AsyncRetrieveInto;
 use super::{AsyncArrayReadOps, *};
-use crate::array::{ArrayBytes, ChunkShapeTraits};
+use crate::array::{ArrayBytes, ChunkFragmentation, ChunkShapeTraits};
 use zarrs_codec::{
     ArrayBytesDecodeIntoTarget, ArrayToBytesCodecTraits, AsyncArrayPartialDecoderTraits,
     CodecError, InvalidNumberOfElementsError, copy_fill_value_into,
@@ -262,6 +262,55 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> AsyncArrayReadOps
             .await
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError> {
+        let options = self.codec_options();
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .storage_transformers()
+            .create_async_readable_transformer(storage_handle)
+            .await?;
+        let sharding_codec = self.sharding_codec_without_bytes_to_bytes_codecs();
+
+        let chunk_fragmentation = |chunk_indices: ArrayIndicesTinyVec| {
+            let storage_transformer = storage_transformer.clone();
+            async move {
+                let chunk_shape = self.chunk_shape(&chunk_indices)?;
+                let key = self.chunk_key(&chunk_indices);
+                let Some(encoded_size) = storage_transformer.size_key(&key).await? else {
+                    return Ok(None);
+                };
+                let referenced_size = if let Some(sharding_codec) = sharding_codec {
+                    let input_handle = (storage_transformer, key);
+                    sharding_codec
+                        .async_shard_referenced_size(&input_handle, &chunk_shape, options)
+                        .await?
+                        .unwrap_or(encoded_size)
+                } else {
+                    encoded_size
+                };
+                Ok::<_, ArrayError>(Some(ChunkFragmentation::new(
+                    chunk_indices.to_vec(),
+                    encoded_size,
+                    encoded_size.saturating_sub(referenced_size),
+                )))
+            }
+        };
+
+        let indices = chunks.indices();
+        let futures = indices.into_iter().map(chunk_fragmentation);
+        let chunks: Vec<_> = futures::stream::iter(futures)
+            .buffered(options.concurrent_target())
+            .try_collect()
+            .await?;
+        Ok(ArrayFragmentationReport::new(
+            chunks.into_iter().flatten().collect(),
+        ))
+    }
+
     pub async fn async_local_subchunk_grid(
         &self,
         chunk_indices: &[u64],
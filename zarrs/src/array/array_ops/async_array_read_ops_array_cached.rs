@@ -482,6 +482,14 @@ where
         .await
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fragmentation_report(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+    ) -> Result<ArrayFragmentationReport, ArrayError> {
+        self.array().async_fragmentation_report(chunks).await
+    }
+
     #[allow(clippy::missing_errors_doc)]
     pub async fn async_local_subchunk_grid(
         &self,
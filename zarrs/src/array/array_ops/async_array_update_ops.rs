@@ -1,4 +1,6 @@
+use super::super::concurrency::concurrency_chunks_and_codec;
 use super::*;
+use futures::{StreamExt, TryStreamExt};
 use std::sync::Arc;
 use zarrs_codec::AsyncArrayPartialEncoderTraits;
 
@@ -30,6 +32,50 @@ pub trait AsyncArrayUpdateOps: AsyncArrayReadOps + AsyncArrayWriteOps {
     #[allow(clippy::missing_errors_doc)]
     async fn async_compact_chunk(&self, chunk_indices: &[u64]) -> Result<bool, ArrayError>;
 
+    /// Async variant of [`ArrayUpdateOps::compact_chunks`].
+    #[allow(clippy::missing_errors_doc)]
+    async fn async_compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>
+    where
+        Self: Sized,
+    {
+        let fragmented_chunks = self
+            .async_fragmentation_report(chunks)
+            .await?
+            .fragmented_chunks(wasted_ratio_threshold)
+            .cloned()
+            .collect::<Vec<_>>();
+        if fragmented_chunks.is_empty() {
+            return Ok(ArrayFragmentationReport::new(fragmented_chunks));
+        }
+
+        // Calculate chunk/codec concurrency
+        let options = self.codec_options();
+        let chunk_shape = self.chunk_shape(fragmented_chunks[0].chunk_indices())?;
+        let codec_concurrency = recommended_codec_concurrency(self, &chunk_shape)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            fragmented_chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+        let array = &self.with_codec_options(options);
+
+        futures::stream::iter(&fragmented_chunks)
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), |chunk| async move {
+                array
+                    .async_compact_chunk(chunk.chunk_indices())
+                    .await
+                    .map(|_| ())
+            })
+            .await?;
+        Ok(ArrayFragmentationReport::new(fragmented_chunks))
+    }
+
     /// Return a read-only instantiation of the array.
     fn async_readable(&self) -> Array<dyn AsyncReadableStorageTraits>;
 
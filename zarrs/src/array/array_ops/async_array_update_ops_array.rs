@@ -144,6 +144,13 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> AsyncArray
             Ok(false)
         }
     }
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     pub fn async_readable(&self) -> Array<dyn AsyncReadableStorageTraits> {
         self.with_storage(self.storage.clone().readable())
     }
@@ -146,6 +146,13 @@ where
         Ok(compacted)
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_compact_chunks(
+        &self,
+        chunks: &dyn ArraySubsetTraits,
+        wasted_ratio_threshold: f64,
+    ) -> Result<ArrayFragmentationReport, ArrayError>;
+
     pub fn async_readable(&self) -> Array<dyn AsyncReadableStorageTraits> {
         self.array().async_readable()
     }
@@ -168,7 +168,9 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> AsyncArrayWriteOps
             .await?;
         storage_transformer
             .erase(&self.chunk_key(chunk_indices))
-            .await
+            .await?;
+        self.invalidate_shard_index_cache(chunk_indices);
+        Ok(())
     }
 
     pub async fn async_erase_chunks(
@@ -185,7 +187,9 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> AsyncArrayWriteOps
             async move {
                 storage_transformer
                     .erase(&self.chunk_key(&chunk_indices))
-                    .await
+                    .await?;
+                self.invalidate_shard_index_cache(&chunk_indices);
+                Ok(())
             }
         };
         futures::stream::iter(chunks.indices())
@@ -211,6 +215,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> AsyncArrayWriteOps
         storage_transformer
             .set(&self.chunk_key(chunk_indices), encoded_chunk_bytes)
             .await?;
+        self.invalidate_shard_index_cache(chunk_indices);
         Ok(())
     }
 }
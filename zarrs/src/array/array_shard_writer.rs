@@ -0,0 +1,880 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::codec::ShardingCodecBound;
+use super::codec::array_to_bytes::sharding::calculate_chunks_per_shard;
+use super::{Array, ArrayError, ArrayIndices, ChunkShapeTraits, CodecChainBound, IntoArrayBytes};
+use zarrs_codec::ArrayToBytesCodecTraits;
+use zarrs_storage::{Bytes, ReadableWritableStorage, StoreKey, WritableStorageTraits};
+
+/// Options for an [`ArrayShardWriter`].
+#[derive(Clone)]
+pub struct ArrayShardWriterOptions {
+    memory_limit: usize,
+    spill_store: Option<ReadableWritableStorage>,
+}
+
+impl Default for ArrayShardWriterOptions {
+    fn default() -> Self {
+        Self {
+            memory_limit: usize::MAX,
+            spill_store: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for ArrayShardWriterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayShardWriterOptions")
+            .field("memory_limit", &self.memory_limit)
+            .field("spill_store", &self.spill_store.is_some())
+            .finish()
+    }
+}
+
+impl ArrayShardWriterOptions {
+    /// Set the maximum number of bytes of encoded subchunks to hold in memory.
+    ///
+    /// Defaults to [`usize::MAX`] (unbounded).
+    #[must_use]
+    pub fn with_memory_limit(mut self, memory_limit: usize) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Set the maximum number of bytes of encoded subchunks to hold in memory.
+    pub fn set_memory_limit(&mut self, memory_limit: usize) -> &mut Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Return the maximum number of bytes of encoded subchunks to hold in memory.
+    #[must_use]
+    pub fn memory_limit(&self) -> usize {
+        self.memory_limit
+    }
+
+    /// Set the store that encoded subchunks are spilled to when the memory limit is exceeded.
+    ///
+    /// This should be a temporary store that is not shared with other shard writers.
+    #[must_use]
+    pub fn with_spill_store(mut self, spill_store: ReadableWritableStorage) -> Self {
+        self.spill_store = Some(spill_store);
+        self
+    }
+
+    /// Set the store that encoded subchunks are spilled to when the memory limit is exceeded.
+    pub fn set_spill_store(&mut self, spill_store: Option<ReadableWritableStorage>) -> &mut Self {
+        self.spill_store = spill_store;
+        self
+    }
+
+    /// Return the store that encoded subchunks are spilled to when the memory limit is exceeded.
+    #[must_use]
+    pub fn spill_store(&self) -> Option<&ReadableWritableStorage> {
+        self.spill_store.as_ref()
+    }
+}
+
+/// The buffered subchunks of a shard that has not been written.
+#[derive(Debug, Default)]
+struct PendingShard {
+    /// Whether each subchunk has been received, in C order.
+    received: Vec<bool>,
+    num_received: usize,
+    /// Encoded subchunks held in memory, keyed by their linearised index in the shard.
+    in_memory: HashMap<usize, Bytes>,
+    in_memory_size: usize,
+    /// Spill store keys of in-memory subchunks that are being moved to the spill store.
+    spilling: HashMap<usize, StoreKey>,
+    spilling_size: usize,
+    /// Spill store keys of the encoded subchunks held in the spill store.
+    spilled: HashMap<usize, StoreKey>,
+}
+
+impl PendingShard {
+    /// Remove the buffered subchunk at `subchunk_index`.
+    ///
+    /// Returns the number of in-memory bytes released and the spill store key of the subchunk if it was spilled.
+    fn remove_subchunk(&mut self, subchunk_index: usize) -> (usize, usize, Option<StoreKey>) {
+        let mut in_memory_size = 0;
+        let mut spilling_size = 0;
+        if let Some(previous) = self.in_memory.remove(&subchunk_index) {
+            in_memory_size = previous.len();
+            self.in_memory_size -= in_memory_size;
+            if self.spilling.remove(&subchunk_index).is_some() {
+                spilling_size = in_memory_size;
+                self.spilling_size -= spilling_size;
+            }
+        }
+        (
+            in_memory_size,
+            spilling_size,
+            self.spilled.remove(&subchunk_index),
+        )
+    }
+}
+
+/// An in-memory subchunk selected to be moved to the spill store.
+struct SpillVictim {
+    shard_indices: ArrayIndices,
+    subchunk_index: usize,
+    key: StoreKey,
+    subchunk_encoded: Bytes,
+}
+
+#[derive(Debug, Default)]
+struct ArrayShardWriterState {
+    shards: HashMap<ArrayIndices, PendingShard>,
+    memory_usage: usize,
+    /// The number of in-memory bytes that are being moved to the spill store.
+    spilling_size: usize,
+    next_spill_id: u64,
+}
+
+impl ArrayShardWriterState {
+    /// Remove the buffered subchunk at `subchunk_index` of the shard at `shard_indices`.
+    ///
+    /// Returns the spill store key of the subchunk if it was spilled.
+    fn remove_subchunk(
+        &mut self,
+        shard_indices: &[u64],
+        subchunk_index: usize,
+    ) -> Option<StoreKey> {
+        let pending = self.shards.get_mut(shard_indices)?;
+        let (in_memory_size, spilling_size, spilled_key) = pending.remove_subchunk(subchunk_index);
+        self.memory_usage -= in_memory_size;
+        self.spilling_size -= spilling_size;
+        spilled_key
+    }
+
+    /// Remove the pending shard at `shard_indices` for writing.
+    fn take_shard(&mut self, shard_indices: &[u64]) -> Option<PendingShard> {
+        let pending = self.shards.remove(shard_indices)?;
+        self.memory_usage -= pending.in_memory_size;
+        self.spilling_size -= pending.spilling_size;
+        Some(pending)
+    }
+
+    /// Mark a spill of an in-memory subchunk as complete and drop the subchunk from memory.
+    ///
+    /// Returns `false` if the subchunk was replaced or written while it was being spilled, in which case the spilled copy is orphaned.
+    fn finish_spill(&mut self, victim: &SpillVictim) -> bool {
+        let Some(pending) = self.shards.get_mut(&victim.shard_indices) else {
+            return false;
+        };
+        if pending.spilling.get(&victim.subchunk_index) != Some(&victim.key) {
+            return false;
+        }
+        pending.spilling.remove(&victim.subchunk_index);
+        pending.in_memory.remove(&victim.subchunk_index);
+        let size = victim.subchunk_encoded.len();
+        pending.in_memory_size -= size;
+        pending.spilling_size -= size;
+        self.memory_usage -= size;
+        self.spilling_size -= size;
+        pending
+            .spilled
+            .insert(victim.subchunk_index, victim.key.clone());
+        true
+    }
+
+    /// Keep an in-memory subchunk in memory after its spill failed.
+    fn cancel_spill(&mut self, victim: &SpillVictim) {
+        let Some(pending) = self.shards.get_mut(&victim.shard_indices) else {
+            return;
+        };
+        if pending.spilling.get(&victim.subchunk_index) == Some(&victim.key) {
+            pending.spilling.remove(&victim.subchunk_index);
+            let size = victim.subchunk_encoded.len();
+            pending.spilling_size -= size;
+            self.spilling_size -= size;
+        }
+    }
+
+    /// Restore a pending shard that failed to be written.
+    ///
+    /// Subchunks received again since the shard was taken take precedence.
+    /// Returns the spill store keys of the superseded spilled subchunks.
+    fn restore_shard(&mut self, shard_indices: &[u64], mut pending: PendingShard) -> Vec<StoreKey> {
+        let Some(newer) = self.shards.get_mut(shard_indices) else {
+            self.memory_usage += pending.in_memory_size;
+            self.spilling_size += pending.spilling_size;
+            self.shards.insert(shard_indices.to_vec(), pending);
+            return Vec::new();
+        };
+        let mut stale_keys = Vec::new();
+        for (subchunk_index, received) in pending.received.iter().enumerate() {
+            if !received {
+                continue;
+            }
+            let subchunk_encoded = pending.in_memory.remove(&subchunk_index);
+            let spilled_key = pending.spilled.remove(&subchunk_index);
+            if newer.received[subchunk_index] {
+                stale_keys.extend(spilled_key);
+                continue;
+            }
+            // Any spill of the restored subchunk in progress is orphaned
+            if let Some(subchunk_encoded) = subchunk_encoded {
+                newer.in_memory_size += subchunk_encoded.len();
+                self.memory_usage += subchunk_encoded.len();
+                newer.in_memory.insert(subchunk_index, subchunk_encoded);
+            }
+            if let Some(spilled_key) = spilled_key {
+                newer.spilled.insert(subchunk_index, spilled_key);
+            }
+            newer.received[subchunk_index] = true;
+            newer.num_received += 1;
+        }
+        stale_keys
+    }
+}
+
+/// A writer that buffers the subchunks of a sharded array and writes each shard once it is complete.
+///
+/// Writing subchunk-sized pieces of a sharded array with [`store_array_subset`](crate::array::ArrayUpdateOps::store_array_subset) rewrites the whole shard on each write, and partial encoding appends to (and fragments) the shard.
+/// Instead, an `ArrayShardWriter` encodes each subchunk as it arrives and holds it until every subchunk of its shard has been received, then writes the compact shard in one store operation.
+///
+/// Encoded subchunks are held in memory up to the [memory limit](ArrayShardWriterOptions::with_memory_limit).
+/// Beyond that, the subchunks of the largest pending shards are moved to the [spill store](ArrayShardWriterOptions::with_spill_store).
+///
+/// Shards that are incomplete can be written with [`flush_shard`](ArrayShardWriter::flush_shard) or [`flush`](ArrayShardWriter::flush).
+/// Subchunks that were not received are written as the fill value, replacing any existing content of the shard.
+/// A shard that could not be written remains pending.
+/// Pending shards are discarded if the writer is dropped without being flushed.
+///
+/// The array must use the `sharding_indexed` codec with no preceding array-to-array codecs.
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs::array::{ArrayBuilder, ArrayShardWriterOptions, data_type};
+/// # use zarrs::storage::store::MemoryStore;
+/// let store = Arc::new(MemoryStore::new());
+/// let array = Arc::new(
+///     ArrayBuilder::new(vec![8, 8], vec![4, 4], data_type::uint8(), 0u8)
+///         .subchunk_shape(vec![2, 2])
+///         .build(store, "/array")?,
+/// );
+/// let writer = array.shard_writer(ArrayShardWriterOptions::default())?;
+/// for i in 0..2 {
+///     for j in 0..2 {
+///         writer.store_subchunk(&[i, j], &[1u8; 4])?;
+///     }
+/// }
+/// // The shard at [0, 0] is complete and has been written
+/// assert!(writer.pending_shards().is_empty());
+/// assert_eq!(array.retrieve_chunk::<Vec<u8>>(&[0, 0])?, vec![1u8; 16]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct ArrayShardWriter<TStorage: ?Sized> {
+    array: Arc<Array<TStorage>>,
+    codecs: Arc<CodecChainBound>,
+    options: ArrayShardWriterOptions,
+    state: Mutex<ArrayShardWriterState>,
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
+    /// Create an [`ArrayShardWriter`] for this array.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::UnsupportedMethod`] if the array is not sharded.
+    pub fn shard_writer(
+        self: &Arc<Self>,
+        options: ArrayShardWriterOptions,
+    ) -> Result<ArrayShardWriter<TStorage>, ArrayError> {
+        ArrayShardWriter::new(self.clone(), options)
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits + 'static> ArrayShardWriter<TStorage> {
+    /// Create a new shard writer for `array`.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::UnsupportedMethod`] if the array does not use the `sharding_indexed` codec or has array-to-array codecs.
+    pub fn new(
+        array: Arc<Array<TStorage>>,
+        options: ArrayShardWriterOptions,
+    ) -> Result<Self, ArrayError> {
+        let codecs = array.codecs_bound();
+        if !codecs.array_to_array_codecs().is_empty()
+            || codecs
+                .array_to_bytes_codec()
+                .as_any()
+                .downcast_ref::<ShardingCodecBound>()
+                .is_none()
+        {
+            return Err(ArrayError::UnsupportedMethod(
+                "the shard writer requires an array with the sharding_indexed codec and no array-to-array codecs".to_string(),
+            ));
+        }
+        Ok(Self {
+            array,
+            codecs,
+            options,
+            state: Mutex::new(ArrayShardWriterState::default()),
+        })
+    }
+
+    /// Return the array.
+    #[must_use]
+    pub fn array(&self) -> &Arc<Array<TStorage>> {
+        &self.array
+    }
+
+    /// Return the shard writer options.
+    #[must_use]
+    pub fn options(&self) -> &ArrayShardWriterOptions {
+        &self.options
+    }
+
+    /// Return the number of bytes of encoded subchunks held in memory.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn memory_usage(&self) -> usize {
+        self.state.lock().unwrap().memory_usage
+    }
+
+    /// Return the indices of shards with buffered subchunks that have not been written.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn pending_shards(&self) -> Vec<ArrayIndices> {
+        let mut shards: Vec<_> = self.state.lock().unwrap().shards.keys().cloned().collect();
+        shards.sort_unstable();
+        shards
+    }
+
+    fn sharding_codec(&self) -> &ShardingCodecBound {
+        self.codecs
+            .array_to_bytes_codec()
+            .as_any()
+            .downcast_ref::<ShardingCodecBound>()
+            .expect("validated in new")
+    }
+
+    /// Encode `subchunk_data` and buffer it for the subchunk at `subchunk_indices`.
+    ///
+    /// `subchunk_indices` are the indices of the subchunk in the array subchunk grid (see [`ArrayOps::subchunk_grid`]).
+    /// The shard is written once all of its subchunks have been received.
+    /// A subchunk that is received again replaces the buffered subchunk.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `subchunk_indices` are invalid,
+    ///  - the length of `subchunk_data` does not match the subchunk shape,
+    ///  - there is a codec encoding error,
+    ///  - the memory limit is exceeded and there is no spill store, or
+    ///  - there is an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned.
+    pub fn store_subchunk<'a, T: IntoArrayBytes<'a>>(
+        &self,
+        subchunk_indices: &[u64],
+        subchunk_data: T,
+    ) -> Result<(), ArrayError> {
+        let sharding = self.sharding_codec();
+        let options = self.array.codec_options();
+
+        // Locate the subchunk within its shard
+        let subchunk_grid = self
+            .array
+            .subchunk_grid()
+            .as_chunk_grid()
+            .ok_or(ArrayError::MissingSubchunkGrid)?;
+        let subchunk_origin = subchunk_grid
+            .chunk_origin(subchunk_indices)?
+            .ok_or_else(|| ArrayError::InvalidChunkGridIndicesError(subchunk_indices.to_vec()))?;
+        let invalid_subchunk =
+            || ArrayError::InvalidChunkGridIndicesError(subchunk_indices.to_vec());
+        let shard_indices = self
+            .array
+            .chunk_grid()
+            .chunk_indices(&subchunk_origin)?
+            .ok_or_else(invalid_subchunk)?;
+        let subchunk_offset = self
+            .array
+            .chunk_grid()
+            .chunk_element_indices(&subchunk_origin)?
+            .ok_or_else(invalid_subchunk)?;
+        let shard_shape = self.array.chunk_shape(&shard_indices)?;
+        let subchunk_shape = sharding.subchunk_shape();
+        let chunks_per_shard = calculate_chunks_per_shard(&shard_shape, subchunk_shape)?;
+        let subchunk_index = std::iter::zip(&subchunk_offset, subchunk_shape)
+            .zip(&chunks_per_shard)
+            .fold(0, |acc, ((offset, subchunk_size), chunks)| {
+                acc * chunks.get() + offset / subchunk_size.get()
+            });
+        let subchunk_index = usize::try_from(subchunk_index).unwrap();
+
+        // Encode the subchunk
+        let subchunk_bytes = subchunk_data.into_array_bytes(self.array.data_type())?;
+        subchunk_bytes.validate(subchunk_shape.num_elements_u64(), self.array.data_type())?;
+        let subchunk_encoded = if subchunk_bytes.is_fill_value(self.array.fill_value()) {
+            None
+        } else {
+            let encoded =
+                sharding
+                    .inner_codecs()
+                    .encode(subchunk_bytes, subchunk_shape, options)?;
+            Some(Bytes::from(encoded.into_owned()))
+        };
+
+        // Select in-memory subchunks to spill and decide where to buffer the subchunk
+        let subchunk_size = subchunk_encoded.as_ref().map_or(0, Bytes::len);
+        let (victims, subchunk_spill_key) = {
+            let mut state = self.state.lock().unwrap();
+            let victims = self.select_spill_victims(&mut state, subchunk_size)?;
+            let subchunk_spill_key = if subchunk_encoded.is_some()
+                && state.memory_usage - state.spilling_size + subchunk_size
+                    > self.options.memory_limit
+            {
+                // The subchunk does not fit in memory even after spilling
+                let spill_id = state.next_spill_id;
+                state.next_spill_id += 1;
+                Some(self.spill_key(&shard_indices, subchunk_index, spill_id)?)
+            } else {
+                None
+            };
+            (victims, subchunk_spill_key)
+        };
+
+        // Spill store I/O is performed without holding the lock
+        self.spill(victims)?;
+        if let (Some(key), Some(subchunk_encoded)) = (&subchunk_spill_key, &subchunk_encoded) {
+            self.spill_store()?.set(key, subchunk_encoded.clone())?;
+        }
+
+        // Buffer the subchunk
+        let (complete_shard, stale_key) = {
+            let mut state = self.state.lock().unwrap();
+            let state = &mut *state;
+            let stale_key = state.remove_subchunk(&shard_indices, subchunk_index);
+            let pending = state.shards.entry(shard_indices.clone()).or_default();
+            if pending.received.is_empty() {
+                pending.received = vec![false; chunks_per_shard.num_elements_usize()];
+            }
+            if let Some(key) = subchunk_spill_key {
+                pending.spilled.insert(subchunk_index, key);
+            } else if let Some(subchunk_encoded) = subchunk_encoded {
+                pending.in_memory.insert(subchunk_index, subchunk_encoded);
+                pending.in_memory_size += subchunk_size;
+                state.memory_usage += subchunk_size;
+            }
+            if !pending.received[subchunk_index] {
+                pending.received[subchunk_index] = true;
+                pending.num_received += 1;
+            }
+            let complete_shard = if pending.num_received == pending.received.len() {
+                state.take_shard(&shard_indices)
+            } else {
+                None
+            };
+            (complete_shard, stale_key)
+        };
+
+        // Erase the spilled copy of a subchunk that was received again
+        if let Some(stale_key) = stale_key {
+            self.spill_store()?.erase(&stale_key)?;
+        }
+        if let Some(pending) = complete_shard {
+            self.write_pending_shard(&shard_indices, pending)?;
+        }
+        Ok(())
+    }
+
+    /// Write the shard at `shard_indices` with the subchunks received so far.
+    ///
+    /// Subchunks that were not received are written as the fill value.
+    /// Does nothing if the shard has no buffered subchunks.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if there is a codec encoding error or an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned.
+    pub fn flush_shard(&self, shard_indices: &[u64]) -> Result<(), ArrayError> {
+        let pending = self.state.lock().unwrap().take_shard(shard_indices);
+        if let Some(pending) = pending {
+            self.write_pending_shard(shard_indices, pending)?;
+        }
+        Ok(())
+    }
+
+    /// Write all pending shards with the subchunks received so far.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if there is a codec encoding error or an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned.
+    pub fn flush(&self) -> Result<(), ArrayError> {
+        for shard_indices in self.pending_shards() {
+            self.flush_shard(&shard_indices)?;
+        }
+        Ok(())
+    }
+
+    /// Select in-memory subchunks of the largest pending shards to move to the spill store until `additional` bytes fit in memory.
+    ///
+    /// The selected subchunks stay in memory until they have been written to the spill store.
+    fn select_spill_victims(
+        &self,
+        state: &mut ArrayShardWriterState,
+        additional: usize,
+    ) -> Result<Vec<SpillVictim>, ArrayError> {
+        let mut victims = Vec::new();
+        if state.memory_usage - state.spilling_size + additional <= self.options.memory_limit {
+            return Ok(victims);
+        }
+        self.spill_store()?;
+        while state.memory_usage - state.spilling_size + additional > self.options.memory_limit {
+            let Some((shard_indices, pending)) = state
+                .shards
+                .iter_mut()
+                .filter(|(_, pending)| pending.in_memory_size > pending.spilling_size)
+                .max_by_key(|(_, pending)| pending.in_memory_size - pending.spilling_size)
+            else {
+                break;
+            };
+            let Some((&subchunk_index, subchunk_encoded)) = pending
+                .in_memory
+                .iter()
+                .find(|(subchunk_index, _)| !pending.spilling.contains_key(subchunk_index))
+            else {
+                break;
+            };
+            let key = self.spill_key(shard_indices, subchunk_index, state.next_spill_id)?;
+            state.next_spill_id += 1;
+            let size = subchunk_encoded.len();
+            pending.spilling.insert(subchunk_index, key.clone());
+            pending.spilling_size += size;
+            state.spilling_size += size;
+            victims.push(SpillVictim {
+                shard_indices: shard_indices.clone(),
+                subchunk_index,
+                key,
+                subchunk_encoded: subchunk_encoded.clone(),
+            });
+        }
+        Ok(victims)
+    }
+
+    /// Move `victims` to the spill store one at a time.
+    ///
+    /// Each subchunk is dropped from memory only once it has been written to the spill store.
+    fn spill(&self, victims: Vec<SpillVictim>) -> Result<(), ArrayError> {
+        if victims.is_empty() {
+            return Ok(());
+        }
+        let spill_store = self.spill_store()?;
+        let mut victims = victims.into_iter();
+        while let Some(victim) = victims.next() {
+            if let Err(err) = spill_store.set(&victim.key, victim.subchunk_encoded.clone()) {
+                let mut state = self.state.lock().unwrap();
+                for victim in std::iter::once(victim).chain(victims) {
+                    state.cancel_spill(&victim);
+                }
+                return Err(err.into());
+            }
+            let spilled = self.state.lock().unwrap().finish_spill(&victim);
+            if !spilled {
+                spill_store.erase(&victim.key)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn spill_store(&self) -> Result<&ReadableWritableStorage, ArrayError> {
+        self.options.spill_store.as_ref().ok_or_else(|| {
+            ArrayError::Other(
+                "the shard writer memory limit was exceeded and no spill store is configured"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Return a spill store key for a subchunk that is unique to each spill.
+    fn spill_key(
+        &self,
+        shard_indices: &[u64],
+        subchunk_index: usize,
+        spill_id: u64,
+    ) -> Result<StoreKey, ArrayError> {
+        let shard_key = self.array.chunk_key(shard_indices);
+        Ok(StoreKey::new(format!(
+            "{}.spill/{subchunk_index}.{spill_id}",
+            shard_key.as_str()
+        ))
+        .map_err(zarrs_storage::StorageError::from)?)
+    }
+
+    /// Write a pending shard and erase its spilled subchunks.
+    ///
+    /// The shard is restored to the pending shards if it could not be written.
+    fn write_pending_shard(
+        &self,
+        shard_indices: &[u64],
+        pending: PendingShard,
+    ) -> Result<(), ArrayError> {
+        if let Err(err) = self.write_shard(shard_indices, &pending) {
+            let stale_keys = self
+                .state
+                .lock()
+                .unwrap()
+                .restore_shard(shard_indices, pending);
+            if let Some(spill_store) = &self.options.spill_store {
+                for key in &stale_keys {
+                    // The write error takes precedence
+                    let _ = spill_store.erase(key);
+                }
+            }
+            return Err(err);
+        }
+        if let Some(spill_store) = &self.options.spill_store {
+            for key in pending.spilled.values() {
+                spill_store.erase(key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Assemble and store a shard from its buffered subchunks.
+    fn write_shard(&self, shard_indices: &[u64], pending: &PendingShard) -> Result<(), ArrayError> {
+        let sharding = self.sharding_codec();
+        let options = self.array.codec_options();
+
+        let mut encoded_subchunks: Vec<(usize, Bytes)> = pending
+            .in_memory
+            .iter()
+            .map(|(subchunk_index, subchunk_encoded)| (*subchunk_index, subchunk_encoded.clone()))
+            .collect();
+        if !pending.spilled.is_empty() {
+            let spill_store = self.spill_store()?;
+            for (subchunk_index, key) in &pending.spilled {
+                let subchunk_encoded = spill_store.get(key)?.ok_or_else(|| {
+                    ArrayError::Other(format!("spilled subchunk {key} is missing"))
+                })?;
+                encoded_subchunks.push((*subchunk_index, subchunk_encoded));
+            }
+        }
+
+        if encoded_subchunks.is_empty() && !options.store_empty_chunks() {
+            self.array.erase_chunk(shard_indices)?;
+        } else {
+            let shard_shape = self.array.chunk_shape(shard_indices)?;
+            let chunks_per_shard =
+                calculate_chunks_per_shard(&shard_shape, sharding.subchunk_shape())?;
+            let mut shard = std::borrow::Cow::Owned(sharding.encode_shard_from_encoded_subchunks(
+                &chunks_per_shard,
+                encoded_subchunks,
+                options,
+            )?);
+            for codec in self.codecs.bytes_to_bytes_codecs() {
+                shard = codec.encode(shard, options)?;
+            }
+            // SAFETY: the shard is encoded with the array codecs
+            unsafe {
+                self.array
+                    .store_encoded_chunk(shard_indices, Bytes::from(shard.into_owned()))
+            }?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{ArrayBuilder, ArraySubset, data_type};
+    use zarrs_storage::store::MemoryStore;
+    use zarrs_storage::{ListableStorageTraits, StorePrefix};
+
+    fn sharded_array(store: Arc<MemoryStore>) -> Arc<Array<MemoryStore>> {
+        Arc::new(
+            ArrayBuilder::new(vec![8, 8], vec![4, 4], data_type::uint16(), 0u16)
+                .subchunk_shape(vec![2, 2])
+                .build(store, "/array")
+                .unwrap(),
+        )
+    }
+
+    fn subchunk_data(i: u64, j: u64) -> Vec<u16> {
+        vec![u16::try_from(i * 4 + j + 1).unwrap(); 4]
+    }
+
+    fn expected_array() -> Vec<u16> {
+        let mut expected = vec![0u16; 64];
+        for (index, value) in expected.iter_mut().enumerate() {
+            let (row, col) = (index / 8, index % 8);
+            *value = u16::try_from((row / 2) * 4 + col / 2 + 1).unwrap();
+        }
+        expected
+    }
+
+    #[test]
+    fn array_shard_writer_complete_shards() {
+        let store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store.clone());
+        let writer = array
+            .shard_writer(ArrayShardWriterOptions::default())
+            .unwrap();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                writer.store_subchunk(&[i, j], subchunk_data(i, j)).unwrap();
+            }
+        }
+        assert!(writer.pending_shards().is_empty());
+        assert_eq!(writer.memory_usage(), 0);
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u16>>(&array.subset_all())
+                .unwrap(),
+            expected_array()
+        );
+
+        // Each shard is compact: exactly 4 subchunks of 8 bytes and the index
+        let shard = array.retrieve_encoded_chunk(&[0, 1]).unwrap().unwrap();
+        assert_eq!(shard.len(), 4 * 8 + 4 * 2 * 8 + 4);
+    }
+
+    #[test]
+    fn array_shard_writer_pending_and_flush() {
+        let store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store.clone());
+        let writer = array
+            .shard_writer(ArrayShardWriterOptions::default())
+            .unwrap();
+
+        writer.store_subchunk(&[0, 0], subchunk_data(0, 0)).unwrap();
+        writer.store_subchunk(&[3, 3], subchunk_data(3, 3)).unwrap();
+        // Fill value subchunks are received, but not buffered
+        writer.store_subchunk(&[0, 1], vec![0u16; 4]).unwrap();
+        assert_eq!(writer.pending_shards(), vec![vec![0, 0], vec![1, 1]]);
+        assert_eq!(writer.memory_usage(), 16);
+        assert!(array.retrieve_encoded_chunk(&[0, 0]).unwrap().is_none());
+
+        writer.flush_shard(&[0, 0]).unwrap();
+        assert_eq!(writer.pending_shards(), vec![vec![1, 1]]);
+        let shard = array.retrieve_chunk::<Vec<u16>>(&[0, 0]).unwrap();
+        assert_eq!(&shard[..2], &[1, 1]);
+        assert_eq!(shard.iter().filter(|v| **v != 0).count(), 4);
+
+        writer.flush().unwrap();
+        assert!(writer.pending_shards().is_empty());
+        let subchunk = array
+            .retrieve_array_subset::<Vec<u16>>(&ArraySubset::new_with_ranges(&[6..8, 6..8]))
+            .unwrap();
+        assert_eq!(subchunk, subchunk_data(3, 3));
+    }
+
+    #[test]
+    fn array_shard_writer_spill() {
+        let store = Arc::new(MemoryStore::new());
+        let spill_store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store.clone());
+        let writer = array
+            .shard_writer(
+                ArrayShardWriterOptions::default()
+                    .with_memory_limit(20)
+                    .with_spill_store(spill_store.clone()),
+            )
+            .unwrap();
+
+        // Interleave subchunks of all shards so that they are all pending
+        for i in (0..4).rev() {
+            for j in 0..4 {
+                writer.store_subchunk(&[j, i], subchunk_data(j, i)).unwrap();
+                assert!(writer.memory_usage() <= 20);
+            }
+        }
+        assert!(writer.pending_shards().is_empty());
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u16>>(&array.subset_all())
+                .unwrap(),
+            expected_array()
+        );
+        // The spill store is cleared once the shards are written
+        assert!(spill_store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn array_shard_writer_spill_replaced() {
+        let store = Arc::new(MemoryStore::new());
+        let spill_store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store);
+        let writer = array
+            .shard_writer(
+                ArrayShardWriterOptions::default()
+                    .with_memory_limit(8)
+                    .with_spill_store(spill_store.clone()),
+            )
+            .unwrap();
+        writer.store_subchunk(&[0, 0], subchunk_data(0, 0)).unwrap();
+        writer.store_subchunk(&[0, 1], subchunk_data(0, 1)).unwrap();
+        assert_eq!(writer.memory_usage(), 8);
+        assert_eq!(spill_store.list().unwrap().len(), 1);
+
+        // The spilled copy is erased when the subchunk is received again
+        writer.store_subchunk(&[0, 0], vec![0u16; 4]).unwrap();
+        assert_eq!(writer.memory_usage(), 8);
+        assert!(spill_store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn array_shard_writer_write_error() {
+        let store = Arc::new(MemoryStore::new());
+        let spill_store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store);
+        let writer = array
+            .shard_writer(
+                ArrayShardWriterOptions::default()
+                    .with_memory_limit(8)
+                    .with_spill_store(spill_store.clone()),
+            )
+            .unwrap();
+        writer.store_subchunk(&[0, 0], subchunk_data(0, 0)).unwrap();
+        writer.store_subchunk(&[0, 1], subchunk_data(0, 1)).unwrap();
+
+        // The shard stays pending if it cannot be written
+        spill_store.erase_prefix(&StorePrefix::root()).unwrap();
+        assert!(writer.flush_shard(&[0, 0]).is_err());
+        assert_eq!(writer.pending_shards(), vec![vec![0, 0]]);
+        assert_eq!(writer.memory_usage(), 8);
+    }
+
+    #[test]
+    fn array_shard_writer_memory_limit_no_spill_store() {
+        let store = Arc::new(MemoryStore::new());
+        let array = sharded_array(store);
+        let writer = array
+            .shard_writer(ArrayShardWriterOptions::default().with_memory_limit(8))
+            .unwrap();
+        writer.store_subchunk(&[0, 0], subchunk_data(0, 0)).unwrap();
+        assert!(writer.store_subchunk(&[0, 1], subchunk_data(0, 1)).is_err());
+    }
+
+    #[test]
+    fn array_shard_writer_unsharded() {
+        let store = Arc::new(MemoryStore::new());
+        let array = Arc::new(
+            ArrayBuilder::new(vec![8, 8], vec![4, 4], data_type::uint16(), 0u16)
+                .build(store, "/array")
+                .unwrap(),
+        );
+        assert!(
+            array
+                .shard_writer(ArrayShardWriterOptions::default())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Retrieval of a single field of a `struct` array.
+
+#[cfg(feature = "async")]
+use zarrs_storage::AsyncReadableStorageTraits;
+use zarrs_storage::ReadableStorageTraits;
+
+use super::data_type::StructDataType;
+use super::{Array, ArrayBytes, ArrayError, ArraySubsetTraits, DataType, FromArrayBytes};
+
+impl<TStorage: ?Sized> Array<TStorage> {
+    /// Return the struct data type of the array and the data type of `field`.
+    fn struct_field_data_type(
+        &self,
+        field: &str,
+    ) -> Result<(&StructDataType, DataType), ArrayError> {
+        let struct_data_type = self
+            .data_type()
+            .downcast_ref::<StructDataType>()
+            .ok_or_else(|| {
+                ArrayError::Other(format!(
+                    "array data type {} is not a struct",
+                    self.data_type()
+                ))
+            })?;
+        let field_data_type = struct_data_type
+            .field(field)
+            .ok_or_else(|| ArrayError::Other(format!("struct data type has no field {field}")))?
+            .data_type()
+            .clone();
+        Ok((struct_data_type, field_data_type))
+    }
+
+    /// Convert the [`ArrayBytes`] of `array_subset` of a struct array to the field `field`.
+    fn struct_field_from_array_bytes<T: FromArrayBytes>(
+        &self,
+        bytes: &ArrayBytes<'_>,
+        array_subset: &dyn ArraySubsetTraits,
+        field: &str,
+    ) -> Result<T, ArrayError> {
+        let (struct_data_type, field_data_type) = self.struct_field_data_type(field)?;
+        let field_bytes = struct_data_type.field_array_bytes(bytes, field)?;
+        let mut shape = array_subset.shape().to_vec();
+        if let Some(field) = struct_data_type.field(field) {
+            shape.extend_from_slice(field.shape());
+        }
+        T::from_array_bytes(field_bytes, &shape, &field_data_type)
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
+    /// Read and decode the `array_subset` of the field `field` of a `struct` array into its requested type.
+    ///
+    /// The shape of the output is the shape of `array_subset` followed by the subarray shape of the field.
+    /// Other fields are decoded from storage but are not converted into the requested type.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if the array data type is not a struct, the field does not exist, or [`retrieve_array_subset`](Array::retrieve_array_subset) fails.
+    pub fn retrieve_array_subset_field<T: FromArrayBytes>(
+        &self,
+        array_subset: &dyn ArraySubsetTraits,
+        field: &str,
+    ) -> Result<T, ArrayError> {
+        self.struct_field_data_type(field)?;
+        let bytes: ArrayBytes<'static> = self.retrieve_array_subset(array_subset)?;
+        self.struct_field_from_array_bytes(&bytes, array_subset, field)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
+    /// Async variant of [`retrieve_array_subset_field`](Array::retrieve_array_subset_field).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_field<T: FromArrayBytes>(
+        &self,
+        array_subset: &dyn ArraySubsetTraits,
+        field: &str,
+    ) -> Result<T, ArrayError> {
+        self.struct_field_data_type(field)?;
+        let bytes: ArrayBytes<'static> = self.async_retrieve_array_subset(array_subset).await?;
+        self.struct_field_from_array_bytes(&bytes, array_subset, field)
+    }
+}
@@ -28,7 +28,11 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Self, ArrayCreateError> {
         let metadata = Self::open_metadata(&storage, path, version)?;
         Self::validate_metadata(&metadata)?;
-        Self::new_with_metadata(storage, path, metadata)
+        #[allow(unused_mut)]
+        let mut array = Self::new_with_metadata(storage, path, metadata)?;
+        #[cfg(feature = "zstd")]
+        array.load_zstd_dictionaries()?;
+        Ok(array)
     }
 
     fn open_metadata(
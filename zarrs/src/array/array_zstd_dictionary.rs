@@ -0,0 +1,142 @@
+//! Storage of `zstd_dictionary` codec compression dictionaries alongside an array.
+
+use zarrs_codec::CodecSpecificOptions;
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_plugin::ExtensionAliasesV3;
+#[cfg(feature = "async")]
+use zarrs_storage::{AsyncReadableStorageTraits, AsyncWritableStorageTraits};
+use zarrs_storage::{ReadableStorageTraits, StorageError, StoreKey, WritableStorageTraits};
+
+use super::codec::{
+    ShardingCodec, ShardingCodecConfiguration, ZstdDictionary, ZstdDictionaryCodec,
+    ZstdDictionaryCodecConfiguration, ZstdDictionaryCodecOptions, is_valid_dictionary_key,
+};
+use super::{Array, ArrayCreateError, ArrayMetadata};
+use crate::node::{NodePath, data_key};
+
+/// Return the keys of the dictionaries referenced by the `zstd_dictionary` codecs in array `metadata`, including the codecs of nested `sharding_indexed` codecs.
+fn zstd_dictionary_keys(metadata: &ArrayMetadata) -> Vec<String> {
+    fn visit(codecs: &[MetadataV3], keys: &mut Vec<String>) {
+        for codec in codecs {
+            if ZstdDictionaryCodec::matches_name_v3(codec.name()) {
+                if let Ok(ZstdDictionaryCodecConfiguration::V0(configuration)) =
+                    codec.to_typed_configuration()
+                    && !keys.contains(&configuration.dictionary)
+                {
+                    keys.push(configuration.dictionary);
+                }
+            } else if ShardingCodec::matches_name_v3(codec.name())
+                && let Ok(ShardingCodecConfiguration::V1(configuration)) =
+                    codec.to_typed_configuration()
+            {
+                visit(&configuration.codecs, keys);
+                visit(&configuration.index_codecs, keys);
+            }
+        }
+    }
+
+    let mut keys = Vec::new();
+    match metadata {
+        ArrayMetadata::V3(metadata) => visit(&metadata.codecs, &mut keys),
+        // The zstd_dictionary codec is not supported in Zarr V2
+        ArrayMetadata::V2(_) => {}
+    }
+    keys
+}
+
+fn zstd_dictionary_store_key(path: &NodePath, key: &str) -> Result<StoreKey, StorageError> {
+    if !is_valid_dictionary_key(key) {
+        return Err(StorageError::Other(format!(
+            "the zstd dictionary key {key} is not a relative key within the array, or collides with array metadata or chunk keys"
+        )));
+    }
+    Ok(data_key(path, &StoreKey::new(key)?))
+}
+
+impl<TStorage: ?Sized> Array<TStorage> {
+    fn set_zstd_dictionaries(
+        &mut self,
+        dictionaries: Vec<(String, ZstdDictionary)>,
+    ) -> Result<(), ArrayCreateError> {
+        if !dictionaries.is_empty() {
+            let options = dictionaries.into_iter().fold(
+                ZstdDictionaryCodecOptions::new(),
+                |options, (key, dictionary)| options.with_dictionary(key, dictionary),
+            );
+            self.set_codec_specific_options(&CodecSpecificOptions::default().with_option(options))?;
+        }
+        Ok(())
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
+    /// Load the compression dictionaries referenced by the `zstd_dictionary` codecs of the array from the store.
+    ///
+    /// Dictionaries are stored relative to the array at the key in the `dictionary` field of the `zstd_dictionary` codec configuration.
+    /// This is called automatically by [`Array::open`] and only needs to be called explicitly if the array was created from metadata.
+    ///
+    /// Missing dictionaries are skipped, and encoding or decoding with a `zstd_dictionary` codec that references a missing dictionary is an error.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayCreateError`] if there is a storage error.
+    pub fn load_zstd_dictionaries(&mut self) -> Result<(), ArrayCreateError> {
+        let mut dictionaries = Vec::new();
+        for key in zstd_dictionary_keys(self.metadata()) {
+            let store_key = zstd_dictionary_store_key(self.path(), &key)?;
+            if let Some(dictionary) = self.storage.get(&store_key)? {
+                dictionaries.push((key, ZstdDictionary::new(dictionary.to_vec())));
+            }
+        }
+        self.set_zstd_dictionaries(dictionaries)
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
+    /// Store a `zstd_dictionary` codec compression dictionary at `key` relative to the array.
+    ///
+    /// `key` should match the [`dictionary_key`](ZstdDictionaryCodec::dictionary_key) of the `zstd_dictionary` codec.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if `key` is not a relative key within the array, collides with array metadata or chunk keys, or there is an underlying store error.
+    pub fn store_zstd_dictionary(
+        &self,
+        key: &str,
+        dictionary: &ZstdDictionary,
+    ) -> Result<(), StorageError> {
+        let store_key = zstd_dictionary_store_key(self.path(), key)?;
+        self.storage
+            .set(&store_key, dictionary.as_bytes().to_vec().into())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
+    /// Async variant of [`load_zstd_dictionaries`](Array::load_zstd_dictionaries).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_load_zstd_dictionaries(&mut self) -> Result<(), ArrayCreateError> {
+        let mut dictionaries = Vec::new();
+        for key in zstd_dictionary_keys(self.metadata()) {
+            let store_key = zstd_dictionary_store_key(self.path(), &key)?;
+            if let Some(dictionary) = self.storage.get(&store_key).await? {
+                dictionaries.push((key, ZstdDictionary::new(dictionary.to_vec())));
+            }
+        }
+        self.set_zstd_dictionaries(dictionaries)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
+    /// Async variant of [`store_zstd_dictionary`](Array::store_zstd_dictionary).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_zstd_dictionary(
+        &self,
+        key: &str,
+        dictionary: &ZstdDictionary,
+    ) -> Result<(), StorageError> {
+        let store_key = zstd_dictionary_store_key(self.path(), key)?;
+        self.storage
+            .set(&store_key, dictionary.as_bytes().to_vec().into())
+            .await
+    }
+}
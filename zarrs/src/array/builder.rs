@@ -331,6 +331,16 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the array-to-array, array-to-bytes, and bytes-to-bytes codecs from a codec chain.
+    ///
+    /// This is a convenience for applying a codec chain selected by a [`CodecChainTuner`](crate::array::CodecChainTuner).
+    pub fn codec_chain(&mut self, codec_chain: &CodecChain) -> &mut Self {
+        self.array_to_array_codecs = codec_chain.array_to_array_codecs().to_vec();
+        self.array_to_bytes_codec = Some(codec_chain.array_to_bytes_codec().clone());
+        self.bytes_to_bytes_codecs = codec_chain.bytes_to_bytes_codecs().to_vec();
+        self
+    }
+
     /// Set the bytes-to-bytes codecs.
     ///
     /// If left unmodified, the array will have no bytes-to-bytes codecs.
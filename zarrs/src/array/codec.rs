@@ -30,10 +30,18 @@ pub use array_to_array::transpose::*;
 // Array to bytes
 pub use array_to_bytes::bytes::*;
 pub use array_to_bytes::codec_chain::{CodecChain, CodecChainBound};
+#[cfg(feature = "jpeg")]
+pub use array_to_bytes::jpeg::*;
+pub use array_to_bytes::json2::*;
+#[cfg(feature = "msgpack")]
+pub use array_to_bytes::msgpack2::*;
 pub use array_to_bytes::optional::*;
 pub use array_to_bytes::packbits::*;
 #[cfg(feature = "pcodec")]
 pub use array_to_bytes::pcodec::*;
+#[cfg(feature = "png")]
+pub use array_to_bytes::png::*;
+pub use array_to_bytes::rle::*;
 pub use array_to_bytes::sharding::*;
 pub use array_to_bytes::vlen::*;
 pub use array_to_bytes::vlen_array::*;
@@ -48,23 +56,39 @@ pub use array_to_bytes::zfpy::*;
 // Bytes to bytes
 #[cfg(feature = "adler32")]
 pub use bytes_to_bytes::adler32::*;
+#[cfg(feature = "aead")]
+pub use bytes_to_bytes::aead::*;
+#[cfg(feature = "bitshuffle")]
+pub use bytes_to_bytes::bitshuffle::*;
 #[cfg(feature = "blosc")]
 pub use bytes_to_bytes::blosc::*;
+#[cfg(feature = "brotli")]
+pub use bytes_to_bytes::brotli::*;
 #[cfg(feature = "bz2")]
 pub use bytes_to_bytes::bz2::*;
+#[cfg(feature = "crc32")]
+pub use bytes_to_bytes::crc32::*;
 #[cfg(feature = "crc32c")]
 pub use bytes_to_bytes::crc32c::*;
+#[cfg(feature = "digest")]
+pub use bytes_to_bytes::digest::*;
 #[cfg(feature = "fletcher32")]
 pub use bytes_to_bytes::fletcher32::*;
 #[cfg(feature = "gdeflate")]
 pub use bytes_to_bytes::gdeflate::*;
 #[cfg(feature = "gzip")]
 pub use bytes_to_bytes::gzip::*;
+#[cfg(feature = "jenkins_lookup3")]
+pub use bytes_to_bytes::jenkins_lookup3::*;
 pub use bytes_to_bytes::shuffle::*;
+#[cfg(feature = "snappy")]
+pub use bytes_to_bytes::snappy::*;
 #[cfg(feature = "zlib")]
 pub use bytes_to_bytes::zlib::*;
 #[cfg(feature = "zstd")]
 pub use bytes_to_bytes::zstd::*;
+#[cfg(feature = "zstd")]
+pub use bytes_to_bytes::zstd_dictionary::*;
 
 mod array_partial_decoder_cache;
 mod bytes_partial_decoder_cache;
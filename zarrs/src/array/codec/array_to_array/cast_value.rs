@@ -37,8 +37,8 @@ impl CodecTraitsV3 for CastValueCodec {
 
 pub use zarrs_data_type::codec_traits::cast_value::{
     CastValueDataTypeExt, CastValueDataTypePlugin, CastValueDataTypeTraits, CastValueIntStored,
-    CastValueKernel, CastValueRepr, impl_cast_value_data_type_traits_float,
-    impl_cast_value_data_type_traits_signed_integer,
+    CastValueKernel, CastValueRepr, impl_cast_value_data_type_traits_complex,
+    impl_cast_value_data_type_traits_float, impl_cast_value_data_type_traits_signed_integer,
     impl_cast_value_data_type_traits_unsigned_integer, select_cast_kernel,
 };
 
@@ -137,6 +137,181 @@ mod tests {
         assert_eq!(encoded_elements, vec![-1i8]);
     }
 
+    #[test]
+    fn codec_cast_value_clamp_uint16_to_uint12() {
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": "uint12",
+                "out_of_range": "clamp"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        let shape = [NonZeroU64::new(3).unwrap()];
+        let data_type = data_type::uint16();
+        let fill_value = FillValue::from(0u16);
+        let codec = codec.with_context(data_type, fill_value).unwrap();
+        let bytes = ArrayBytes::from(crate::array::transmute_to_bytes_vec(vec![0u16, 4095, 4096]));
+
+        let encoded = codec
+            .encode(bytes, &shape, &CodecOptions::default())
+            .unwrap();
+        let encoded_elements = crate::array::transmute_from_bytes_vec::<u16>(
+            encoded.clone().into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(encoded_elements, vec![0u16, 4095, 4095]);
+
+        let decoded = codec
+            .decode(encoded, &shape, &CodecOptions::default())
+            .unwrap();
+        let decoded_elements = crate::array::transmute_from_bytes_vec::<u16>(
+            decoded.into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(decoded_elements, vec![0u16, 4095, 4095]);
+    }
+
+    #[test]
+    fn codec_cast_value_float64_to_decimal() {
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": {"name": "zarrs.decimal", "configuration": {"precision": 6, "scale": 2}},
+                "rounding": "towards-zero",
+                "out_of_range": "clamp"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        let shape = [NonZeroU64::new(4).unwrap()];
+        let data_type = data_type::float64();
+        let fill_value = FillValue::from(0.0f64);
+        let codec = codec.with_context(data_type, fill_value).unwrap();
+        let bytes = ArrayBytes::from(crate::array::transmute_to_bytes_vec(vec![
+            1.239f64, -1.239, 1.1, 1e300,
+        ]));
+
+        let encoded = codec
+            .encode(bytes, &shape, &CodecOptions::default())
+            .unwrap();
+        let encoded_elements = crate::array::transmute_from_bytes_vec::<i32>(
+            encoded.clone().into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(encoded_elements, vec![123i32, -123, 110, 999_999]);
+
+        let decoded = codec
+            .decode(encoded, &shape, &CodecOptions::default())
+            .unwrap();
+        let decoded_elements = crate::array::transmute_from_bytes_vec::<f64>(
+            decoded.into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(decoded_elements, vec![1.23f64, -1.23, 1.1, 9999.99]);
+    }
+
+    #[test]
+    fn codec_cast_value_decimal_to_int16() {
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": "int16"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        let shape = [NonZeroU64::new(4).unwrap()];
+        let data_type = data_type::decimal(std::num::NonZeroU32::new(6).unwrap(), 2).unwrap();
+        let fill_value = FillValue::from(0i32);
+        let codec = codec.with_context(data_type, fill_value).unwrap();
+        let bytes = ArrayBytes::from(crate::array::transmute_to_bytes_vec(vec![
+            150i32, -250, 249, 50,
+        ]));
+
+        let encoded = codec
+            .encode(bytes, &shape, &CodecOptions::default())
+            .unwrap();
+        let encoded_elements = crate::array::transmute_from_bytes_vec::<i16>(
+            encoded.clone().into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(encoded_elements, vec![2i16, -2, 2, 0]);
+
+        let decoded = codec
+            .decode(encoded, &shape, &CodecOptions::default())
+            .unwrap();
+        let decoded_elements = crate::array::transmute_from_bytes_vec::<i32>(
+            decoded.into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(decoded_elements, vec![200i32, -200, 200, 0]);
+    }
+
+    #[test]
+    fn codec_cast_value_complex_int16_to_complex_float32() {
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": "complex_float32"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        let shape = [NonZeroU64::new(2).unwrap()];
+        let data_type = data_type::complex_int16();
+        let fill_value = FillValue::from(num::complex::Complex::new(0i16, 0));
+        let codec = codec.with_context(data_type, fill_value).unwrap();
+        let elements = vec![
+            num::complex::Complex::new(1i16, -2),
+            num::complex::Complex::new(i16::MIN, i16::MAX),
+        ];
+        let bytes = ArrayBytes::from(crate::array::transmute_to_bytes_vec(elements.clone()));
+
+        let encoded = codec
+            .encode(bytes, &shape, &CodecOptions::default())
+            .unwrap();
+        let encoded_elements = crate::array::transmute_from_bytes_vec::<num::complex::Complex32>(
+            encoded.clone().into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(
+            encoded_elements,
+            vec![
+                num::complex::Complex32::new(1.0, -2.0),
+                num::complex::Complex32::new(-32768.0, 32767.0)
+            ]
+        );
+
+        let decoded = codec
+            .decode(encoded, &shape, &CodecOptions::default())
+            .unwrap();
+        let decoded_elements = crate::array::transmute_from_bytes_vec::<num::complex::Complex<i16>>(
+            decoded.into_fixed().unwrap().into_owned(),
+        );
+        assert_eq!(decoded_elements, elements);
+    }
+
+    #[test]
+    fn codec_cast_value_complex_to_real_unsupported() {
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": "float32"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        let fill_value = FillValue::from(num::complex::Complex::new(0i8, 0));
+        assert!(
+            codec
+                .with_context(data_type::complex_int8(), fill_value)
+                .is_err()
+        );
+
+        let configuration: CastValueCodecConfiguration = serde_json::from_str(
+            r#"{
+                "data_type": "complex_int32"
+            }"#,
+        )
+        .unwrap();
+        let codec = CastValueCodec::new_with_configuration(&configuration).unwrap();
+        assert!(
+            codec
+                .with_context(data_type::int32(), FillValue::from(0i32))
+                .is_err()
+        );
+    }
+
     #[test]
     fn codec_cast_value_wrap_float_to_uint8_decodes() {
         let configuration: CastValueCodecConfiguration = serde_json::from_str(
@@ -2,16 +2,29 @@
 
 pub mod bytes;
 pub mod codec_chain;
+pub mod json2;
 pub mod optional;
 pub mod packbits;
+pub mod rle;
 pub mod vlen;
 pub mod vlen_array;
 pub mod vlen_bytes;
 pub mod vlen_utf8;
 pub mod vlen_v2;
 
+mod object_items;
+
+#[cfg(any(feature = "png", feature = "jpeg"))]
+mod image_shape;
+#[cfg(feature = "jpeg")]
+pub mod jpeg;
+
+#[cfg(feature = "msgpack")]
+pub mod msgpack2;
 #[cfg(feature = "pcodec")]
 pub mod pcodec;
+#[cfg(feature = "png")]
+pub mod png;
 pub mod sharding;
 #[cfg(feature = "zfp")]
 pub mod zfp;
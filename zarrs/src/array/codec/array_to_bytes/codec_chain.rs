@@ -114,6 +114,11 @@ impl CodecChain {
             fill_value = bound.encoded_fill_value().clone();
             array_to_array.push(bound);
         }
+        let bytes_to_bytes = self
+            .bytes_to_bytes
+            .iter()
+            .map(|codec| codec.clone().with_data_type(&data_type))
+            .collect::<Result<Vec<_>, _>>()?;
         let array_to_bytes = self.array_to_bytes.with_context(data_type, fill_value)?;
 
         let mut cache_index_must = None;
@@ -163,7 +168,7 @@ impl CodecChain {
         Ok(Arc::new(CodecChainBound {
             array_to_array,
             array_to_bytes,
-            bytes_to_bytes: self.bytes_to_bytes.clone(),
+            bytes_to_bytes,
             cache_index,
         }))
     }
@@ -0,0 +1,91 @@
+//! Interpretation of chunk shapes as images for the image codecs.
+
+use std::num::NonZeroU64;
+
+use zarrs_codec::CodecError;
+
+/// The shape of a chunk interpreted as an image with interleaved channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ImageShape {
+    pub(crate) height: u64,
+    pub(crate) width: u64,
+    pub(crate) channels: u64,
+}
+
+impl ImageShape {
+    /// Interpret a chunk shape as an image.
+    ///
+    /// A chunk is either 2D (`[height, width]`) or 2D with a trailing channel dimension (`[height, width, channels]`), where `channels` is one of `valid_channels`.
+    /// Any dimensions preceding these must have a size of 1.
+    ///
+    /// # Errors
+    /// Returns an error if the chunk shape cannot be interpreted as an image.
+    pub(crate) fn new(
+        shape: &[NonZeroU64],
+        valid_channels: &[u64],
+        codec_name: &str,
+    ) -> Result<Self, CodecError> {
+        let shape: Vec<u64> = shape.iter().map(|s| s.get()).collect();
+        let leading_ones = |n: usize| shape[..shape.len() - n].iter().all(|&s| s == 1);
+        match shape.as_slice() {
+            [.., height, width, channels]
+                if valid_channels.contains(channels) && leading_ones(3) =>
+            {
+                Ok(Self {
+                    height: *height,
+                    width: *width,
+                    channels: *channels,
+                })
+            }
+            [.., height, width] if valid_channels.contains(&1) && leading_ones(2) => Ok(Self {
+                height: *height,
+                width: *width,
+                channels: 1,
+            }),
+            _ => Err(CodecError::Other(format!(
+                "{codec_name} codec cannot interpret chunk shape {shape:?} as an image, expected [height, width] or [height, width, channels] with channels in {valid_channels:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(shape: &[u64]) -> Vec<NonZeroU64> {
+        shape.iter().map(|&s| NonZeroU64::new(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn image_shape() {
+        let image = |s: &[u64]| ImageShape::new(&shape(s), &[1, 3], "test");
+        assert_eq!(
+            image(&[4, 5]).unwrap(),
+            ImageShape {
+                height: 4,
+                width: 5,
+                channels: 1
+            }
+        );
+        assert_eq!(
+            image(&[1, 1, 4, 5, 3]).unwrap(),
+            ImageShape {
+                height: 4,
+                width: 5,
+                channels: 3
+            }
+        );
+        assert_eq!(
+            image(&[1, 4, 5]).unwrap(),
+            ImageShape {
+                height: 4,
+                width: 5,
+                channels: 1
+            }
+        );
+        assert!(image(&[4]).is_err());
+        assert!(image(&[2, 4, 5]).is_err());
+        assert!(image(&[2, 4, 5, 3]).is_err());
+    }
+}
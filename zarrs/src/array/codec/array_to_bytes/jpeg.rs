@@ -0,0 +1,152 @@
+//! The `imagecodecs_jpeg` array to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Lossily encodes a chunk as a baseline [JPEG](https://jpeg.org/jpeg/) image.
+//!
+//! The only supported data type is `uint8`.
+//! A chunk must have the shape `[height, width]` (grayscale) or `[height, width, channels]` with 1 (grayscale) or 3 (RGB) channels.
+//! Any preceding dimensions must have a size of 1, and the height and width must not exceed 65535.
+//!
+//! This codec requires the `jpeg` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! This codec is compatible with the `imagecodecs_jpeg` codec of [`imagecodecs`](https://github.com/cgohlke/imagecodecs) registered with `numcodecs` for 8-bit lossy JPEG.
+//! Lossless JPEG, abbreviated streams (`tables` and `header`), and bit depths other than 8 are not supported.
+//! Other encoding parameters, such as `subsampling` and `colorspace_jpeg`, are ignored.
+//!
+//! ### Specification
+//! - <https://github.com/cgohlke/imagecodecs/blob/master/imagecodecs/numcodecs.py>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.imagecodecs_jpeg`
+//! - `imagecodecs_jpeg`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `imagecodecs_jpeg`
+//!
+//! ### Codec `configuration` Example - [`JpegCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "level": 90
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::jpeg::JpegCodecConfiguration;
+//! # serde_json::from_str::<JpegCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod jpeg_codec;
+
+use std::sync::Arc;
+
+pub use jpeg_codec::JpegCodec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::jpeg::{JpegCodecConfiguration, JpegCodecConfigurationV1};
+
+zarrs_plugin::impl_extension_aliases!(JpegCodec,
+    v3: "numcodecs.imagecodecs_jpeg", ["imagecodecs_jpeg"],
+    v2: "imagecodecs_jpeg"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<JpegCodec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<JpegCodec>()
+}
+
+impl CodecTraitsV3 for JpegCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(JpegCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for JpegCodec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: JpegCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(JpegCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{ArrayBytes, ChunkShapeTraits, DataType, FillValue, data_type};
+    use zarrs_codec::{CodecOptions, UnboundArrayToBytesCodecTraits};
+
+    fn codec_jpeg_round_trip_impl(
+        data_type: DataType,
+        fill_value: impl Into<FillValue>,
+        chunk_shape: &[u64],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let chunk_shape: Vec<NonZeroU64> = chunk_shape
+            .iter()
+            .map(|&s| NonZeroU64::new(s).unwrap())
+            .collect();
+        let size = chunk_shape.num_elements_usize() * data_type.fixed_size().unwrap();
+        let bytes: Vec<u8> = (0..size).map(|s| (s / 8) as u8).collect();
+
+        let codec = Arc::new(JpegCodec::new_with_configuration(&serde_json::from_str(
+            r#"{"level": 100}"#,
+        )?)?)
+        .with_context(data_type, fill_value.into())?;
+        let encoded = codec.encode(
+            ArrayBytes::from(bytes.clone()),
+            &chunk_shape,
+            &CodecOptions::default(),
+        )?;
+        assert_eq!(&encoded[..2], &[0xFF, 0xD8]);
+        let decoded = codec.decode(encoded, &chunk_shape, &CodecOptions::default())?;
+        let decoded = decoded.into_fixed()?;
+        assert_eq!(decoded.len(), bytes.len());
+        // JPEG is lossy
+        for (a, b) in bytes.iter().zip(decoded.iter()) {
+            assert!(a.abs_diff(*b) <= 8, "{a} {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn codec_jpeg_round_trip_u8() {
+        for shape in [
+            vec![16, 24],
+            vec![1, 16, 24],
+            vec![16, 24, 1],
+            vec![16, 24, 3],
+        ] {
+            codec_jpeg_round_trip_impl(data_type::uint8(), 0u8, &shape).unwrap();
+        }
+    }
+
+    #[test]
+    fn codec_jpeg_invalid() {
+        assert!(codec_jpeg_round_trip_impl(data_type::uint16(), 0u16, &[16, 24]).is_err());
+        assert!(codec_jpeg_round_trip_impl(data_type::uint8(), 0u8, &[16, 24, 4]).is_err());
+        assert!(codec_jpeg_round_trip_impl(data_type::uint8(), 0u8, &[16, 70000]).is_err());
+        for configuration in [
+            r#"{"level": 0}"#,
+            r#"{"lossless": true}"#,
+            r#"{"bitspersample": 12}"#,
+            r#"{"tables": "AAAA"}"#,
+        ] {
+            assert!(
+                JpegCodec::new_with_configuration(&serde_json::from_str(configuration).unwrap())
+                    .is_err()
+            );
+        }
+    }
+}
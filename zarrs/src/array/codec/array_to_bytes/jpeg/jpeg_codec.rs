@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+use std::io::Cursor;
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use zarrs_plugin::{ExtensionAliasesV3, PluginCreateError, ZarrVersion};
+
+use super::{JpegCodecConfiguration, JpegCodecConfigurationV1};
+use crate::array::codec::array_to_bytes::image_shape::ImageShape;
+use crate::array::{ChunkShapeTraits, DataType, FillValue, data_type};
+use zarrs_codec::{
+    ArrayBytes, ArrayBytesRaw, ArrayCodecTraits, ArrayToBytesCodecTraits, BytesRepresentation,
+    CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    InvalidBytesLengthError, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency, UnboundArrayToBytesCodecTraits,
+};
+use zarrs_metadata::Configuration;
+
+/// The default JPEG quality.
+const DEFAULT_QUALITY: u8 = 75;
+
+/// An `imagecodecs_jpeg` codec implementation.
+#[derive(Debug, Clone)]
+pub struct JpegCodec {
+    configuration: JpegCodecConfigurationV1,
+}
+
+/// An `imagecodecs_jpeg` codec implementation bound to a data type and fill value.
+#[derive(Debug, Clone)]
+struct JpegCodecBound {
+    quality: u8,
+    data_type: DataType,
+    fill_value: FillValue,
+}
+
+impl JpegCodec {
+    /// Create a new `imagecodecs_jpeg` codec.
+    ///
+    /// `quality` is the JPEG quality (1-100). The default (75) is used if `None`.
+    ///
+    /// # Errors
+    /// Returns an error if `quality` is not between 1 and 100.
+    pub fn new(quality: Option<u8>) -> Result<Self, PluginCreateError> {
+        Self::new_with_configuration(&JpegCodecConfiguration::V1(JpegCodecConfigurationV1 {
+            level: quality,
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new `imagecodecs_jpeg` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &JpegCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            JpegCodecConfiguration::V1(configuration) => {
+                if configuration
+                    .level
+                    .is_some_and(|level| !(1..=100).contains(&level))
+                {
+                    return Err(PluginCreateError::Other(
+                        "imagecodecs_jpeg level must be between 1 and 100".to_string(),
+                    ));
+                }
+                if configuration.lossless == Some(true) {
+                    return Err(PluginCreateError::Other(
+                        "imagecodecs_jpeg lossless JPEG is not supported".to_string(),
+                    ));
+                }
+                if configuration.bitspersample.is_some_and(|bits| bits != 8) {
+                    return Err(PluginCreateError::Other(
+                        "imagecodecs_jpeg only supports 8 bits per sample".to_string(),
+                    ));
+                }
+                if configuration.tables.is_some() || configuration.header.is_some() {
+                    return Err(PluginCreateError::Other(
+                        "imagecodecs_jpeg abbreviated streams (tables and header) are not supported"
+                            .to_string(),
+                    ));
+                }
+                Ok(Self {
+                    configuration: configuration.clone(),
+                })
+            }
+            _ => Err(PluginCreateError::Other(
+                "this imagecodecs_jpeg codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for JpegCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        Some(JpegCodecConfiguration::V1(self.configuration.clone()).into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+impl UnboundArrayToBytesCodecTraits for JpegCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn UnboundArrayToBytesCodecTraits> {
+        self as Arc<dyn UnboundArrayToBytesCodecTraits>
+    }
+
+    fn with_context(
+        &self,
+        data_type: DataType,
+        fill_value: FillValue,
+    ) -> Result<Arc<dyn ArrayToBytesCodecTraits>, CodecCreateError> {
+        if !data_type.is::<data_type::UInt8DataType>() {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type,
+                JpegCodec::aliases_v3().default_name.to_string(),
+            ));
+        }
+        Ok(Arc::new(JpegCodecBound {
+            quality: self.configuration.level.unwrap_or(DEFAULT_QUALITY),
+            data_type,
+            fill_value,
+        }))
+    }
+}
+
+fn image_shape(shape: &[NonZeroU64]) -> Result<(ImageShape, u16, u16), CodecError> {
+    let image_shape = ImageShape::new(shape, &[1, 3], &JpegCodec::aliases_v3().default_name)?;
+    if let (Ok(height), Ok(width)) = (
+        u16::try_from(image_shape.height),
+        u16::try_from(image_shape.width),
+    ) {
+        Ok((image_shape, height, width))
+    } else {
+        Err(CodecError::Other(format!(
+            "imagecodecs_jpeg codec does not support images with dimensions {}x{}",
+            image_shape.height, image_shape.width
+        )))
+    }
+}
+
+impl ArrayCodecTraits for JpegCodecBound {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn fill_value(&self) -> &FillValue {
+        &self.fill_value
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+impl zarrs_codec::ArrayToBytesCodecNoSubchunkingTraits for JpegCodecBound {}
+
+impl ArrayToBytesCodecTraits for JpegCodecBound {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let (image_shape, height, width) = image_shape(shape)?;
+        let bytes = bytes.into_fixed()?;
+        let color_type = if image_shape.channels == 1 {
+            jpeg_encoder::ColorType::Luma
+        } else {
+            jpeg_encoder::ColorType::Rgb
+        };
+
+        let mut encoded = Vec::new();
+        jpeg_encoder::Encoder::new(&mut encoded, self.quality)
+            .encode(&bytes, width, height, color_type)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        Ok(Cow::Owned(encoded))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytesRaw<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let (image_shape, height, width) = image_shape(shape)?;
+        let mut image_decoder = jpeg_decoder::Decoder::new(Cursor::new(bytes.as_ref()));
+        let decoded = image_decoder
+            .decode()
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        let info = image_decoder
+            .info()
+            .ok_or_else(|| CodecError::Other("imagecodecs_jpeg image has no header".to_string()))?;
+
+        let pixel_format_channels = match info.pixel_format {
+            jpeg_decoder::PixelFormat::L8 => 1,
+            jpeg_decoder::PixelFormat::RGB24 => 3,
+            jpeg_decoder::PixelFormat::L16 | jpeg_decoder::PixelFormat::CMYK32 => 0,
+        };
+        if pixel_format_channels != image_shape.channels
+            || info.width != width
+            || info.height != height
+        {
+            return Err(CodecError::Other(format!(
+                "imagecodecs_jpeg decoded a {}x{} {:?} image incompatible with the chunk shape {shape:?}",
+                info.height, info.width, info.pixel_format
+            )));
+        }
+        let expected_len = shape.num_elements_usize();
+        if decoded.len() != expected_len {
+            return Err(InvalidBytesLengthError::new(decoded.len(), expected_len).into());
+        }
+        Ok(ArrayBytes::from(decoded))
+    }
+
+    fn encoded_representation(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<BytesRepresentation, CodecError> {
+        Ok(BytesRepresentation::UnboundedSize)
+    }
+}
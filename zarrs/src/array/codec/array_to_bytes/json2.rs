@@ -0,0 +1,258 @@
+//! The `numcodecs.json2` array to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Encodes the elements of an object (`|O`) array as a JSON list, followed by the `numpy` dtype string and the chunk shape.
+//!
+//! Supported data types are `string` and [`zarrs.json`](crate::array::data_type::JsonDataType).
+//! If a `string` array holds elements that are not strings, they are decoded to their JSON representation.
+//! Use the `zarrs.json` data type to retrieve such elements as [`serde_json::Value`].
+//!
+//! Non-finite floating point values (`NaN`, `Infinity`) are not supported.
+//!
+//! ### Compatible Implementations
+//! This codec is compatible with the `numcodecs` `JSON` codec, which is used as an object codec (filter) in Zarr V2 arrays with a `|O` dtype.
+//! The `ensure_ascii`, `indent`, `separators`, and `sort_keys` encoding parameters are supported.
+//! An `indent` or `separators` that would not produce valid JSON is rejected.
+//!
+//! ### Specification
+//! - <https://numcodecs.readthedocs.io/en/stable/other/json.html>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.json2`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `json2`
+//!
+//! ### Codec `configuration` Example - [`Json2CodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "encoding": "utf-8",
+//!     "sort_keys": true
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::json2::Json2CodecConfiguration;
+//! # serde_json::from_str::<Json2CodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod json2_codec;
+mod json2_format;
+
+use std::sync::Arc;
+
+pub use json2_codec::Json2Codec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::json2::{Json2CodecConfiguration, Json2CodecConfigurationV1};
+
+zarrs_plugin::impl_extension_aliases!(Json2Codec,
+    v3: "numcodecs.json2", [],
+    v2: "json2"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<Json2Codec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<Json2Codec>()
+}
+
+impl CodecTraitsV3 for Json2Codec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(Json2Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for Json2Codec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: Json2CodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(Json2Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{Element, ElementOwned, FillValue, data_type};
+    use zarrs_codec::{CodecOptions, UnboundArrayToBytesCodecTraits};
+
+    fn shape(shape: &[u64]) -> Vec<NonZeroU64> {
+        shape.iter().map(|&s| NonZeroU64::new(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn codec_json2_string() {
+        let data_type = data_type::string();
+        let codec = Arc::new(Json2Codec::new())
+            .with_context(data_type.clone(), FillValue::from(""))
+            .unwrap();
+        let chunk_shape = shape(&[2, 2]);
+        let elements = ["a", "bb", "", "ccc"];
+        let bytes = <&str>::to_array_bytes(&data_type, &elements).unwrap();
+        let encoded = codec
+            .encode(bytes.clone(), &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            std::str::from_utf8(&encoded).unwrap(),
+            r#"[["a","bb"],["","ccc"],"|O",[2,2]]"#
+        );
+        let decoded = codec
+            .decode(encoded, &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded);
+    }
+
+    #[test]
+    fn codec_json2_numcodecs() {
+        // numcodecs.JSON().encode(np.array(["a", 1, {"b": None}], dtype=object))
+        let encoded = br#"["a",1,{"b":null},"|O",[3]]"#;
+        let chunk_shape = shape(&[3]);
+
+        let codec = Arc::new(Json2Codec::new())
+            .with_context(data_type::string(), FillValue::from(""))
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded.to_vec().into(),
+                &chunk_shape,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            String::from_array_bytes(&data_type::string(), decoded).unwrap(),
+            ["a", "1", r#"{"b":null}"#]
+        );
+
+        let data_type = data_type::json();
+        let codec = Arc::new(Json2Codec::new())
+            .with_context(data_type.clone(), FillValue::from("null".as_bytes()))
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded.to_vec().into(),
+                &chunk_shape,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let elements = serde_json::Value::from_array_bytes(&data_type, decoded.clone()).unwrap();
+        assert_eq!(
+            elements,
+            [
+                serde_json::json!("a"),
+                serde_json::json!(1),
+                serde_json::json!({"b": null})
+            ]
+        );
+        let encoded_round_trip = codec
+            .encode(decoded, &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded_round_trip.as_ref(), encoded);
+    }
+
+    #[test]
+    fn codec_json2_format() {
+        let data_type = data_type::json();
+        let elements = [
+            serde_json::json!({"a": "\u{7f}\u{1f600}", "b": ["é", []]}),
+            serde_json::json!({}),
+        ];
+        let bytes = serde_json::Value::to_array_bytes(&data_type, &elements).unwrap();
+        let chunk_shape = shape(&[2]);
+        let encode = |configuration: serde_json::Value| {
+            let configuration: Json2CodecConfiguration =
+                serde_json::from_value(configuration).unwrap();
+            let codec = Arc::new(Json2Codec::new_with_configuration(&configuration).unwrap())
+                .with_context(data_type.clone(), FillValue::from("null".as_bytes()))
+                .unwrap();
+            let encoded = codec
+                .encode(bytes.clone(), &chunk_shape, &CodecOptions::default())
+                .unwrap();
+            let decoded = codec
+                .decode(encoded.clone(), &chunk_shape, &CodecOptions::default())
+                .unwrap();
+            assert_eq!(decoded, bytes);
+            String::from_utf8(encoded.to_vec()).unwrap()
+        };
+
+        // numcodecs.JSON().encode(...)
+        assert_eq!(
+            encode(serde_json::json!({})),
+            r#"[{"a":"\u007f\ud83d\ude00","b":["\u00e9",[]]},{},"|O",[2]]"#
+        );
+        // numcodecs.JSON(ensure_ascii=False, separators=(", ", ": ")).encode(...)
+        assert_eq!(
+            encode(serde_json::json!({"ensure_ascii": false, "separators": [", ", ": "]})),
+            "[{\"a\": \"\u{7f}\u{1f600}\", \"b\": [\"é\", []]}, {}, \"|O\", [2]]"
+        );
+        // numcodecs.JSON(indent=0).encode(...), the default separators have trailing whitespace if indent is set
+        assert!(
+            encode(serde_json::json!({"indent": 0}))
+                .ends_with("},\x20\n{},\x20\n\"|O\",\x20\n[\n2\n]\n]")
+        );
+        // numcodecs.JSON(indent=2, separators=(",", ": ")).encode(...)
+        assert_eq!(
+            encode(serde_json::json!({"indent": 2, "separators": [",", ": "]})),
+            r#"[
+  {
+    "a": "\u007f\ud83d\ude00",
+    "b": [
+      "\u00e9",
+      []
+    ]
+  },
+  {},
+  "|O",
+  [
+    2
+  ]
+]"#
+        );
+
+        for configuration in [
+            serde_json::json!({"indent": "-"}),
+            serde_json::json!({"indent": 1.5}),
+            serde_json::json!({"separators": [";", ":"]}),
+            serde_json::json!({"separators": [","]}),
+        ] {
+            let configuration: Json2CodecConfiguration =
+                serde_json::from_value(configuration).unwrap();
+            assert!(Json2Codec::new_with_configuration(&configuration).is_err());
+        }
+    }
+
+    #[test]
+    fn codec_json2_invalid() {
+        assert!(
+            Arc::new(Json2Codec::new())
+                .with_context(data_type::uint8(), FillValue::from(0u8))
+                .is_err()
+        );
+        let codec = Arc::new(Json2Codec::new())
+            .with_context(data_type::string(), FillValue::from(""))
+            .unwrap();
+        for encoded in [&br#"["a","|O",[2]]"#[..], br#"["a","b"]"#, br"{}", b"NaN"] {
+            assert!(
+                codec
+                    .decode(
+                        encoded.to_vec().into(),
+                        &shape(&[2]),
+                        &CodecOptions::default()
+                    )
+                    .is_err()
+            );
+        }
+    }
+}
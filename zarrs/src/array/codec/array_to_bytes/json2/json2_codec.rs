@@ -0,0 +1,204 @@
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use zarrs_plugin::{ExtensionAliasesV3, PluginCreateError, ZarrVersion};
+
+use super::json2_format::Json2Format;
+use super::{Json2CodecConfiguration, Json2CodecConfigurationV1};
+use crate::array::codec::array_to_bytes::object_items::{
+    ObjectElements, items_to_values, values_to_items,
+};
+use crate::array::{DataType, FillValue};
+use zarrs_codec::{
+    ArrayBytes, ArrayBytesRaw, ArrayCodecTraits, ArrayToBytesCodecTraits, BytesRepresentation,
+    CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+    UnboundArrayToBytesCodecTraits,
+};
+use zarrs_metadata::Configuration;
+
+/// A `numcodecs.json2` codec implementation.
+#[derive(Debug, Clone)]
+pub struct Json2Codec {
+    configuration: Json2CodecConfigurationV1,
+    format: Json2Format,
+}
+
+/// A `numcodecs.json2` codec implementation bound to a data type and fill value.
+#[derive(Debug, Clone)]
+struct Json2CodecBound {
+    elements: ObjectElements,
+    sort_keys: bool,
+    format: Json2Format,
+    data_type: DataType,
+    fill_value: FillValue,
+}
+
+impl Default for Json2Codec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Json2Codec {
+    /// Create a new `numcodecs.json2` codec with the `numcodecs` default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            configuration: Json2CodecConfigurationV1 {
+                encoding: Some("utf-8".to_string()),
+                skipkeys: Some(false),
+                ensure_ascii: Some(true),
+                check_circular: Some(true),
+                allow_nan: Some(true),
+                indent: Some(serde_json::Value::Null),
+                separators: Some(vec![",".to_string(), ":".to_string()]),
+                sort_keys: Some(true),
+                strict: Some(true),
+            },
+            format: Json2Format::default(),
+        }
+    }
+
+    /// Create a new `numcodecs.json2` codec from configuration.
+    ///
+    /// The `ensure_ascii`, `indent`, and `separators` parameters are applied when encoding, as with the Python `json` module.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported, including an `indent` or `separators` that would not produce valid JSON.
+    pub fn new_with_configuration(
+        configuration: &Json2CodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            Json2CodecConfiguration::V1(configuration) => {
+                if let Some(encoding) = &configuration.encoding
+                    && !matches!(encoding.to_lowercase().as_str(), "utf-8" | "utf8")
+                {
+                    return Err(PluginCreateError::Other(format!(
+                        "json2 encoding {encoding} is unsupported, expected utf-8"
+                    )));
+                }
+                Ok(Self {
+                    configuration: configuration.clone(),
+                    format: Json2Format::new(configuration)?,
+                })
+            }
+            _ => Err(PluginCreateError::Other(
+                "this json2 codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for Json2Codec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        Some(Json2CodecConfiguration::V1(self.configuration.clone()).into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+impl UnboundArrayToBytesCodecTraits for Json2Codec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn UnboundArrayToBytesCodecTraits> {
+        self as Arc<dyn UnboundArrayToBytesCodecTraits>
+    }
+
+    fn with_context(
+        &self,
+        data_type: DataType,
+        fill_value: FillValue,
+    ) -> Result<Arc<dyn ArrayToBytesCodecTraits>, CodecCreateError> {
+        let Some(elements) = ObjectElements::new(&data_type) else {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type,
+                Self::aliases_v3().default_name.to_string(),
+            ));
+        };
+        Ok(Arc::new(Json2CodecBound {
+            elements,
+            sort_keys: self.configuration.sort_keys.unwrap_or(true),
+            format: self.format.clone(),
+            data_type,
+            fill_value,
+        }))
+    }
+}
+
+impl ArrayCodecTraits for Json2CodecBound {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn fill_value(&self) -> &FillValue {
+        &self.fill_value
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+impl zarrs_codec::ArrayToBytesCodecNoSubchunkingTraits for Json2CodecBound {}
+
+impl ArrayToBytesCodecTraits for Json2CodecBound {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let num_elements = shape.iter().map(|d| d.get()).product::<u64>();
+        bytes.validate(num_elements, &self.data_type)?;
+        let values = self.elements.to_values(bytes, self.sort_keys)?;
+        let items = values_to_items(values, shape);
+        self.format
+            .to_vec(&items)
+            .map(Into::into)
+            .map_err(|err| CodecError::Other(err.to_string()))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytesRaw<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let items: Vec<serde_json::Value> =
+            serde_json::from_slice(&bytes).map_err(|err| CodecError::Other(err.to_string()))?;
+        let values = items_to_values(items, shape)?;
+        self.elements.to_array_bytes(&values)
+    }
+
+    fn encoded_representation(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<BytesRepresentation, CodecError> {
+        Ok(BytesRepresentation::UnboundedSize)
+    }
+}
@@ -0,0 +1,214 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+use serde_json::ser::Formatter;
+use zarrs_plugin::PluginCreateError;
+
+use super::Json2CodecConfigurationV1;
+
+/// The output format of the `json2` codec, matching the Python `json.dumps` `ensure_ascii`, `indent` and `separators` parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Json2Format {
+    ensure_ascii: bool,
+    indent: Option<String>,
+    item_separator: String,
+    key_separator: String,
+}
+
+impl Default for Json2Format {
+    fn default() -> Self {
+        Self {
+            ensure_ascii: true,
+            indent: None,
+            item_separator: ",".to_string(),
+            key_separator: ":".to_string(),
+        }
+    }
+}
+
+impl Json2Format {
+    /// Create the output format from `json2` codec configuration, using the `numcodecs` defaults for unset parameters.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `indent` is not an integer, string, or null, or if `separators` would not produce valid JSON.
+    pub(super) fn new(
+        configuration: &Json2CodecConfigurationV1,
+    ) -> Result<Self, PluginCreateError> {
+        let indent = match &configuration.indent {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(indent)) => Some(indent.clone()),
+            Some(serde_json::Value::Number(indent)) if indent.is_i64() => {
+                let indent = usize::try_from(indent.as_i64().unwrap_or_default()).unwrap_or(0);
+                Some(" ".repeat(indent))
+            }
+            Some(indent) => {
+                return Err(PluginCreateError::Other(format!(
+                    "json2 indent {indent} is unsupported, expected an integer, string, or null"
+                )));
+            }
+        };
+        if let Some(indent) = &indent
+            && !indent.chars().all(is_json_whitespace)
+        {
+            return Err(PluginCreateError::Other(format!(
+                "json2 indent {indent:?} is unsupported, expected whitespace"
+            )));
+        }
+
+        // numcodecs defaults to the most compact separators if indent is unset
+        let (item_separator, key_separator) = match configuration.separators.as_deref() {
+            None if indent.is_none() => (",".to_string(), ":".to_string()),
+            None => (", ".to_string(), ": ".to_string()),
+            Some([item_separator, key_separator]) => {
+                if item_separator.trim_matches(is_json_whitespace) != ","
+                    || key_separator.trim_matches(is_json_whitespace) != ":"
+                {
+                    return Err(PluginCreateError::Other(format!(
+                        "json2 separators [{item_separator:?}, {key_separator:?}] are unsupported, expected a comma and a colon surrounded by whitespace"
+                    )));
+                }
+                (item_separator.clone(), key_separator.clone())
+            }
+            Some(separators) => {
+                return Err(PluginCreateError::Other(format!(
+                    "json2 separators {separators:?} are unsupported, expected an item and a key separator"
+                )));
+            }
+        };
+
+        Ok(Self {
+            ensure_ascii: configuration.ensure_ascii.unwrap_or(true),
+            indent,
+            item_separator,
+            key_separator,
+        })
+    }
+
+    /// Serialize `value` to JSON in this format.
+    pub(super) fn to_vec<T: Serialize>(&self, value: &T) -> serde_json::Result<Vec<u8>> {
+        let mut writer = Vec::new();
+        let formatter = Json2Formatter {
+            format: self,
+            current_indent: 0,
+            has_value: false,
+        };
+        let mut serializer = serde_json::Serializer::with_formatter(&mut writer, formatter);
+        value.serialize(&mut serializer)?;
+        Ok(writer)
+    }
+}
+
+fn is_json_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// A [`Formatter`] following the Python `json` module encoder.
+struct Json2Formatter<'a> {
+    format: &'a Json2Format,
+    current_indent: usize,
+    has_value: bool,
+}
+
+impl Json2Formatter<'_> {
+    fn write_newline_indent<W: ?Sized + Write>(&self, writer: &mut W) -> io::Result<()> {
+        if let Some(indent) = &self.format.indent {
+            writer.write_all(b"\n")?;
+            for _ in 0..self.current_indent {
+                writer.write_all(indent.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn begin_item<W: ?Sized + Write>(&self, writer: &mut W, first: bool) -> io::Result<()> {
+        if !first {
+            writer.write_all(self.format.item_separator.as_bytes())?;
+        }
+        self.write_newline_indent(writer)
+    }
+
+    fn end_container<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.current_indent -= 1;
+        if self.has_value {
+            self.write_newline_indent(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Formatter for Json2Formatter<'_> {
+    fn begin_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write_all(b"[")
+    }
+
+    fn end_array<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.end_container(writer)?;
+        writer.write_all(b"]")
+    }
+
+    fn begin_array_value<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.begin_item(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn begin_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write_all(b"{")
+    }
+
+    fn end_object<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.end_container(writer)?;
+        writer.write_all(b"}")
+    }
+
+    fn begin_object_key<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        self.begin_item(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.format.key_separator.as_bytes())
+    }
+
+    fn end_object_value<W: ?Sized + Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn write_string_fragment<W: ?Sized + Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        if !self.format.ensure_ascii {
+            return writer.write_all(fragment.as_bytes());
+        }
+        // Python escapes everything outside of the printable ASCII range
+        let mut start = 0;
+        for (i, c) in fragment.char_indices() {
+            if !(' '..='~').contains(&c) {
+                writer.write_all(&fragment.as_bytes()[start..i])?;
+                let mut utf16 = [0u16; 2];
+                for unit in c.encode_utf16(&mut utf16) {
+                    write!(writer, "\\u{unit:04x}")?;
+                }
+                start = i + c.len_utf8();
+            }
+        }
+        writer.write_all(&fragment.as_bytes()[start..])
+    }
+}
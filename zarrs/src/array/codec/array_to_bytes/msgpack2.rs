@@ -0,0 +1,192 @@
+//! The `numcodecs.msgpack2` array to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Encodes the elements of an object (`|O`) array as a [MessagePack](https://msgpack.org/) array, followed by the `numpy` dtype string and the chunk shape.
+//!
+//! Supported data types are `string` and [`zarrs.json`](crate::array::data_type::JsonDataType).
+//! If a `string` array holds elements that are not strings, they are decoded to their JSON representation.
+//! Use the `zarrs.json` data type to retrieve such elements as [`serde_json::Value`].
+//!
+//! `msgpack` binary values are decoded as strings and must be valid UTF-8.
+//! Extension values, non-string map keys, and non-finite floating point values are not supported.
+//!
+//! ### Compatible Implementations
+//! This codec is compatible with the `numcodecs` `MsgPack` codec, which is used as an object codec (filter) in Zarr V2 arrays with a `|O` dtype.
+//!
+//! ### Specification
+//! - <https://numcodecs.readthedocs.io/en/stable/other/msgpack.html>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.msgpack2`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `msgpack2`
+//!
+//! ### Codec `configuration` Example - [`MsgPack2CodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "use_single_float": false,
+//!     "use_bin_type": true,
+//!     "raw": false
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::msgpack2::MsgPack2CodecConfiguration;
+//! # serde_json::from_str::<MsgPack2CodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod msgpack2_codec;
+
+use std::sync::Arc;
+
+pub use msgpack2_codec::MsgPack2Codec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::msgpack2::{
+    MsgPack2CodecConfiguration, MsgPack2CodecConfigurationV1,
+};
+
+zarrs_plugin::impl_extension_aliases!(MsgPack2Codec,
+    v3: "numcodecs.msgpack2", [],
+    v2: "msgpack2"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<MsgPack2Codec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<MsgPack2Codec>()
+}
+
+impl CodecTraitsV3 for MsgPack2Codec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(MsgPack2Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for MsgPack2Codec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: MsgPack2CodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(MsgPack2Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{Element, ElementOwned, FillValue, data_type};
+    use zarrs_codec::{CodecOptions, UnboundArrayToBytesCodecTraits};
+
+    fn shape(shape: &[u64]) -> Vec<NonZeroU64> {
+        shape.iter().map(|&s| NonZeroU64::new(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn codec_msgpack2_round_trip() {
+        let data_type = data_type::json();
+        let codec = Arc::new(MsgPack2Codec::new())
+            .with_context(data_type.clone(), FillValue::from("null".as_bytes()))
+            .unwrap();
+        let chunk_shape = shape(&[2, 3]);
+        let elements = [
+            serde_json::json!("a"),
+            serde_json::json!(-1),
+            serde_json::json!(1.5),
+            serde_json::json!(null),
+            serde_json::json!([true, false]),
+            serde_json::json!({"a": [1, 2], "b": "c"}),
+        ];
+        let bytes = serde_json::Value::to_array_bytes(&data_type, &elements).unwrap();
+        let encoded = codec
+            .encode(bytes.clone(), &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        let decoded = codec
+            .decode(encoded, &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            serde_json::Value::from_array_bytes(&data_type, decoded).unwrap(),
+            elements
+        );
+    }
+
+    #[test]
+    fn codec_msgpack2_numcodecs() {
+        // numcodecs.MsgPack().encode(np.array(["a", 1], dtype=object))
+        let encoded = [0x94, 0xa1, b'a', 0x01, 0xa2, b'|', b'O', 0x91, 0x02];
+        let chunk_shape = shape(&[2]);
+
+        let codec = Arc::new(MsgPack2Codec::new())
+            .with_context(data_type::string(), FillValue::from(""))
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded.to_vec().into(),
+                &chunk_shape,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            String::from_array_bytes(&data_type::string(), decoded).unwrap(),
+            ["a", "1"]
+        );
+
+        let data_type = data_type::json();
+        let codec = Arc::new(MsgPack2Codec::new())
+            .with_context(data_type.clone(), FillValue::from("null".as_bytes()))
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded.to_vec().into(),
+                &chunk_shape,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let encoded_round_trip = codec
+            .encode(decoded, &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded_round_trip.as_ref(), encoded);
+    }
+
+    #[test]
+    fn codec_msgpack2_invalid() {
+        assert!(
+            Arc::new(MsgPack2Codec::new())
+                .with_context(data_type::uint8(), FillValue::from(0u8))
+                .is_err()
+        );
+        let codec = Arc::new(MsgPack2Codec::new())
+            .with_context(data_type::string(), FillValue::from(""))
+            .unwrap();
+        for encoded in [
+            &[0x93, 0xa1, b'a', 0xa2, b'|', b'O', 0x91, 0x02][..], // too few elements
+            &[0x92, 0xa1, b'a', 0xa1, b'b'],                       // missing dtype and shape
+            &[
+                0x94, 0xd4, 0x01, 0x00, 0xa1, b'b', 0xa2, b'|', b'O', 0x91, 0x02,
+            ], // ext
+            &[0x94],                                               // truncated
+        ] {
+            assert!(
+                codec
+                    .decode(
+                        encoded.to_vec().into(),
+                        &shape(&[2]),
+                        &CodecOptions::default()
+                    )
+                    .is_err()
+            );
+        }
+    }
+}
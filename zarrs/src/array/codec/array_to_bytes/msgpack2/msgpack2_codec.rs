@@ -0,0 +1,310 @@
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use zarrs_plugin::{ExtensionAliasesV3, PluginCreateError, ZarrVersion};
+
+use super::{MsgPack2CodecConfiguration, MsgPack2CodecConfigurationV1};
+use crate::array::codec::array_to_bytes::object_items::{
+    ObjectElements, items_to_values, values_to_items,
+};
+use crate::array::{DataType, FillValue};
+use zarrs_codec::{
+    ArrayBytes, ArrayBytesRaw, ArrayCodecTraits, ArrayToBytesCodecTraits, BytesRepresentation,
+    CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+    UnboundArrayToBytesCodecTraits,
+};
+use zarrs_metadata::Configuration;
+
+/// A `numcodecs.msgpack2` codec implementation.
+#[derive(Debug, Clone)]
+pub struct MsgPack2Codec {
+    configuration: MsgPack2CodecConfigurationV1,
+}
+
+/// A `numcodecs.msgpack2` codec implementation bound to a data type and fill value.
+#[derive(Debug, Clone)]
+struct MsgPack2CodecBound {
+    elements: ObjectElements,
+    use_single_float: bool,
+    data_type: DataType,
+    fill_value: FillValue,
+}
+
+impl Default for MsgPack2Codec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MsgPack2Codec {
+    /// Create a new `numcodecs.msgpack2` codec with the `numcodecs` default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            configuration: MsgPack2CodecConfigurationV1 {
+                use_single_float: Some(false),
+                use_bin_type: Some(true),
+                raw: Some(false),
+            },
+        }
+    }
+
+    /// Create a new `numcodecs.msgpack2` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &MsgPack2CodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            MsgPack2CodecConfiguration::V1(configuration) => Ok(Self {
+                configuration: *configuration,
+            }),
+            _ => Err(PluginCreateError::Other(
+                "this msgpack2 codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for MsgPack2Codec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        Some(MsgPack2CodecConfiguration::V1(self.configuration).into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+impl UnboundArrayToBytesCodecTraits for MsgPack2Codec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn UnboundArrayToBytesCodecTraits> {
+        self as Arc<dyn UnboundArrayToBytesCodecTraits>
+    }
+
+    fn with_context(
+        &self,
+        data_type: DataType,
+        fill_value: FillValue,
+    ) -> Result<Arc<dyn ArrayToBytesCodecTraits>, CodecCreateError> {
+        let Some(elements) = ObjectElements::new(&data_type) else {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type,
+                Self::aliases_v3().default_name.to_string(),
+            ));
+        };
+        Ok(Arc::new(MsgPack2CodecBound {
+            elements,
+            use_single_float: self.configuration.use_single_float.unwrap_or(false),
+            data_type,
+            fill_value,
+        }))
+    }
+}
+
+impl ArrayCodecTraits for MsgPack2CodecBound {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn fill_value(&self) -> &FillValue {
+        &self.fill_value
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+impl zarrs_codec::ArrayToBytesCodecNoSubchunkingTraits for MsgPack2CodecBound {}
+
+impl ArrayToBytesCodecTraits for MsgPack2CodecBound {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let num_elements = shape.iter().map(|d| d.get()).product::<u64>();
+        bytes.validate(num_elements, &self.data_type)?;
+        let values = self.elements.to_values(bytes, false)?;
+        let items = values_to_items(values, shape);
+        let items = json_to_msgpack(&serde_json::Value::Array(items), self.use_single_float)?;
+        let mut encoded = Vec::new();
+        rmpv::encode::write_value(&mut encoded, &items)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        Ok(encoded.into())
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytesRaw<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let mut reader = bytes.as_ref();
+        let items = rmpv::decode::read_value(&mut reader)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        if !reader.is_empty() {
+            return Err(CodecError::Other(
+                "msgpack2 encoded chunk has trailing bytes".to_string(),
+            ));
+        }
+        let serde_json::Value::Array(items) = msgpack_to_json(items)? else {
+            return Err(CodecError::Other(
+                "msgpack2 encoded chunk is not an array".to_string(),
+            ));
+        };
+        let values = items_to_values(items, shape)?;
+        self.elements.to_array_bytes(&values)
+    }
+
+    fn encoded_representation(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<BytesRepresentation, CodecError> {
+        Ok(BytesRepresentation::UnboundedSize)
+    }
+}
+
+/// Convert a JSON value to a `msgpack` value.
+fn json_to_msgpack(
+    value: &serde_json::Value,
+    use_single_float: bool,
+) -> Result<rmpv::Value, CodecError> {
+    use serde_json::Value;
+    Ok(match value {
+        Value::Null => rmpv::Value::Nil,
+        Value::Bool(b) => rmpv::Value::Boolean(*b),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                rmpv::Value::from(n)
+            } else if let Some(n) = n.as_i64() {
+                rmpv::Value::from(n)
+            } else if let Some(n) = n.as_f64() {
+                if use_single_float {
+                    #[allow(clippy::cast_possible_truncation)]
+                    rmpv::Value::F32(n as f32)
+                } else {
+                    rmpv::Value::F64(n)
+                }
+            } else {
+                return Err(CodecError::Other(format!(
+                    "number {n} is not supported by the msgpack2 codec"
+                )));
+            }
+        }
+        Value::String(s) => rmpv::Value::from(s.as_str()),
+        Value::Array(values) => rmpv::Value::Array(
+            values
+                .iter()
+                .map(|value| json_to_msgpack(value, use_single_float))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(map) => rmpv::Value::Map(
+            map.iter()
+                .map(|(key, value)| {
+                    Ok((
+                        rmpv::Value::from(key.as_str()),
+                        json_to_msgpack(value, use_single_float)?,
+                    ))
+                })
+                .collect::<Result<_, CodecError>>()?,
+        ),
+    })
+}
+
+/// Convert a `msgpack` value to a JSON value.
+fn msgpack_to_json(value: rmpv::Value) -> Result<serde_json::Value, CodecError> {
+    use serde_json::Value;
+    let to_string =
+        |bytes: Vec<u8>| String::from_utf8(bytes).map_err(|err| CodecError::Other(err.to_string()));
+    Ok(match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(b) => Value::Bool(b),
+        rmpv::Value::Integer(n) => {
+            if let Some(n) = n.as_u64() {
+                Value::from(n)
+            } else if let Some(n) = n.as_i64() {
+                Value::from(n)
+            } else {
+                return Err(CodecError::Other(format!(
+                    "integer {n} is not supported by the msgpack2 codec"
+                )));
+            }
+        }
+        rmpv::Value::F32(n) => float_to_json(f64::from(n))?,
+        rmpv::Value::F64(n) => float_to_json(n)?,
+        rmpv::Value::String(s) => Value::String(to_string(s.into_bytes())?),
+        rmpv::Value::Binary(bytes) => Value::String(to_string(bytes)?),
+        rmpv::Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(msgpack_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        rmpv::Value::Map(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        rmpv::Value::String(s) => to_string(s.into_bytes())?,
+                        rmpv::Value::Binary(bytes) => to_string(bytes)?,
+                        key @ (rmpv::Value::Nil
+                        | rmpv::Value::Boolean(_)
+                        | rmpv::Value::Integer(_)
+                        | rmpv::Value::F32(_)
+                        | rmpv::Value::F64(_)
+                        | rmpv::Value::Array(_)
+                        | rmpv::Value::Map(_)
+                        | rmpv::Value::Ext(..)) => {
+                            return Err(CodecError::Other(format!(
+                                "map key {key} is not supported by the msgpack2 codec"
+                            )));
+                        }
+                    };
+                    Ok((key, msgpack_to_json(value)?))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        rmpv::Value::Ext(..) => {
+            return Err(CodecError::Other(
+                "extension values are not supported by the msgpack2 codec".to_string(),
+            ));
+        }
+    })
+}
+
+fn float_to_json(n: f64) -> Result<serde_json::Value, CodecError> {
+    serde_json::Number::from_f64(n)
+        .map(serde_json::Value::Number)
+        .ok_or_else(|| {
+            CodecError::Other(format!(
+                "non-finite value {n} is not supported by the msgpack2 codec"
+            ))
+        })
+}
@@ -0,0 +1,173 @@
+//! Encoded items of the `numcodecs` object codecs (`json2` and `msgpack2`).
+//!
+//! A chunk is encoded as a list of its elements (nested by the chunk shape), followed by the `numpy` dtype string (`|O`) and the chunk shape.
+
+use std::num::NonZeroU64;
+
+use itertools::Itertools;
+use serde_json::Value;
+
+use crate::array::data_type::{self, sort_json_object_keys};
+use crate::array::{ArrayBytes, ArrayBytesOffsets, DataType};
+use zarrs_codec::CodecError;
+
+/// The interpretation of the elements of an object array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectElements {
+    /// The `string` data type. Non-string elements are decoded to their JSON representation.
+    String,
+    /// The `zarrs.json` data type.
+    Json,
+}
+
+impl ObjectElements {
+    /// Return the interpretation of elements of `data_type`, or [`None`] if the data type is not supported.
+    pub(crate) fn new(data_type: &DataType) -> Option<Self> {
+        if data_type.is::<data_type::StringDataType>() {
+            Some(Self::String)
+        } else if data_type.is::<data_type::JsonDataType>() {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+
+    /// Convert variable-length array bytes to element values.
+    pub(crate) fn to_values(
+        self,
+        bytes: ArrayBytes<'_>,
+        sort_keys: bool,
+    ) -> Result<Vec<Value>, CodecError> {
+        let (bytes, offsets) = bytes.into_variable()?.into_parts();
+        offsets
+            .iter()
+            .tuple_windows()
+            .map(|(&curr, &next)| {
+                let element = &bytes[curr..next];
+                match self {
+                    Self::String => std::str::from_utf8(element)
+                        .map(|s| Value::String(s.to_string()))
+                        .map_err(|err| CodecError::Other(err.to_string())),
+                    Self::Json => {
+                        let mut value = serde_json::from_slice(element)
+                            .map_err(|err| CodecError::Other(err.to_string()))?;
+                        if sort_keys {
+                            sort_json_object_keys(&mut value);
+                        }
+                        Ok(value)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Convert element values to variable-length array bytes.
+    pub(crate) fn to_array_bytes(
+        self,
+        values: &[Value],
+    ) -> Result<ArrayBytes<'static>, CodecError> {
+        let mut bytes = Vec::new();
+        let mut offsets = Vec::with_capacity(values.len() + 1);
+        for value in values {
+            offsets.push(bytes.len());
+            match (self, value) {
+                (Self::String, Value::String(s)) => bytes.extend_from_slice(s.as_bytes()),
+                (Self::String | Self::Json, value) => serde_json::to_writer(&mut bytes, value)
+                    .map_err(|err| CodecError::Other(err.to_string()))?,
+            }
+        }
+        offsets.push(bytes.len());
+        let offsets = ArrayBytesOffsets::new(offsets)?;
+        Ok(ArrayBytes::new_vlen(bytes, offsets)?)
+    }
+}
+
+/// Nest `values` by the chunk `shape` and append the dtype and shape.
+pub(crate) fn values_to_items(values: Vec<Value>, shape: &[NonZeroU64]) -> Vec<Value> {
+    let mut items = values;
+    for extent in shape.iter().skip(1).rev() {
+        let extent = usize::try_from(extent.get()).unwrap();
+        items = items
+            .into_iter()
+            .chunks(extent)
+            .into_iter()
+            .map(|chunk| Value::Array(chunk.collect()))
+            .collect();
+    }
+    items.push(Value::from("|O"));
+    items.push(Value::Array(
+        shape
+            .iter()
+            .map(|extent| Value::from(extent.get()))
+            .collect(),
+    ));
+    items
+}
+
+/// Flatten encoded `items` into the element values of a chunk with `shape`.
+pub(crate) fn items_to_values(
+    mut items: Vec<Value>,
+    shape: &[NonZeroU64],
+) -> Result<Vec<Value>, CodecError> {
+    let err = |msg: &str| CodecError::Other(format!("invalid object codec encoding: {msg}"));
+    let encoded_shape = match items.pop() {
+        Some(Value::Array(encoded_shape)) => encoded_shape
+            .iter()
+            .map(Value::as_u64)
+            .collect::<Option<Vec<u64>>>()
+            .ok_or_else(|| err("the shape is not an array of integers"))?,
+        _ => return Err(err("missing shape")),
+    };
+    if !items.pop().is_some_and(|dtype| dtype.is_string()) {
+        return Err(err("missing dtype"));
+    }
+
+    let mut values = items;
+    for _ in 1..encoded_shape.len() {
+        values = values
+            .into_iter()
+            .map(|value| {
+                if let Value::Array(values) = value {
+                    Ok(values)
+                } else {
+                    Err(err("the elements are not nested by the shape"))
+                }
+            })
+            .flatten_ok()
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    let num_elements = shape.iter().map(|extent| extent.get()).product::<u64>();
+    if encoded_shape.iter().product::<u64>() != num_elements || values.len() as u64 != num_elements
+    {
+        return Err(err(&format!(
+            "expected {num_elements} elements, got {} with shape {encoded_shape:?}",
+            values.len()
+        )));
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object_items_round_trip() {
+        let shape: Vec<NonZeroU64> = [2, 3].map(|s| NonZeroU64::new(s).unwrap()).to_vec();
+        let values: Vec<Value> = (0..6).map(Value::from).collect();
+        let items = values_to_items(values.clone(), &shape);
+        assert_eq!(
+            Value::Array(items.clone()),
+            serde_json::json!([[0, 1, 2], [3, 4, 5], "|O", [2, 3]])
+        );
+        assert_eq!(items_to_values(items, &shape).unwrap(), values);
+
+        // Flat items are also accepted
+        let items = serde_json::from_str::<Vec<Value>>(r#"[0, 1, 2, 3, 4, 5, "|O", [6]]"#).unwrap();
+        assert_eq!(items_to_values(items, &shape).unwrap(), values);
+
+        let items = serde_json::from_str::<Vec<Value>>(r#"[0, 1, 2, "|O", [3]]"#).unwrap();
+        assert!(items_to_values(items, &shape).is_err());
+    }
+}
@@ -378,6 +378,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn codec_packbits_int12() -> Result<(), Box<dyn std::error::Error>> {
+        for encoding in [
+            PackBitsPaddingEncoding::None,
+            PackBitsPaddingEncoding::FirstByte,
+            PackBitsPaddingEncoding::LastByte,
+        ] {
+            let chunk_shape = vec![NonZeroU64::new(16).unwrap(), NonZeroU64::new(1).unwrap()];
+            let data_type = data_type::int_bits(12)?;
+            let fill_value = FillValue::from(0i16);
+            let codec = Arc::new(super::PackBitsCodec::new(encoding, None, None).unwrap())
+                .with_context(data_type.clone(), fill_value.clone())?;
+
+            let elements: Vec<i16> = (-8..8).map(|i| i * 256).collect();
+            let bytes = i16::to_array_bytes(&data_type, &elements)?.into_owned();
+
+            // Encoding
+            let encoded = codec.encode(bytes.clone(), &chunk_shape, &CodecOptions::default())?;
+            assert!((encoded.len() as u64) <= (12 * 16).div_ceil(&8) + 1);
+
+            // Decoding
+            let decoded = codec
+                .decode(encoded.clone(), &chunk_shape, &CodecOptions::default())
+                .unwrap();
+            assert_eq!(elements, i16::from_array_bytes(&data_type, decoded)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn codec_packbits_uint3() -> Result<(), Box<dyn std::error::Error>> {
+        let chunk_shape = vec![NonZeroU64::new(8).unwrap()];
+        let data_type = data_type::uint_bits(3)?;
+        let fill_value = FillValue::from(0u8);
+        let codec =
+            Arc::new(super::PackBitsCodec::new(PackBitsPaddingEncoding::None, None, None).unwrap())
+                .with_context(data_type.clone(), fill_value.clone())?;
+
+        let elements: Vec<u8> = (0..8).collect();
+        let bytes = u8::to_array_bytes(&data_type, &elements)?.into_owned();
+
+        let encoded = codec.encode(bytes.clone(), &chunk_shape, &CodecOptions::default())?;
+        assert_eq!(encoded.len(), 3);
+
+        let decoded = codec
+            .decode(encoded.clone(), &chunk_shape, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(elements, u8::from_array_bytes(&data_type, decoded)?);
+        Ok(())
+    }
+
     #[test]
     fn codec_packbits_float4_e2m1fn() -> Result<(), Box<dyn std::error::Error>> {
         for encoding in [
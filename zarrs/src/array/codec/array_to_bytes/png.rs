@@ -0,0 +1,145 @@
+//! The `imagecodecs_png` array to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Losslessly encodes a chunk as a [PNG](https://www.w3.org/TR/png/) image.
+//!
+//! Supported data types are `uint8` and `uint16`.
+//! A chunk must have the shape `[height, width]` (grayscale) or `[height, width, channels]` with 1 (grayscale), 2 (grayscale and alpha), 3 (RGB), or 4 (RGBA) channels.
+//! Any preceding dimensions must have a size of 1.
+//!
+//! This codec requires the `png` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! This codec is compatible with the `imagecodecs_png` codec of [`imagecodecs`](https://github.com/cgohlke/imagecodecs) registered with `numcodecs`.
+//! The `strategy` and `filter` encoding parameters are ignored.
+//!
+//! ### Specification
+//! - <https://github.com/cgohlke/imagecodecs/blob/master/imagecodecs/numcodecs.py>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.imagecodecs_png`
+//! - `imagecodecs_png`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `imagecodecs_png`
+//!
+//! ### Codec `configuration` Example - [`PngCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "level": 6
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::png::PngCodecConfiguration;
+//! # serde_json::from_str::<PngCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod png_codec;
+
+use std::sync::Arc;
+
+pub use png_codec::PngCodec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::png::{PngCodecConfiguration, PngCodecConfigurationV1};
+
+zarrs_plugin::impl_extension_aliases!(PngCodec,
+    v3: "numcodecs.imagecodecs_png", ["imagecodecs_png"],
+    v2: "imagecodecs_png"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<PngCodec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<PngCodec>()
+}
+
+impl CodecTraitsV3 for PngCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(PngCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for PngCodec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: PngCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(PngCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{ArrayBytes, ChunkShapeTraits, DataType, FillValue, data_type};
+    use zarrs_codec::{CodecOptions, UnboundArrayToBytesCodecTraits};
+
+    fn codec_png_round_trip_impl(
+        data_type: DataType,
+        fill_value: impl Into<FillValue>,
+        chunk_shape: &[u64],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let chunk_shape: Vec<NonZeroU64> = chunk_shape
+            .iter()
+            .map(|&s| NonZeroU64::new(s).unwrap())
+            .collect();
+        let size = chunk_shape.num_elements_usize() * data_type.fixed_size().unwrap();
+        let bytes: Vec<u8> = (0..size).map(|s| (s * 7) as u8).collect();
+        let bytes: ArrayBytes = bytes.into();
+
+        let codec = Arc::new(PngCodec::new_with_configuration(&serde_json::from_str(
+            r#"{"level": 6}"#,
+        )?)?)
+        .with_context(data_type, fill_value.into())?;
+        let encoded = codec.encode(bytes.clone(), &chunk_shape, &CodecOptions::default())?;
+        assert_eq!(&encoded[1..4], b"PNG");
+        let decoded = codec.decode(encoded, &chunk_shape, &CodecOptions::default())?;
+        assert_eq!(bytes, decoded);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_png_round_trip_u8() {
+        for shape in [
+            vec![16, 12],
+            vec![1, 16, 12],
+            vec![16, 12, 1],
+            vec![16, 12, 2],
+            vec![16, 12, 3],
+            vec![1, 16, 12, 4],
+        ] {
+            codec_png_round_trip_impl(data_type::uint8(), 0u8, &shape).unwrap();
+        }
+    }
+
+    #[test]
+    fn codec_png_round_trip_u16() {
+        for shape in [vec![16, 12], vec![16, 12, 3]] {
+            codec_png_round_trip_impl(data_type::uint16(), 0u16, &shape).unwrap();
+        }
+    }
+
+    #[test]
+    fn codec_png_invalid() {
+        assert!(codec_png_round_trip_impl(data_type::int16(), 0i16, &[16, 12]).is_err());
+        assert!(codec_png_round_trip_impl(data_type::uint8(), 0u8, &[16]).is_err());
+        assert!(codec_png_round_trip_impl(data_type::uint8(), 0u8, &[2, 16, 12]).is_err());
+        assert!(
+            PngCodec::new_with_configuration(&serde_json::from_str(r#"{"level": 10}"#).unwrap())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+use std::io::Cursor;
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use zarrs_plugin::{ExtensionAliasesV3, PluginCreateError, ZarrVersion};
+
+use super::{PngCodecConfiguration, PngCodecConfigurationV1};
+use crate::array::codec::array_to_bytes::image_shape::ImageShape;
+use crate::array::{ChunkShapeTraits, DataType, FillValue, data_type};
+use zarrs_codec::{
+    ArrayBytes, ArrayBytesRaw, ArrayCodecTraits, ArrayToBytesCodecTraits, BytesRepresentation,
+    CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    InvalidBytesLengthError, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency, UnboundArrayToBytesCodecTraits,
+};
+use zarrs_metadata::Configuration;
+
+/// An `imagecodecs_png` codec implementation.
+#[derive(Debug, Clone)]
+pub struct PngCodec {
+    configuration: PngCodecConfigurationV1,
+}
+
+/// An `imagecodecs_png` codec implementation bound to a data type and fill value.
+#[derive(Debug, Clone)]
+struct PngCodecBound {
+    level: Option<u8>,
+    bit_depth: ::png::BitDepth,
+    data_type: DataType,
+    fill_value: FillValue,
+}
+
+impl PngCodec {
+    /// Create a new `imagecodecs_png` codec.
+    ///
+    /// `level` is the deflate compression level (0-9). The encoder default is used if `None`.
+    ///
+    /// # Errors
+    /// Returns an error if `level` is greater than 9.
+    pub fn new(level: Option<u8>) -> Result<Self, PluginCreateError> {
+        Self::new_with_configuration(&PngCodecConfiguration::V1(PngCodecConfigurationV1 {
+            level,
+            ..Default::default()
+        }))
+    }
+
+    /// Create a new `imagecodecs_png` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &PngCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            PngCodecConfiguration::V1(configuration) => {
+                if configuration.level.is_some_and(|level| level > 9) {
+                    return Err(PluginCreateError::Other(
+                        "imagecodecs_png level must be between 0 and 9".to_string(),
+                    ));
+                }
+                Ok(Self {
+                    configuration: configuration.clone(),
+                })
+            }
+            _ => Err(PluginCreateError::Other(
+                "this imagecodecs_png codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for PngCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        Some(PngCodecConfiguration::V1(self.configuration.clone()).into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+impl UnboundArrayToBytesCodecTraits for PngCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn UnboundArrayToBytesCodecTraits> {
+        self as Arc<dyn UnboundArrayToBytesCodecTraits>
+    }
+
+    fn with_context(
+        &self,
+        data_type: DataType,
+        fill_value: FillValue,
+    ) -> Result<Arc<dyn ArrayToBytesCodecTraits>, CodecCreateError> {
+        let bit_depth = if data_type.is::<data_type::UInt8DataType>() {
+            ::png::BitDepth::Eight
+        } else if data_type.is::<data_type::UInt16DataType>() {
+            ::png::BitDepth::Sixteen
+        } else {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type,
+                PngCodec::aliases_v3().default_name.to_string(),
+            ));
+        };
+        Ok(Arc::new(PngCodecBound {
+            level: self.configuration.level,
+            bit_depth,
+            data_type,
+            fill_value,
+        }))
+    }
+}
+
+fn png_color_type(channels: u64) -> ::png::ColorType {
+    match channels {
+        1 => ::png::ColorType::Grayscale,
+        2 => ::png::ColorType::GrayscaleAlpha,
+        3 => ::png::ColorType::Rgb,
+        4 => ::png::ColorType::Rgba,
+        _ => unreachable!("validated by ImageShape"),
+    }
+}
+
+fn image_shape(shape: &[NonZeroU64]) -> Result<ImageShape, CodecError> {
+    let image_shape = ImageShape::new(shape, &[1, 2, 3, 4], &PngCodec::aliases_v3().default_name)?;
+    if u32::try_from(image_shape.height).is_err() || u32::try_from(image_shape.width).is_err() {
+        return Err(CodecError::Other(format!(
+            "imagecodecs_png codec does not support images with dimensions {}x{}",
+            image_shape.height, image_shape.width
+        )));
+    }
+    Ok(image_shape)
+}
+
+/// Swap between native and big endian 16-bit samples.
+fn u16_swap_native_big_endian(bytes: &mut [u8]) {
+    if cfg!(target_endian = "little") {
+        for sample in bytes.as_chunks_mut::<2>().0 {
+            sample.swap(0, 1);
+        }
+    }
+}
+
+impl ArrayCodecTraits for PngCodecBound {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn fill_value(&self) -> &FillValue {
+        &self.fill_value
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+impl zarrs_codec::ArrayToBytesCodecNoSubchunkingTraits for PngCodecBound {}
+
+impl ArrayToBytesCodecTraits for PngCodecBound {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let image_shape = image_shape(shape)?;
+        let mut bytes = bytes.into_fixed()?;
+        if self.bit_depth == ::png::BitDepth::Sixteen {
+            u16_swap_native_big_endian(bytes.to_mut());
+        }
+
+        let mut encoded = Vec::new();
+        let mut image_encoder = ::png::Encoder::new(
+            &mut encoded,
+            u32::try_from(image_shape.width).unwrap(),
+            u32::try_from(image_shape.height).unwrap(),
+        );
+        image_encoder.set_color(png_color_type(image_shape.channels));
+        image_encoder.set_depth(self.bit_depth);
+        match self.level {
+            Some(0) => {
+                image_encoder.set_deflate_compression(::png::DeflateCompression::NoCompression);
+            }
+            Some(level) => {
+                image_encoder.set_deflate_compression(::png::DeflateCompression::Level(level));
+            }
+            None => {}
+        }
+        let mut writer = image_encoder
+            .write_header()
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        writer
+            .write_image_data(&bytes)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        writer
+            .finish()
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        Ok(Cow::Owned(encoded))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytesRaw<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let image_shape = image_shape(shape)?;
+        let mut image_decoder = ::png::Decoder::new(Cursor::new(bytes.as_ref()));
+        image_decoder.set_transformations(::png::Transformations::EXPAND);
+        let mut reader = image_decoder
+            .read_info()
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        let mut decoded = vec![
+            0;
+            reader.output_buffer_size().ok_or_else(|| {
+                CodecError::Other("imagecodecs_png image is too large".to_string())
+            })?
+        ];
+        let info = reader
+            .next_frame(&mut decoded)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        decoded.truncate(info.buffer_size());
+
+        if info.bit_depth != self.bit_depth
+            || info.color_type != png_color_type(image_shape.channels)
+            || u64::from(info.width) != image_shape.width
+            || u64::from(info.height) != image_shape.height
+        {
+            return Err(CodecError::Other(format!(
+                "imagecodecs_png decoded a {}x{} {:?} {:?} image incompatible with the chunk shape {shape:?}",
+                info.height, info.width, info.color_type, info.bit_depth
+            )));
+        }
+        let expected_len = shape.num_elements_usize() * self.data_type.fixed_size().unwrap();
+        if decoded.len() != expected_len {
+            return Err(InvalidBytesLengthError::new(decoded.len(), expected_len).into());
+        }
+        if self.bit_depth == ::png::BitDepth::Sixteen {
+            u16_swap_native_big_endian(&mut decoded);
+        }
+        Ok(ArrayBytes::from(decoded))
+    }
+
+    fn encoded_representation(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<BytesRepresentation, CodecError> {
+        Ok(BytesRepresentation::UnboundedSize)
+    }
+}
@@ -0,0 +1,420 @@
+//! The `rle` array to bytes codec (Experimental).
+//!
+//! Run-length encodes the elements of a chunk in C order.
+//! This codec is suited to sparse arrays and label (segmentation) arrays with long runs of repeated values.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/array_to_bytes/rle>
+//!
+//! This codec supports fixed-size data types that support the `bytes` codec, such as integers, `bool`, and `numpy.datetime64` (including `NaT`).
+//! Elements are compared bitwise, so runs of floating point values are only merged if they share an identical representation.
+//!
+//! The encoded representation is:
+//! - the number of runs `n` as a little-endian `u64`,
+//! - `n` run ends as little-endian `u64`s, where each run end is the exclusive end of the run in the C order linearised element index, then
+//! - `n` run values, each encoded as little-endian with the `bytes` codec.
+//!
+//! Partial decoding only reads the run ends and the run values intersecting the requested elements, so a chunk subset can be decoded without expanding the whole chunk.
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.rle`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`RleCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {}
+//! # "#;
+//! # use zarrs::metadata_ext::codec::rle::RleCodecConfiguration;
+//! # let configuration: RleCodecConfiguration = serde_json::from_str(JSON).unwrap();
+//! ```
+
+mod rle_codec;
+mod rle_partial_decoder;
+
+use std::sync::Arc;
+
+pub use rle_codec::RleCodec;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecError, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::rle::{RleCodecConfiguration, RleCodecConfigurationV0};
+
+zarrs_plugin::impl_extension_aliases!(RleCodec,
+  v3: "zarrs.rle", []
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<RleCodec>()
+}
+
+impl CodecTraitsV3 for RleCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: RleCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(RleCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::ArrayToBytes(codec))
+    }
+}
+
+/// The size of the run count header.
+const HEADER_SIZE: usize = size_of::<u64>();
+
+/// Run-length encode little-endian element `bytes` with an element size of `element_size`.
+fn rle_encode(bytes: &[u8], element_size: usize) -> Vec<u8> {
+    let mut run_ends: Vec<u64> = Vec::new();
+    let mut run_values: Vec<u8> = Vec::new();
+    for (index, element) in bytes.chunks_exact(element_size).enumerate() {
+        let index_end = index as u64 + 1;
+        if let Some(run_end) = run_ends.last_mut()
+            && &run_values[run_values.len() - element_size..] == element
+        {
+            *run_end = index_end;
+        } else {
+            run_ends.push(index_end);
+            run_values.extend_from_slice(element);
+        }
+    }
+
+    let mut bytes_enc =
+        Vec::with_capacity(HEADER_SIZE + run_ends.len() * size_of::<u64>() + run_values.len());
+    bytes_enc.extend_from_slice(&(run_ends.len() as u64).to_le_bytes());
+    for run_end in run_ends {
+        bytes_enc.extend_from_slice(&run_end.to_le_bytes());
+    }
+    bytes_enc.extend_from_slice(&run_values);
+    bytes_enc
+}
+
+/// Decode the number of runs from the run count header.
+fn rle_num_runs(header: &[u8]) -> Result<usize, CodecError> {
+    let num_runs = header
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| CodecError::Other("rle encoded header is invalid".to_string()))?;
+    usize::try_from(num_runs)
+        .map_err(|_| CodecError::Other("rle encoded number of runs is too large".to_string()))
+}
+
+/// Return the sizes of the run ends and run values of `num_runs` runs of `element_size` byte elements.
+fn rle_sizes(num_runs: usize, element_size: usize) -> Result<(usize, usize), CodecError> {
+    num_runs
+        .checked_mul(size_of::<u64>())
+        .zip(num_runs.checked_mul(element_size))
+        .filter(|(run_ends_size, run_values_size)| {
+            HEADER_SIZE
+                .checked_add(*run_ends_size)
+                .and_then(|size| size.checked_add(*run_values_size))
+                .is_some()
+        })
+        .ok_or_else(|| CodecError::Other("rle encoded number of runs is too large".to_string()))
+}
+
+/// Return the size of `num_elements` decoded elements of `element_size` bytes.
+fn rle_decoded_size(num_elements: u64, element_size: usize) -> Result<usize, CodecError> {
+    usize::try_from(num_elements)
+        .ok()
+        .and_then(|num_elements| num_elements.checked_mul(element_size))
+        .ok_or_else(|| CodecError::Other("rle decoded size is too large".to_string()))
+}
+
+/// Decode and validate run ends for a chunk with `num_elements` elements.
+fn rle_run_ends(bytes: &[u8], num_elements: u64) -> Result<Vec<u64>, CodecError> {
+    let run_ends: Vec<u64> = bytes
+        .chunks_exact(size_of::<u64>())
+        .map(|run_end| u64::from_le_bytes(run_end.try_into().unwrap()))
+        .collect();
+    let increasing = run_ends.iter().try_fold(0, |previous, &run_end| {
+        (run_end > previous).then_some(run_end)
+    });
+    if increasing.unwrap_or(0) != num_elements {
+        return Err(CodecError::Other(
+            "rle encoded run ends are invalid".to_string(),
+        ));
+    }
+    Ok(run_ends)
+}
+
+/// Expand run-length encoded `bytes` to little-endian element bytes.
+fn rle_decode(bytes: &[u8], element_size: usize, num_elements: u64) -> Result<Vec<u8>, CodecError> {
+    let num_runs = rle_num_runs(bytes.get(..HEADER_SIZE).unwrap_or_default())?;
+    let (run_ends_size, run_values_size) = rle_sizes(num_runs, element_size)?;
+    let expected_length = HEADER_SIZE + run_ends_size + run_values_size;
+    if bytes.len() != expected_length {
+        return Err(zarrs_codec::InvalidBytesLengthError::new(bytes.len(), expected_length).into());
+    }
+    let run_ends = rle_run_ends(
+        &bytes[HEADER_SIZE..HEADER_SIZE + run_ends_size],
+        num_elements,
+    )?;
+    let run_values = &bytes[HEADER_SIZE + run_ends_size..];
+
+    let mut bytes_dec = Vec::with_capacity(rle_decoded_size(num_elements, element_size)?);
+    let mut run_start = 0;
+    for (run_end, value) in run_ends
+        .into_iter()
+        .zip(run_values.chunks_exact(element_size))
+    {
+        for _ in run_start..run_end {
+            bytes_dec.extend_from_slice(value);
+        }
+        run_start = run_end;
+    }
+    Ok(bytes_dec)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use zarrs_data_type::FillValue;
+
+    use super::*;
+    use crate::array::element::{Element, ElementOwned};
+    use crate::array::{ArrayBytes, ArraySubset, data_type};
+    use zarrs_codec::{
+        BytesPartialDecoderTraits, CodecCreateError, CodecOptions, CodecTraits,
+        UnboundArrayToBytesCodecTraits,
+    };
+
+    fn labels() -> Vec<u16> {
+        // 4x6 labels with long runs
+        vec![
+            0, 0, 0, 0, 7, 7, //
+            7, 7, 7, 7, 7, 7, //
+            0, 0, 3, 3, 3, 3, //
+            3, 3, 3, 3, 3, 9, //
+        ]
+    }
+
+    fn shape() -> Vec<NonZeroU64> {
+        vec![NonZeroU64::new(4).unwrap(), NonZeroU64::new(6).unwrap()]
+    }
+
+    #[test]
+    fn codec_rle_decode_invalid_num_runs() {
+        let mut bytes = u64::MAX.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[0; 16]);
+        assert!(rle_decode(&bytes, 2, 4).is_err());
+        assert!(rle_sizes(usize::MAX / 4, 2).is_err());
+    }
+
+    #[test]
+    fn codec_rle_decoded_size_too_large() {
+        assert_eq!(rle_decoded_size(4, 2).unwrap(), 8);
+        assert!(rle_decoded_size(u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn codec_rle_configuration() {
+        let codec = RleCodec::new();
+        let configuration = codec
+            .configuration_v3(&zarrs_codec::CodecMetadataOptions::default())
+            .unwrap();
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), r"{}");
+    }
+
+    #[test]
+    fn codec_rle_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::uint16();
+        let codec =
+            Arc::new(RleCodec::new()).with_context(data_type.clone(), FillValue::from(0u16))?;
+
+        let elements = labels();
+        let bytes = u16::into_array_bytes(&data_type, elements.clone())?.into_owned();
+        let encoded = codec.encode(bytes, &shape(), &CodecOptions::default())?;
+        // 5 runs: header + run ends + run values
+        assert_eq!(encoded.len(), 8 + 5 * 8 + 5 * 2);
+        assert_eq!(&encoded[..8], &5u64.to_le_bytes());
+
+        let decoded = codec.decode(encoded, &shape(), &CodecOptions::default())?;
+        assert_eq!(u16::from_array_bytes(&data_type, decoded)?, elements);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_rle_bool() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::bool();
+        let codec =
+            Arc::new(RleCodec::new()).with_context(data_type.clone(), FillValue::from(false))?;
+
+        let elements: Vec<bool> = (0..24).map(|i| i >= 20).collect();
+        let bytes = bool::into_array_bytes(&data_type, elements.clone())?.into_owned();
+        let encoded = codec.encode(bytes, &shape(), &CodecOptions::default())?;
+        assert_eq!(encoded.len(), 8 + 2 * 8 + 2);
+
+        let decoded = codec.decode(encoded, &shape(), &CodecOptions::default())?;
+        assert_eq!(bool::from_array_bytes(&data_type, decoded)?, elements);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_rle_datetime64_nat() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::numpy_datetime64(
+            zarrs_metadata_ext::data_type::NumpyTimeUnit::Second,
+            std::num::NonZeroU32::new(1).unwrap(),
+        );
+        let fill_value = FillValue::from(i64::MIN);
+        let codec = Arc::new(RleCodec::new()).with_context(data_type.clone(), fill_value)?;
+
+        let mut elements = vec![i64::MIN; 24];
+        elements[10..14].fill(1_700_000_000);
+        let bytes = ArrayBytes::new_flen(bytemuck::cast_slice::<i64, u8>(&elements).to_vec());
+        let encoded = codec.encode(bytes.clone(), &shape(), &CodecOptions::default())?;
+        assert_eq!(encoded.len(), 8 + 3 * 8 + 3 * 8);
+
+        let decoded = codec.decode(encoded, &shape(), &CodecOptions::default())?;
+        assert_eq!(decoded, bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_rle_invalid() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::uint16();
+        let codec =
+            Arc::new(RleCodec::new()).with_context(data_type.clone(), FillValue::from(0u16))?;
+
+        let bytes = u16::into_array_bytes(&data_type, labels())?.into_owned();
+        let encoded = codec
+            .encode(bytes, &shape(), &CodecOptions::default())?
+            .into_owned();
+
+        // Truncated
+        assert!(
+            codec
+                .decode(
+                    encoded[..encoded.len() - 1].to_vec().into(),
+                    &shape(),
+                    &CodecOptions::default()
+                )
+                .is_err()
+        );
+
+        // Run ends do not cover the chunk
+        let shape_large = vec![NonZeroU64::new(5).unwrap(), NonZeroU64::new(6).unwrap()];
+        assert!(
+            codec
+                .decode(encoded.into(), &shape_large, &CodecOptions::default())
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn codec_rle_unsupported_data_type() {
+        assert!(matches!(
+            Arc::new(RleCodec::new()).with_context(data_type::string(), FillValue::from("")),
+            Err(CodecCreateError::UnsupportedDataType(_, _))
+        ));
+    }
+
+    #[test]
+    fn codec_rle_partial_decode() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::uint16();
+        let codec =
+            Arc::new(RleCodec::new()).with_context(data_type.clone(), FillValue::from(0u16))?;
+
+        let bytes = u16::into_array_bytes(&data_type, labels())?.into_owned();
+        let encoded = codec.encode(bytes, &shape(), &CodecOptions::default())?;
+        let input_handle = Arc::new(encoded.into_owned());
+        let partial_decoder =
+            codec
+                .clone()
+                .partial_decoder(input_handle, &shape(), &CodecOptions::default())?;
+
+        let subset = ArraySubset::new_with_ranges(&[1..3, 3..6]);
+        let decoded = partial_decoder.partial_decode(&subset, &CodecOptions::default())?;
+        assert_eq!(
+            u16::from_array_bytes(&data_type, decoded)?,
+            vec![7, 7, 7, 3, 3, 3]
+        );
+
+        let subset = ArraySubset::new_with_ranges(&[3..4, 5..6]);
+        let decoded = partial_decoder.partial_decode(&subset, &CodecOptions::default())?;
+        assert_eq!(u16::from_array_bytes(&data_type, decoded)?, vec![9]);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_rle_partial_decode_reads() -> Result<(), Box<dyn std::error::Error>> {
+        let data_type = data_type::uint16();
+        let codec =
+            Arc::new(RleCodec::new()).with_context(data_type.clone(), FillValue::from(0u16))?;
+
+        let bytes = u16::into_array_bytes(&data_type, labels())?.into_owned();
+        let encoded = codec.encode(bytes, &shape(), &CodecOptions::default())?;
+        let input_handle = Arc::new(encoded.into_owned());
+        let partial_decoder = RleCodecPartialDecoderProbe::new(input_handle);
+        let partial_decoder_dyn =
+            codec.partial_decoder(partial_decoder.clone(), &shape(), &CodecOptions::default())?;
+
+        // Only the header, the run ends, and the value of the single intersecting run are read
+        let subset = ArraySubset::new_with_ranges(&[1..2, 0..6]);
+        let decoded = partial_decoder_dyn.partial_decode(&subset, &CodecOptions::default())?;
+        assert_eq!(u16::from_array_bytes(&data_type, decoded)?, vec![7; 6]);
+        assert_eq!(partial_decoder.bytes_read(), 8 + 5 * 8 + 2);
+        Ok(())
+    }
+
+    /// Wraps a partial decoder and counts the number of bytes read.
+    #[derive(Clone)]
+    struct RleCodecPartialDecoderProbe {
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        bytes_read: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl RleCodecPartialDecoderProbe {
+        fn new(input_handle: Arc<dyn BytesPartialDecoderTraits>) -> Arc<Self> {
+            Arc::new(Self {
+                input_handle,
+                bytes_read: Arc::default(),
+            })
+        }
+
+        fn bytes_read(&self) -> usize {
+            self.bytes_read.load(std::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    impl BytesPartialDecoderTraits for RleCodecPartialDecoderProbe {
+        fn exists(&self) -> Result<bool, zarrs_storage::StorageError> {
+            self.input_handle.exists()
+        }
+
+        fn size_held(&self) -> usize {
+            self.input_handle.size_held()
+        }
+
+        fn partial_decode_many(
+            &self,
+            decoded_regions: zarrs_storage::byte_range::ByteRangeIterator,
+            options: &CodecOptions,
+        ) -> Result<Option<Vec<crate::array::ArrayBytesRaw<'_>>>, zarrs_codec::CodecError> {
+            let bytes = self
+                .input_handle
+                .partial_decode_many(decoded_regions, options)?;
+            if let Some(bytes) = &bytes {
+                self.bytes_read.fetch_add(
+                    bytes.iter().map(|bytes| bytes.len()).sum(),
+                    std::sync::atomic::Ordering::Relaxed,
+                );
+            }
+            Ok(bytes)
+        }
+
+        fn supports_partial_decode(&self) -> bool {
+            true
+        }
+    }
+}
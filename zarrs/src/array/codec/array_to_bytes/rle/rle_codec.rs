@@ -0,0 +1,219 @@
+use std::borrow::Cow;
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use zarrs_plugin::{ExtensionAliasesV3, PluginCreateError, ZarrVersion};
+
+#[cfg(feature = "async")]
+use super::rle_partial_decoder::AsyncRlePartialDecoder;
+use super::rle_partial_decoder::RlePartialDecoder;
+use super::{RleCodecConfiguration, RleCodecConfigurationV0, rle_decode, rle_encode};
+use crate::array::codec::BytesDataTypeExt;
+use crate::array::{
+    ArrayBytes, ArrayBytesRaw, BytesRepresentation, ChunkShapeTraits, DataType, FillValue,
+};
+use zarrs_codec::{
+    ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayToBytesCodecTraits,
+    BytesPartialDecoderTraits, CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions,
+    CodecTraits, PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+    UnboundArrayToBytesCodecTraits,
+};
+#[cfg(feature = "async")]
+use zarrs_codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+use zarrs_metadata::{Configuration, Endianness};
+
+/// A `rle` codec implementation.
+#[derive(Debug, Clone, Default)]
+pub struct RleCodec;
+
+/// A `rle` codec implementation bound to a data type and fill value.
+#[derive(Debug, Clone)]
+struct RleCodecBound {
+    data_type: DataType,
+    fill_value: FillValue,
+    element_size: usize,
+}
+
+impl RleCodec {
+    /// Create a new `rle` codec.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Create a new `rle` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &RleCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            RleCodecConfiguration::V0(_) => Ok(Self),
+            _ => Err(PluginCreateError::Other(
+                "this rle codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for RleCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = RleCodecConfiguration::V0(RleCodecConfigurationV0 {});
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: true,
+            partial_decode: true,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl UnboundArrayToBytesCodecTraits for RleCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn UnboundArrayToBytesCodecTraits> {
+        self as Arc<dyn UnboundArrayToBytesCodecTraits>
+    }
+
+    fn with_context(
+        &self,
+        data_type: DataType,
+        fill_value: FillValue,
+    ) -> Result<Arc<dyn ArrayToBytesCodecTraits>, CodecCreateError> {
+        let element_size = data_type
+            .fixed_size()
+            .filter(|_| !data_type.is_optional() && data_type.codec_bytes().is_ok());
+        let Some(element_size) = element_size else {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type,
+                Self::aliases_v3().default_name.to_string(),
+            ));
+        };
+        Ok(Arc::new(RleCodecBound {
+            data_type,
+            fill_value,
+            element_size,
+        }))
+    }
+}
+
+impl ArrayCodecTraits for RleCodecBound {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn fill_value(&self) -> &FillValue {
+        &self.fill_value
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _shape: &[NonZeroU64],
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+impl zarrs_codec::ArrayToBytesCodecNoSubchunkingTraits for RleCodecBound {}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl ArrayToBytesCodecTraits for RleCodecBound {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        bytes.validate(shape.num_elements_u64(), &self.data_type)?;
+        let bytes = bytes.into_fixed()?;
+        let bytes = self
+            .data_type
+            .codec_bytes()?
+            .encode(bytes, Some(Endianness::Little))?;
+        Ok(Cow::Owned(rle_encode(&bytes, self.element_size)))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytesRaw<'a>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let bytes = rle_decode(&bytes, self.element_size, shape.num_elements_u64())?;
+        let bytes = self
+            .data_type
+            .codec_bytes()?
+            .decode(Cow::Owned(bytes), Some(Endianness::Little))?;
+        Ok(ArrayBytes::from(bytes))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(RlePartialDecoder::new(
+            input_handle,
+            shape.to_vec(),
+            self.data_type.clone(),
+            self.fill_value.clone(),
+            self.element_size,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        shape: &[NonZeroU64],
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncRlePartialDecoder::new(
+            input_handle,
+            shape.to_vec(),
+            self.data_type.clone(),
+            self.fill_value.clone(),
+            self.element_size,
+        )))
+    }
+
+    fn encoded_representation(
+        &self,
+        shape: &[NonZeroU64],
+    ) -> Result<BytesRepresentation, CodecError> {
+        // The worst case is a run per element
+        let num_elements = shape.num_elements_u64();
+        Ok(BytesRepresentation::BoundedSize(
+            size_of::<u64>() as u64 + num_elements * (size_of::<u64>() + self.element_size) as u64,
+        ))
+    }
+}
@@ -0,0 +1,270 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+#[cfg(feature = "async")]
+use async_generic::async_generic;
+use std::num::NonZeroU64;
+
+use super::{HEADER_SIZE, rle_decoded_size, rle_num_runs, rle_run_ends, rle_sizes};
+use crate::array::codec::BytesDataTypeExt;
+use crate::array::{ArrayBytes, ChunkShape, ChunkShapeTraits, DataType, FillValue};
+use zarrs_codec::{
+    ArrayPartialDecoderNoSubchunkingTraits, ArrayPartialDecoderTraits, BytesPartialDecoderTraits,
+    CodecError, CodecOptions,
+};
+#[cfg(feature = "async")]
+use zarrs_codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+use zarrs_metadata::Endianness;
+use zarrs_storage::StorageError;
+use zarrs_storage::byte_range::ByteRange;
+
+#[cfg_attr(feature = "async", async_generic(
+    async_signature(
+    input_handle: &Arc<dyn AsyncBytesPartialDecoderTraits>,
+    shape: &[NonZeroU64],
+    data_type: &DataType,
+    fill_value: &FillValue,
+    element_size: usize,
+    indexer: &dyn crate::array::Indexer,
+    options: &CodecOptions,
+)))]
+fn partial_decode<'a>(
+    input_handle: &Arc<dyn BytesPartialDecoderTraits>,
+    shape: &[NonZeroU64],
+    data_type: &DataType,
+    fill_value: &FillValue,
+    element_size: usize,
+    indexer: &dyn crate::array::Indexer,
+    options: &CodecOptions,
+) -> Result<ArrayBytes<'a>, CodecError> {
+    // Get the contiguous element ranges of the indexer
+    let contiguous_indices = indexer
+        .iter_contiguous_linearised_indices(bytemuck::must_cast_slice(shape))?
+        .collect::<Vec<_>>();
+    if contiguous_indices.is_empty() {
+        return Ok(ArrayBytes::new_flen(vec![]));
+    }
+
+    // Retrieve the number of runs
+    let header_range = ByteRange::FromStart(0, Some(HEADER_SIZE as u64));
+    #[cfg(feature = "async")]
+    let header = if _async {
+        input_handle.partial_decode(header_range, options).await
+    } else {
+        input_handle.partial_decode(header_range, options)
+    }?;
+    #[cfg(not(feature = "async"))]
+    let header = input_handle.partial_decode(header_range, options)?;
+    let Some(header) = header else {
+        return Ok(ArrayBytes::new_fill_value(
+            data_type,
+            indexer.len(),
+            fill_value,
+        )?);
+    };
+    let num_runs = rle_num_runs(&header)?;
+    let (run_ends_size, _) = rle_sizes(num_runs, element_size)?;
+
+    // Retrieve the run ends
+    let run_ends_size = run_ends_size as u64;
+    let run_ends_range = ByteRange::FromStart(HEADER_SIZE as u64, Some(run_ends_size));
+    #[cfg(feature = "async")]
+    let run_ends = if _async {
+        input_handle.partial_decode(run_ends_range, options).await
+    } else {
+        input_handle.partial_decode(run_ends_range, options)
+    }?;
+    #[cfg(not(feature = "async"))]
+    let run_ends = input_handle.partial_decode(run_ends_range, options)?;
+    let run_ends = run_ends
+        .ok_or_else(|| CodecError::Other("rle encoded run ends are missing".to_string()))?;
+    let run_ends = rle_run_ends(&run_ends, shape.num_elements_u64())?;
+
+    // Find the runs intersecting the indexer
+    let run_index =
+        |element_index: u64| run_ends.partition_point(|&run_end| run_end <= element_index);
+    let (run_first, run_last) = contiguous_indices.iter().fold(
+        (usize::MAX, 0),
+        |(run_first, run_last), &(start, length)| {
+            (
+                run_first.min(run_index(start)),
+                run_last.max(run_index(start + length - 1)),
+            )
+        },
+    );
+
+    // Retrieve the values of the intersecting runs
+    let run_values_range = ByteRange::FromStart(
+        HEADER_SIZE as u64 + run_ends_size + (run_first * element_size) as u64,
+        Some(((run_last - run_first + 1) * element_size) as u64),
+    );
+    #[cfg(feature = "async")]
+    let run_values = if _async {
+        input_handle.partial_decode(run_values_range, options).await
+    } else {
+        input_handle.partial_decode(run_values_range, options)
+    }?;
+    #[cfg(not(feature = "async"))]
+    let run_values = input_handle.partial_decode(run_values_range, options)?;
+    let run_values = run_values
+        .ok_or_else(|| CodecError::Other("rle encoded run values are missing".to_string()))?;
+
+    // Expand the runs intersecting each contiguous element range
+    let mut bytes_dec = Vec::with_capacity(rle_decoded_size(indexer.len(), element_size)?);
+    for (start, length) in contiguous_indices {
+        let end = start + length;
+        let mut index = start;
+        let mut run = run_index(start);
+        while index < end {
+            let run_end = run_ends[run].min(end);
+            let offset = (run - run_first) * element_size;
+            let value = &run_values[offset..offset + element_size];
+            for _ in index..run_end {
+                bytes_dec.extend_from_slice(value);
+            }
+            index = run_end;
+            run += 1;
+        }
+    }
+
+    let bytes_dec = data_type
+        .codec_bytes()?
+        .decode(Cow::Owned(bytes_dec), Some(Endianness::Little))?;
+    Ok(ArrayBytes::from(bytes_dec))
+}
+
+/// Partial decoder for the `rle` codec.
+pub(crate) struct RlePartialDecoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    shape: ChunkShape,
+    data_type: DataType,
+    fill_value: FillValue,
+    element_size: usize,
+}
+
+impl RlePartialDecoder {
+    /// Create a new partial decoder for the `rle` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        shape: ChunkShape,
+        data_type: DataType,
+        fill_value: FillValue,
+        element_size: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            shape,
+            data_type,
+            fill_value,
+            element_size,
+        }
+    }
+}
+
+/// The `rle` codec encodes a chunk as a whole, so it has no subchunks.
+impl ArrayPartialDecoderNoSubchunkingTraits for RlePartialDecoder {}
+
+impl ArrayPartialDecoderTraits for RlePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn exists(&self) -> Result<bool, StorageError> {
+        self.input_handle.exists()
+    }
+
+    fn size_held(&self) -> usize {
+        self.input_handle.size_held()
+    }
+
+    fn partial_decode(
+        &self,
+        indexer: &dyn crate::array::Indexer,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, CodecError> {
+        partial_decode(
+            &self.input_handle,
+            &self.shape,
+            &self.data_type,
+            &self.fill_value,
+            self.element_size,
+            indexer,
+            options,
+        )
+    }
+
+    fn supports_partial_decode(&self) -> bool {
+        self.input_handle.supports_partial_decode()
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `rle` codec.
+pub(crate) struct AsyncRlePartialDecoder {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    shape: ChunkShape,
+    data_type: DataType,
+    fill_value: FillValue,
+    element_size: usize,
+}
+
+#[cfg(feature = "async")]
+impl AsyncRlePartialDecoder {
+    /// Create a new partial decoder for the `rle` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        shape: ChunkShape,
+        data_type: DataType,
+        fill_value: FillValue,
+        element_size: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            shape,
+            data_type,
+            fill_value,
+            element_size,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl ArrayPartialDecoderNoSubchunkingTraits for AsyncRlePartialDecoder {}
+
+#[cfg(feature = "async")]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl AsyncArrayPartialDecoderTraits for AsyncRlePartialDecoder {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    async fn exists(&self) -> Result<bool, StorageError> {
+        self.input_handle.exists().await
+    }
+
+    fn size_held(&self) -> usize {
+        self.input_handle.size_held()
+    }
+
+    async fn partial_decode<'a>(
+        &'a self,
+        indexer: &dyn crate::array::Indexer,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        partial_decode_async(
+            &self.input_handle,
+            &self.shape,
+            &self.data_type,
+            &self.fill_value,
+            self.element_size,
+            indexer,
+            options,
+        )
+        .await
+    }
+
+    fn supports_partial_decode(&self) -> bool {
+        self.input_handle.supports_partial_decode()
+    }
+}
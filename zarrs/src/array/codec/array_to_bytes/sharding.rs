@@ -63,6 +63,7 @@
 
 mod sharding_codec;
 mod sharding_codec_builder;
+mod sharding_index_cache;
 mod sharding_options;
 #[cfg(feature = "async")]
 mod sharding_partial_decoder_async;
@@ -70,6 +71,7 @@ mod sharding_partial_decoder_sync;
 mod sharding_partial_encoder;
 #[cfg(feature = "async")]
 mod sharding_partial_encoder_async;
+mod sharding_subchunk_order;
 
 #[cfg(feature = "async")]
 pub use sharding_partial_decoder_async::AsyncShardingPartialDecoder;
@@ -86,6 +88,7 @@ use crate::array::{
 };
 pub use sharding_codec::{ShardingCodec, ShardingCodecBound};
 pub use sharding_codec_builder::ShardingCodecBuilder;
+pub use sharding_index_cache::ShardIndexCache;
 pub use sharding_options::{ShardingCodecOptions, SubchunkWriteOrder};
 use zarrs_codec::{
     ArrayCodecTraits, ArrayToBytesCodecTraits, BytesPartialDecoderTraits, ChunkGridDecoded, Codec,
@@ -133,7 +136,7 @@ impl CodecTraitsV3 for ShardingCodec {
     }
 }
 
-fn calculate_chunks_per_shard(
+pub(crate) fn calculate_chunks_per_shard(
     shard_shape: &[NonZeroU64],
     subchunk_shape: &[NonZeroU64],
 ) -> Result<ChunkShape, CodecError> {
@@ -469,8 +472,33 @@ mod tests {
             )
             .unwrap();
         match fill_value_amount {
+            FillValueAmount::None | FillValueAmount::Partial
+                if matches!(
+                    codec.options.subchunk_write_order(),
+                    SubchunkWriteOrder::Morton | SubchunkWriteOrder::Hilbert
+                ) =>
+            {
+                // Sorting the written chunks by offset gives the curve order
+                let mut written = index
+                    .chunks(2)
+                    .enumerate()
+                    .filter(|(_, e)| e[0] != u64::MAX)
+                    .map(|(i, e)| (e[0], i))
+                    .collect::<Vec<_>>();
+                written.sort_unstable();
+                let written = written.into_iter().map(|(_, i)| i).collect::<Vec<_>>();
+                let expected = sharding_subchunk_order::subchunk_write_order_indices(
+                    codec.options.subchunk_write_order(),
+                    &[NonZeroU64::new(chunk_size / subchunk_size).unwrap(); NUM_AXES],
+                )
+                .into_iter()
+                .filter(|i| written.contains(i))
+                .collect::<Vec<_>>();
+                assert_eq!(written, expected);
+            }
             FillValueAmount::None => match codec.options.subchunk_write_order() {
                 SubchunkWriteOrder::Unordered | SubchunkWriteOrder::Random => (),
+                SubchunkWriteOrder::Morton | SubchunkWriteOrder::Hilbert => unreachable!(),
                 SubchunkWriteOrder::C => {
                     let mut offset_with_len = index.chunks(2).collect::<Vec<&[u64]>>();
                     offset_with_len.sort_by_key(|x| x[0]);
@@ -486,6 +514,7 @@ mod tests {
             },
             FillValueAmount::Partial => match codec.options.subchunk_write_order() {
                 SubchunkWriteOrder::Unordered | SubchunkWriteOrder::Random => (),
+                SubchunkWriteOrder::Morton | SubchunkWriteOrder::Hilbert => unreachable!(),
                 SubchunkWriteOrder::C => {
                     // The sorted index with unwritten elements filtered matches that of the real index
                     let filtered_index = index
@@ -512,7 +541,12 @@ mod tests {
 
     #[test]
     fn codec_sharding_round_trip1() {
-        for subchunk_write_order in [SubchunkWriteOrder::C, SubchunkWriteOrder::Unordered] {
+        for subchunk_write_order in [
+            SubchunkWriteOrder::C,
+            SubchunkWriteOrder::Unordered,
+            SubchunkWriteOrder::Morton,
+            SubchunkWriteOrder::Hilbert,
+        ] {
             for index_at_end in [true, false] {
                 for fill_value_amount in [
                     FillValueAmount::All,
@@ -544,7 +578,12 @@ mod tests {
     #[test]
     fn codec_sharding_round_trip2() {
         use crate::array::codec::{Crc32cCodec, GzipCodec};
-        for subchunk_write_order in [SubchunkWriteOrder::C, SubchunkWriteOrder::Unordered] {
+        for subchunk_write_order in [
+            SubchunkWriteOrder::C,
+            SubchunkWriteOrder::Unordered,
+            SubchunkWriteOrder::Morton,
+            SubchunkWriteOrder::Hilbert,
+        ] {
             for index_at_end in [true, false] {
                 for fill_value_amount in [
                     FillValueAmount::All,
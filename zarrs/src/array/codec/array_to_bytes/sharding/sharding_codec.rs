@@ -13,6 +13,7 @@ use zarrs_chunk_grid::ChunkGridCreateError;
 #[cfg(feature = "async")]
 use super::sharding_partial_decoder_async::AsyncShardingPartialDecoder;
 use super::sharding_partial_decoder_sync::ShardingPartialDecoder;
+use super::sharding_subchunk_order::subchunk_write_order_indices;
 use super::{
     CodecChain, ShardingCodecConfiguration, ShardingCodecConfigurationV1, ShardingCodecOptions,
     ShardingIndexLocation, SubchunkWriteOrder, calculate_chunks_per_shard,
@@ -279,13 +280,23 @@ impl UnboundArrayToBytesCodecTraits for ShardingCodec {
         self: Arc<Self>,
         opts: &CodecSpecificOptions,
     ) -> Result<Arc<dyn UnboundArrayToBytesCodecTraits>, CodecCreateError> {
+        let mut codec = self;
+        let codec_mut = Arc::make_mut(&mut codec);
+        // Forward to the inner and index codecs (e.g. so that they can receive encryption keys)
+        codec_mut.inner_codecs = Arc::new(
+            (*codec_mut.inner_codecs)
+                .clone()
+                .with_codec_specific_options(opts)?,
+        );
+        codec_mut.index_codecs = Arc::new(
+            (*codec_mut.index_codecs)
+                .clone()
+                .with_codec_specific_options(opts)?,
+        );
         if let Some(sharding_opts) = opts.get_option::<ShardingCodecOptions>() {
-            let mut codec = self;
-            Arc::make_mut(&mut codec).options = sharding_opts.clone();
-            Ok(codec)
-        } else {
-            Ok(self.into_dyn())
+            codec_mut.options = sharding_opts.clone();
         }
+        Ok(codec)
     }
 }
 
@@ -1014,9 +1025,11 @@ impl ShardingCodecBound {
                     encoded_shard_offset_atomic.load(std::sync::atomic::Ordering::Relaxed),
                 )
             }
-            SubchunkWriteOrder::C => {
-                // TODO: Replace this chunk order with the desired order i.e., a `Vec` of the ids i.e., for morton order.
-                let chunk_order = 0..n_chunks;
+            SubchunkWriteOrder::C | SubchunkWriteOrder::Morton | SubchunkWriteOrder::Hilbert => {
+                let chunk_order = subchunk_write_order_indices(
+                    self.options.subchunk_write_order(),
+                    chunks_per_shard.as_slice(),
+                );
                 let encoded_chunk_ids_and_chunks: Vec<(usize, Vec<u8>)> = chunk_order
                     .concurrent_limit(shard_concurrent_limit)
                     .filter_map(|chunk_index: usize| {
@@ -1118,13 +1131,6 @@ impl ShardingCodecBound {
             compute_index_encoded_size(self.index_codecs.as_ref(), &index_shape)?;
         let index_encoded_size = usize::try_from(index_encoded_size).unwrap();
 
-        // Find chunks that are not entirely the fill value and collect their decoded bytes
-        let n_chunks = chunks_per_shard
-            .as_slice()
-            .iter()
-            .map(|i| usize::try_from(i.get()).unwrap())
-            .product::<usize>();
-
         // Calc self/internal concurrent limits
         let (shard_concurrent_limit, concurrency_limit_subchunks) = calc_concurrency_outer_inner(
             options.concurrent_target(),
@@ -1133,20 +1139,12 @@ impl ShardingCodecBound {
         );
         let options_inner = options.with_concurrent_target(concurrency_limit_subchunks);
 
-        #[cfg(not(target_arch = "wasm32"))]
-        let iterator = match self.options.subchunk_write_order() {
-            SubchunkWriteOrder::Unordered | SubchunkWriteOrder::Random | SubchunkWriteOrder::C => {
-                (0..n_chunks).into_par_iter()
-            }
-        };
-        #[cfg(target_arch = "wasm32")]
-        let iterator = match self.options.subchunk_write_order() {
-            SubchunkWriteOrder::Unordered | SubchunkWriteOrder::Random | SubchunkWriteOrder::C => {
-                0..n_chunks
-            }
-        };
-
-        let encoded_chunks: Vec<(usize, Vec<u8>)> = iterator
+        // Encode chunks that are not entirely the fill value, collected in write order
+        let chunk_order = subchunk_write_order_indices(
+            self.options.subchunk_write_order(),
+            chunks_per_shard.as_slice(),
+        );
+        let encoded_chunks: Vec<(usize, Vec<u8>)> = chunk_order
             .concurrent_limit(shard_concurrent_limit)
             .filter_map(|chunk_index| {
                 self.encode_inner_by_chunk_index(
@@ -1201,7 +1199,9 @@ impl ShardingCodecBound {
                             }
                         });
                 }
-                SubchunkWriteOrder::C => {
+                SubchunkWriteOrder::C
+                | SubchunkWriteOrder::Morton
+                | SubchunkWriteOrder::Hilbert => {
                     let mut offset = encoded_shard_offset;
                     for (i, chunk) in &encoded_chunks {
                         let chunk_len_usize = chunk.len();
@@ -1252,6 +1252,64 @@ impl ShardingCodecBound {
         Ok(shard)
     }
 
+    /// Assemble a shard from encoded subchunks.
+    ///
+    /// `encoded_subchunks` holds the linearised index and encoded bytes of each subchunk that is present.
+    /// Absent subchunks are marked as missing (fill value) in the shard index.
+    /// Subchunks are written in the [`SubchunkWriteOrder`] of the codec, with `Unordered` using C order.
+    pub(crate) fn encode_shard_from_encoded_subchunks(
+        &self,
+        chunks_per_shard: &[NonZeroU64],
+        mut encoded_subchunks: Vec<(usize, bytes::Bytes)>,
+        options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let index_shape = sharding_index_shape(chunks_per_shard);
+        let index_encoded_size =
+            compute_index_encoded_size(self.index_codecs.as_ref(), &index_shape)?;
+        let index_encoded_size = usize::try_from(index_encoded_size).unwrap();
+
+        // Sort the subchunks into write order
+        let chunk_order =
+            subchunk_write_order_indices(self.options.subchunk_write_order(), chunks_per_shard);
+        let mut chunk_position = vec![0; chunk_order.len()];
+        for (position, chunk_index) in chunk_order.into_iter().enumerate() {
+            chunk_position[chunk_index] = position;
+        }
+        encoded_subchunks.sort_unstable_by_key(|(chunk_index, _)| chunk_position[*chunk_index]);
+
+        // Write the subchunks and update the shard index
+        let encoded_chunk_length = encoded_subchunks
+            .iter()
+            .map(|(_, bytes)| bytes.len())
+            .sum::<usize>();
+        let mut shard = Vec::with_capacity(encoded_chunk_length + index_encoded_size);
+        if self.index_location == ShardingIndexLocation::Start {
+            shard.resize(index_encoded_size, 0);
+        }
+        let mut shard_index = vec![u64::MAX; index_shape.num_elements_usize()];
+        for (chunk_index, chunk_encoded) in &encoded_subchunks {
+            shard_index[chunk_index * 2] = u64::try_from(shard.len()).unwrap();
+            shard_index[chunk_index * 2 + 1] = u64::try_from(chunk_encoded.len()).unwrap();
+            shard.extend_from_slice(chunk_encoded);
+        }
+
+        // Write shard index
+        let encoded_array_index = self.index_codecs.encode(
+            ArrayBytes::from(transmute_to_bytes_vec(shard_index)),
+            &index_shape,
+            options,
+        )?;
+        match self.index_location {
+            ShardingIndexLocation::Start => {
+                shard[..encoded_array_index.len()].copy_from_slice(&encoded_array_index);
+            }
+            ShardingIndexLocation::End => {
+                shard.extend_from_slice(&encoded_array_index);
+            }
+        }
+        Ok(shard)
+    }
+
     /// Decode the shard index inside the given encoded shard.
     ///
     /// # Errors
@@ -1296,4 +1354,68 @@ impl ShardingCodecBound {
             options,
         )
     }
+
+    /// Return the number of bytes of a shard referenced by its index, i.e. the encoded subchunks and the index itself.
+    ///
+    /// Only the shard index is read from `input_handle`.
+    /// Returns [`None`] if the shard does not exist.
+    pub(crate) fn shard_referenced_size(
+        &self,
+        input_handle: &dyn BytesPartialDecoderTraits,
+        shard_shape: &[NonZeroU64],
+        options: &CodecOptions,
+    ) -> Result<Option<u64>, CodecError> {
+        let shard_index = super::decode_shard_index_partial_decoder(
+            input_handle,
+            &self.index_codecs,
+            self.index_location,
+            shard_shape,
+            &self.subchunk_shape,
+            options,
+        )?;
+        shard_index
+            .map(|shard_index| self.shard_index_referenced_size(&shard_index, shard_shape))
+            .transpose()
+    }
+
+    #[cfg(feature = "async")]
+    /// Async variant of [`shard_referenced_size`](ShardingCodecBound::shard_referenced_size).
+    pub(crate) async fn async_shard_referenced_size(
+        &self,
+        input_handle: &dyn AsyncBytesPartialDecoderTraits,
+        shard_shape: &[NonZeroU64],
+        options: &CodecOptions,
+    ) -> Result<Option<u64>, CodecError> {
+        let shard_index = super::decode_shard_index_async_partial_decoder(
+            input_handle,
+            &self.index_codecs,
+            self.index_location,
+            shard_shape,
+            &self.subchunk_shape,
+            options,
+        )
+        .await?;
+        shard_index
+            .map(|shard_index| self.shard_index_referenced_size(&shard_index, shard_shape))
+            .transpose()
+    }
+
+    fn shard_index_referenced_size(
+        &self,
+        shard_index: &[u64],
+        shard_shape: &[NonZeroU64],
+    ) -> Result<u64, CodecError> {
+        let chunks_per_shard = calculate_chunks_per_shard(shard_shape, &self.subchunk_shape)?;
+        let index_shape = sharding_index_shape(&chunks_per_shard);
+        let index_encoded_size =
+            compute_index_encoded_size(self.index_codecs.as_ref(), &index_shape)?;
+        let chunks_size = shard_index
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .filter(|&&[offset, size]| offset != u64::MAX && size != u64::MAX)
+            .map(|[_, size]| size)
+            .sum::<u64>();
+        Ok(chunks_size + index_encoded_size)
+    }
 }
@@ -0,0 +1,207 @@
+//! A shared cache of decoded shard indexes.
+
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use lru::LruCache;
+use zarrs_codec::CodecOptions;
+use zarrs_storage::StoreKey;
+
+use super::ShardingIndexLocation;
+use crate::array::CodecChainBound;
+
+/// Cached shard indexes, keyed by the store key of the shard.
+type ShardIndexLruCache = LruCache<StoreKey, Arc<[u64]>>;
+
+/// A shared, bounded least-recently-used cache of decoded shard indexes.
+///
+/// Creating a [`ShardingPartialDecoder`](super::ShardingPartialDecoder) or [`AsyncShardingPartialDecoder`](super::AsyncShardingPartialDecoder) reads and decodes the shard index.
+/// With a shard index cache set in [`ShardingCodecOptions`](super::ShardingCodecOptions), the index of each shard is read once and reused by later partial decoders of the same shard.
+/// This can substantially reduce latency on high-latency stores when many small regions of the same shards are accessed.
+///
+/// Indexes are keyed by the store key of the shard only, so a cache must not be shared between stores.
+/// Indexes are only cached if the sharding codec reads directly from the store (i.e. there are no bytes-to-bytes codecs after it), and shards that do not exist are not cached.
+///
+/// A cached index is used without any store request, and is only validated lazily: if decoding with a cached index fails, it is removed from the cache and the index is read again.
+/// Writes and erasures through an [`Array`](crate::array::Array) invalidate the cached indexes of the affected shards.
+/// Shards rewritten by other writers may not be detected, so their cached indexes must be [invalidated](ShardIndexCache::invalidate) manually.
+///
+/// Cloning a `ShardIndexCache` produces a handle to the same cache.
+#[derive(Clone)]
+pub struct ShardIndexCache {
+    cache: Arc<Mutex<ShardIndexLruCache>>,
+}
+
+impl std::fmt::Debug for ShardIndexCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cache = self.lock();
+        f.debug_struct("ShardIndexCache")
+            .field("len", &cache.len())
+            .field("capacity", &cache.cap())
+            .finish()
+    }
+}
+
+impl ShardIndexCache {
+    /// Create a new shard index cache holding at most `index_capacity` shard indexes.
+    #[must_use]
+    pub fn new(index_capacity: u64) -> Self {
+        let cache = LruCache::new(
+            NonZeroUsize::new(usize::try_from(index_capacity).unwrap_or(usize::MAX))
+                .unwrap_or(NonZeroUsize::MIN),
+        );
+        Self {
+            cache: Arc::new(Mutex::new(cache)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ShardIndexLruCache> {
+        self.cache.lock().unwrap()
+    }
+
+    /// Return the maximum number of cached shard indexes.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.lock().cap().get()
+    }
+
+    /// Return the number of cached shard indexes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Remove the cached index of the shard at `key`.
+    pub fn invalidate(&self, key: &StoreKey) {
+        self.lock().pop(key);
+    }
+
+    /// Remove all cached shard indexes.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Return the cached index of the shard at `key`.
+    pub(crate) fn get(&self, key: &StoreKey) -> Option<Arc<[u64]>> {
+        self.lock().get(key).cloned()
+    }
+
+    pub(crate) fn insert(&self, key: StoreKey, shard_index: Arc<[u64]>) {
+        self.lock().push(key, shard_index);
+    }
+}
+
+/// The source of a shard index taken from a [`ShardIndexCache`].
+///
+/// Holds what is needed to remove a stale index from the cache and read the index again.
+pub(super) struct CachedShardIndexSource {
+    pub(super) cache: ShardIndexCache,
+    pub(super) key: StoreKey,
+    pub(super) index_codecs: CodecChainBound,
+    pub(super) index_location: ShardingIndexLocation,
+    pub(super) options: CodecOptions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shard_index_cache_lru() {
+        let cache = ShardIndexCache::new(2);
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 2);
+        let key = |k: &str| StoreKey::new(k).unwrap();
+        cache.insert(key("c/0"), Arc::from(vec![0, 1]));
+        cache.insert(key("c/1"), Arc::from(vec![2, 3]));
+        assert_eq!(cache.get(&key("c/0")).as_deref(), Some([0, 1].as_slice()));
+        cache.insert(key("c/2"), Arc::from(vec![4, 5]));
+        // c/1 was least recently used
+        assert!(cache.get(&key("c/1")).is_none());
+        assert_eq!(cache.len(), 2);
+
+        // Clones share the cache
+        let cache_clone = cache.clone();
+        cache_clone.invalidate(&key("c/0"));
+        assert!(cache.get(&key("c/0")).is_none());
+        assert_eq!(cache.len(), 1);
+        cache_clone.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn shard_index_cache_array() {
+        use crate::array::codec::ShardingCodecOptions;
+        use crate::array::{Array, ArrayBuilder, ArraySubset, CodecSpecificOptions, data_type};
+        use zarrs_storage::store::MemoryStore;
+
+        let cache = ShardIndexCache::new(8);
+        let store = Arc::new(MemoryStore::new());
+        let array =
+            ArrayBuilder::new(vec![16, 8], vec![8, 8], data_type::uint16(), 0u16)
+                .subchunk_shape(vec![4, 4])
+                .codec_specific_options(CodecSpecificOptions::default().with_option(
+                    ShardingCodecOptions::default().with_shard_index_cache(cache.clone()),
+                ))
+                .build(store.clone(), "/array")
+                .unwrap();
+        array.store_metadata().unwrap();
+        array
+            .store_array_subset(&array.subset_all(), vec![1u16; 128])
+            .unwrap();
+
+        // Partial reads populate the cache once per shard
+        let subset = ArraySubset::new_with_ranges(&[0..2, 0..2]);
+        for _ in 0..2 {
+            assert_eq!(
+                array.retrieve_array_subset::<Vec<u16>>(&subset).unwrap(),
+                vec![1u16; 4]
+            );
+        }
+        assert_eq!(cache.len(), 1);
+
+        // Writes invalidate the cached index
+        array
+            .store_array_subset(&ArraySubset::new_with_ranges(&[0..1, 0..4]), vec![2u16; 4])
+            .unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(
+            array.retrieve_array_subset::<Vec<u16>>(&subset).unwrap(),
+            vec![2, 2, 1, 1]
+        );
+        assert_eq!(cache.len(), 1);
+
+        // A shard rewritten by another writer is read again if decoding with the stale index fails
+        let array_other = Array::open(store, "/array").unwrap();
+        let mut chunk = vec![0u16; 64];
+        chunk[..2].copy_from_slice(&[3, 3]);
+        array_other.store_chunk(&[0, 0], chunk).unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u16>>(&ArraySubset::new_with_ranges(&[6..8, 6..8]))
+                .unwrap(),
+            vec![0u16; 4]
+        );
+        assert_eq!(
+            array.retrieve_array_subset::<Vec<u16>>(&subset).unwrap(),
+            vec![3, 3, 0, 0]
+        );
+        assert_eq!(cache.len(), 1);
+
+        // Erasures invalidate the cached index
+        array.erase_chunk(&[0, 0]).unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(
+            array.retrieve_array_subset::<Vec<u16>>(&subset).unwrap(),
+            vec![0u16; 4]
+        );
+        assert!(cache.is_empty());
+    }
+}
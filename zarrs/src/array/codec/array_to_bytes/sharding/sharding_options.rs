@@ -1,5 +1,7 @@
 //! Runtime options for the sharding codec.
 
+use super::ShardIndexCache;
+
 /// Write order for subchunks within a shard
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
@@ -16,7 +18,15 @@ pub enum SubchunkWriteOrder {
     /// Because subchunk writing is parallelized, it will often appear that subchunks are written at random with this setting although this is dependent on the parallelizable workload.
     /// For example in the degenerate case of one thread, you may observe (mostly) ordered chunks.
     Unordered,
-    // TODO: Morton order - depend on https://docs.rs/morton-encoding/latest/morton_encoding/?
+    /// Morton (Z-order) curve order.
+    ///
+    /// Spatially close subchunks are written close together in the shard, so the bytes of a region span a smaller part of the shard than with C order.
+    /// Each subchunk is still read with its own byte range, so this mostly benefits stores that read ahead or cache nearby bytes (e.g. a filesystem page cache).
+    Morton,
+    /// Hilbert curve order.
+    ///
+    /// Like [`Morton`](SubchunkWriteOrder::Morton), but consecutive subchunks are always face neighbours (for power-of-two subchunk grids), giving slightly better locality at a small extra encoding cost.
+    Hilbert,
 }
 
 /// Runtime options for the [`ShardingCodec`](super::ShardingCodec).
@@ -24,12 +34,14 @@ pub enum SubchunkWriteOrder {
 #[non_exhaustive]
 pub struct ShardingCodecOptions {
     subchunk_write_order: SubchunkWriteOrder,
+    shard_index_cache: Option<ShardIndexCache>,
 }
 
 impl Default for ShardingCodecOptions {
     fn default() -> Self {
         Self {
             subchunk_write_order: SubchunkWriteOrder::Unordered,
+            shard_index_cache: None,
         }
     }
 }
@@ -56,6 +68,28 @@ impl ShardingCodecOptions {
     pub fn subchunk_write_order(&self) -> SubchunkWriteOrder {
         self.subchunk_write_order
     }
+
+    /// Set the shard index cache used by partial decoders.
+    #[must_use]
+    pub fn with_shard_index_cache(mut self, shard_index_cache: ShardIndexCache) -> Self {
+        self.shard_index_cache = Some(shard_index_cache);
+        self
+    }
+
+    /// Set the shard index cache used by partial decoders.
+    pub fn set_shard_index_cache(
+        &mut self,
+        shard_index_cache: Option<ShardIndexCache>,
+    ) -> &mut Self {
+        self.shard_index_cache = shard_index_cache;
+        self
+    }
+
+    /// Return the shard index cache used by partial decoders.
+    #[must_use]
+    pub fn shard_index_cache(&self) -> Option<&ShardIndexCache> {
+        self.shard_index_cache.as_ref()
+    }
 }
 
 #[cfg(test)]
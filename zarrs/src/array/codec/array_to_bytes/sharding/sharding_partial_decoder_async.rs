@@ -1,5 +1,5 @@
 use std::num::NonZeroU64;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
@@ -8,6 +8,7 @@ use unsafe_cell_slice::UnsafeCellSlice;
 use zarrs_chunk_grid::ChunkGridTraits;
 use zarrs_data_type::FillValue;
 
+use super::sharding_index_cache::CachedShardIndexSource;
 use super::{
     ShardingCodecOptions, ShardingIndexLocation, calculate_chunks_per_shard,
     nested_local_subchunk_grids,
@@ -37,7 +38,9 @@ pub struct AsyncShardingPartialDecoder {
     shard_shape: ChunkShape,
     subchunk_shape: ChunkShape,
     inner_codecs: Arc<CodecChainBound>,
-    shard_index: Option<Vec<u64>>,
+    shard_index: RwLock<Option<Arc<[u64]>>>,
+    /// Set if the shard index was taken from a shard index cache and has not been read again.
+    cached_shard_index_source: Mutex<Option<CachedShardIndexSource>>,
     #[expect(dead_code)] // TODO: Remove when sharding-specific options are added
     sharding_options: ShardingCodecOptions,
 }
@@ -55,26 +58,79 @@ impl AsyncShardingPartialDecoder {
         options: &CodecOptions,
         sharding_options: ShardingCodecOptions,
     ) -> Result<Self, CodecError> {
-        let shard_index = super::decode_shard_index_async_partial_decoder(
-            &*input_handle,
-            index_codecs,
-            index_location,
-            &shard_shape,
-            &subchunk_shape,
-            options,
-        )
-        .await?;
+        // Reuse a cached shard index if the shard is read directly from a store
+        let cache_key = sharding_options
+            .shard_index_cache()
+            .zip(input_handle.store_key());
+        let cached_shard_index =
+            cache_key.and_then(|(cache, key)| Some((cache.get(key)?, cache, key)));
+        let (shard_index, cached_shard_index_source) =
+            if let Some((shard_index, cache, key)) = cached_shard_index {
+                let source = CachedShardIndexSource {
+                    cache: cache.clone(),
+                    key: key.clone(),
+                    index_codecs: index_codecs.clone(),
+                    index_location,
+                    options: *options,
+                };
+                (Some(shard_index), Some(source))
+            } else {
+                let shard_index = super::decode_shard_index_async_partial_decoder(
+                    &*input_handle,
+                    index_codecs,
+                    index_location,
+                    &shard_shape,
+                    &subchunk_shape,
+                    options,
+                )
+                .await?
+                .map(Arc::<[u64]>::from);
+                if let (Some((cache, key)), Some(shard_index)) = (cache_key, &shard_index) {
+                    cache.insert(key.clone(), shard_index.clone());
+                }
+                (shard_index, None)
+            };
 
         Ok(Self {
             input_handle,
             shard_shape,
             subchunk_shape,
             inner_codecs,
-            shard_index,
+            shard_index: RwLock::new(shard_index),
+            cached_shard_index_source: Mutex::new(cached_shard_index_source),
             sharding_options,
         })
     }
 
+    fn shard_index(&self) -> Option<Arc<[u64]>> {
+        self.shard_index.read().unwrap().clone()
+    }
+
+    /// Read the shard index again if it was taken from a shard index cache, since it may be stale.
+    ///
+    /// Returns false if the shard index was not taken from a cache or has already been read again.
+    async fn reload_cached_shard_index(&self) -> Result<bool, CodecError> {
+        let Some(source) = self.cached_shard_index_source.lock().unwrap().take() else {
+            return Ok(false);
+        };
+        source.cache.invalidate(&source.key);
+        let shard_index = super::decode_shard_index_async_partial_decoder(
+            &*self.input_handle,
+            &source.index_codecs,
+            source.index_location,
+            &self.shard_shape,
+            &self.subchunk_shape,
+            &source.options,
+        )
+        .await?
+        .map(Arc::<[u64]>::from);
+        if let Some(shard_index) = &shard_index {
+            source.cache.insert(source.key, shard_index.clone());
+        }
+        *self.shard_index.write().unwrap() = shard_index;
+        Ok(true)
+    }
+
     /// Retrieve the byte range of an encoded subchunk.
     ///
     /// The `chunk_indices` are relative to the start of the shard.
@@ -83,7 +139,7 @@ impl AsyncShardingPartialDecoder {
         chunk_indices: &[u64],
     ) -> Result<Option<ByteRange>, CodecError> {
         super::subchunk_byte_range(
-            self.shard_index.as_deref(),
+            self.shard_index().as_deref(),
             &self.shard_shape,
             &self.subchunk_shape,
             chunk_indices,
@@ -97,13 +153,18 @@ impl AsyncShardingPartialDecoder {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ArrayBytesRaw<'_>>, CodecError> {
-        let byte_range = self.subchunk_byte_range(chunk_indices)?;
-        if let Some(byte_range) = byte_range {
-            self.input_handle
-                .partial_decode(byte_range, &CodecOptions::default())
-                .await
-        } else {
-            Ok(None)
+        loop {
+            let result = if let Some(byte_range) = self.subchunk_byte_range(chunk_indices)? {
+                self.input_handle
+                    .partial_decode(byte_range, &CodecOptions::default())
+                    .await
+            } else {
+                Ok(None)
+            };
+            if result.is_err() && self.reload_cached_shard_index().await? {
+                continue;
+            }
+            return result;
         }
     }
 }
@@ -221,7 +282,10 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
 
     fn size_held(&self) -> usize {
         self.input_handle.size_held()
-            + self.shard_index.as_ref().map_or(0, Vec::len) * size_of::<u64>()
+            + self
+                .shard_index()
+                .map_or(0, |shard_index| shard_index.len())
+                * size_of::<u64>()
     }
 
     async fn partial_decode(
@@ -229,16 +293,22 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
         indexer: &dyn crate::array::Indexer,
         options: &CodecOptions,
     ) -> Result<ArrayBytes<'_>, CodecError> {
-        partial_decode(
-            &self.input_handle,
-            &self.shard_shape,
-            &self.subchunk_shape,
-            &self.inner_codecs,
-            self.shard_index.as_deref(),
-            indexer,
-            options,
-        )
-        .await
+        loop {
+            let result = partial_decode(
+                &self.input_handle,
+                &self.shard_shape,
+                &self.subchunk_shape,
+                &self.inner_codecs,
+                self.shard_index().as_deref(),
+                indexer,
+                options,
+            )
+            .await;
+            if result.is_err() && self.reload_cached_shard_index().await? {
+                continue;
+            }
+            return result;
+        }
     }
 
     async fn partial_decode_into(
@@ -258,17 +328,23 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
             && let Some(subset) = indexer.as_array_subset()
             && let ArrayBytesDecodeIntoTarget::Fixed(output_view) = output_target
         {
-            partial_decode_fixed_array_subset_into(
-                &self.input_handle,
-                &self.shard_shape,
-                &self.subchunk_shape,
-                &self.inner_codecs,
-                self.shard_index.as_deref(),
-                subset,
-                options,
-                output_view,
-            )
-            .await
+            loop {
+                let result = partial_decode_fixed_array_subset_into(
+                    &self.input_handle,
+                    &self.shard_shape,
+                    &self.subchunk_shape,
+                    &self.inner_codecs,
+                    self.shard_index().as_deref(),
+                    subset,
+                    options,
+                    output_view,
+                )
+                .await;
+                if result.is_err() && self.reload_cached_shard_index().await? {
+                    continue;
+                }
+                return result;
+            }
         } else {
             let decoded_value = self.partial_decode(indexer, options).await?;
             decode_into_array_bytes_target(&decoded_value, output_target)
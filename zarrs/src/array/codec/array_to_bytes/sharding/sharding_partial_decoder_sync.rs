@@ -1,5 +1,5 @@
 use std::num::NonZeroU64;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
@@ -7,6 +7,7 @@ use rayon::prelude::*;
 use unsafe_cell_slice::UnsafeCellSlice;
 use zarrs_chunk_grid::{ArraySubset, ChunkGridTraits};
 
+use super::sharding_index_cache::CachedShardIndexSource;
 use super::{
     ShardingCodecOptions, ShardingIndexLocation, calculate_chunks_per_shard,
     nested_local_subchunk_grids,
@@ -36,7 +37,9 @@ pub struct ShardingPartialDecoder {
     shard_shape: ChunkShape,
     subchunk_shape: ChunkShape,
     inner_codecs: Arc<CodecChainBound>,
-    shard_index: Option<Vec<u64>>,
+    shard_index: RwLock<Option<Arc<[u64]>>>,
+    /// Set if the shard index was taken from a shard index cache and has not been read again.
+    cached_shard_index_source: Mutex<Option<CachedShardIndexSource>>,
     #[expect(dead_code)] // TODO: Remove when sharding-specific options are added
     sharding_options: ShardingCodecOptions,
 }
@@ -54,25 +57,77 @@ impl ShardingPartialDecoder {
         options: &CodecOptions,
         sharding_options: ShardingCodecOptions,
     ) -> Result<Self, CodecError> {
-        let shard_index = super::decode_shard_index_partial_decoder(
-            &*input_handle,
-            index_codecs,
-            index_location,
-            &shard_shape,
-            &subchunk_shape,
-            options,
-        )?;
+        // Reuse a cached shard index if the shard is read directly from a store
+        let cache_key = sharding_options
+            .shard_index_cache()
+            .zip(input_handle.store_key());
+        let cached_shard_index =
+            cache_key.and_then(|(cache, key)| Some((cache.get(key)?, cache, key)));
+        let (shard_index, cached_shard_index_source) =
+            if let Some((shard_index, cache, key)) = cached_shard_index {
+                let source = CachedShardIndexSource {
+                    cache: cache.clone(),
+                    key: key.clone(),
+                    index_codecs: index_codecs.clone(),
+                    index_location,
+                    options: *options,
+                };
+                (Some(shard_index), Some(source))
+            } else {
+                let shard_index = super::decode_shard_index_partial_decoder(
+                    &*input_handle,
+                    index_codecs,
+                    index_location,
+                    &shard_shape,
+                    &subchunk_shape,
+                    options,
+                )?
+                .map(Arc::<[u64]>::from);
+                if let (Some((cache, key)), Some(shard_index)) = (cache_key, &shard_index) {
+                    cache.insert(key.clone(), shard_index.clone());
+                }
+                (shard_index, None)
+            };
 
         Ok(Self {
             input_handle,
             shard_shape,
             subchunk_shape,
             inner_codecs,
-            shard_index,
+            shard_index: RwLock::new(shard_index),
+            cached_shard_index_source: Mutex::new(cached_shard_index_source),
             sharding_options,
         })
     }
 
+    fn shard_index(&self) -> Option<Arc<[u64]>> {
+        self.shard_index.read().unwrap().clone()
+    }
+
+    /// Read the shard index again if it was taken from a shard index cache, since it may be stale.
+    ///
+    /// Returns false if the shard index was not taken from a cache or has already been read again.
+    fn reload_cached_shard_index(&self) -> Result<bool, CodecError> {
+        let Some(source) = self.cached_shard_index_source.lock().unwrap().take() else {
+            return Ok(false);
+        };
+        source.cache.invalidate(&source.key);
+        let shard_index = super::decode_shard_index_partial_decoder(
+            &*self.input_handle,
+            &source.index_codecs,
+            source.index_location,
+            &self.shard_shape,
+            &self.subchunk_shape,
+            &source.options,
+        )?
+        .map(Arc::<[u64]>::from);
+        if let Some(shard_index) = &shard_index {
+            source.cache.insert(source.key, shard_index.clone());
+        }
+        *self.shard_index.write().unwrap() = shard_index;
+        Ok(true)
+    }
+
     /// Retrieve the byte range of an encoded subchunk.
     ///
     /// The `chunk_indices` are relative to the start of the shard.
@@ -81,7 +136,7 @@ impl ShardingPartialDecoder {
         chunk_indices: &[u64],
     ) -> Result<Option<ByteRange>, CodecError> {
         super::subchunk_byte_range(
-            self.shard_index.as_deref(),
+            self.shard_index().as_deref(),
             &self.shard_shape,
             &self.subchunk_shape,
             chunk_indices,
@@ -95,12 +150,17 @@ impl ShardingPartialDecoder {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ArrayBytesRaw<'_>>, CodecError> {
-        let byte_range = self.subchunk_byte_range(chunk_indices)?;
-        if let Some(byte_range) = byte_range {
-            self.input_handle
-                .partial_decode(byte_range, &CodecOptions::default())
-        } else {
-            Ok(None)
+        loop {
+            let result = if let Some(byte_range) = self.subchunk_byte_range(chunk_indices)? {
+                self.input_handle
+                    .partial_decode(byte_range, &CodecOptions::default())
+            } else {
+                Ok(None)
+            };
+            if result.is_err() && self.reload_cached_shard_index()? {
+                continue;
+            }
+            return result;
         }
     }
 }
@@ -219,7 +279,10 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
 
     fn size_held(&self) -> usize {
         self.input_handle.size_held()
-            + self.shard_index.as_ref().map_or(0, Vec::len) * size_of::<u64>()
+            + self
+                .shard_index()
+                .map_or(0, |shard_index| shard_index.len())
+                * size_of::<u64>()
     }
 
     fn partial_decode(
@@ -227,15 +290,21 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
         indexer: &dyn crate::array::Indexer,
         options: &CodecOptions,
     ) -> Result<ArrayBytes<'_>, CodecError> {
-        partial_decode(
-            &self.input_handle,
-            &self.shard_shape,
-            &self.subchunk_shape,
-            &self.inner_codecs,
-            self.shard_index.as_deref(),
-            indexer,
-            options,
-        )
+        loop {
+            let result = partial_decode(
+                &self.input_handle,
+                &self.shard_shape,
+                &self.subchunk_shape,
+                &self.inner_codecs,
+                self.shard_index().as_deref(),
+                indexer,
+                options,
+            );
+            if result.is_err() && self.reload_cached_shard_index()? {
+                continue;
+            }
+            return result;
+        }
     }
 
     fn partial_decode_into(
@@ -255,16 +324,22 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
             && let Some(subset) = indexer.as_array_subset()
             && let ArrayBytesDecodeIntoTarget::Fixed(output_view) = output_target
         {
-            partial_decode_fixed_array_subset_into(
-                &self.input_handle,
-                &self.shard_shape,
-                &self.subchunk_shape,
-                &self.inner_codecs,
-                self.shard_index.as_deref(),
-                subset,
-                options,
-                output_view,
-            )
+            loop {
+                let result = partial_decode_fixed_array_subset_into(
+                    &self.input_handle,
+                    &self.shard_shape,
+                    &self.subchunk_shape,
+                    &self.inner_codecs,
+                    self.shard_index().as_deref(),
+                    subset,
+                    options,
+                    output_view,
+                );
+                if result.is_err() && self.reload_cached_shard_index()? {
+                    continue;
+                }
+                return result;
+            }
         } else {
             let decoded_value = self.partial_decode(indexer, options)?;
             decode_into_array_bytes_target(&decoded_value, output_target)
@@ -37,7 +37,6 @@ pub(crate) struct ShardingPartialEncoder {
     index_location: ShardingIndexLocation,
     index_shape: ChunkShape,
     shard_index: Arc<Mutex<Vec<u64>>>,
-    #[expect(dead_code)] // TODO: Remove when sharding-specific options are added
     sharding_options: ShardingCodecOptions,
 }
 
@@ -90,6 +89,17 @@ impl ShardingPartialEncoder {
             sharding_options,
         })
     }
+
+    /// Remove the index of this shard from the shard index cache after it has been modified.
+    fn invalidate_cached_shard_index(&self) {
+        if let Some((shard_index_cache, key)) = self
+            .sharding_options
+            .shard_index_cache()
+            .zip(self.input_output_handle.store_key())
+        {
+            shard_index_cache.invalidate(key);
+        }
+    }
 }
 
 impl ArrayPartialDecoderSubchunkingTraits for ShardingPartialEncoder {
@@ -143,7 +153,9 @@ impl ArrayPartialDecoderTraits for ShardingPartialEncoder {
 
 impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
     fn erase(&self) -> Result<(), super::CodecError> {
-        self.input_output_handle.erase()
+        self.input_output_handle.erase()?;
+        self.invalidate_cached_shard_index();
+        Ok(())
     }
 
     #[allow(clippy::too_many_lines)]
@@ -481,6 +493,7 @@ impl ArrayPartialEncoderTraits for ShardingPartialEncoder {
                 }
             }
         }
+        self.invalidate_cached_shard_index();
         Ok(())
     }
 
@@ -38,7 +38,6 @@ pub(crate) struct AsyncShardingPartialEncoder {
     index_location: ShardingIndexLocation,
     index_shape: ChunkShape,
     shard_index: Arc<AsyncMutex<Vec<u64>>>,
-    #[expect(dead_code)] // TODO: Remove when sharding-specific options are added
     sharding_options: ShardingCodecOptions,
 }
 
@@ -92,6 +91,17 @@ impl AsyncShardingPartialEncoder {
             sharding_options,
         })
     }
+
+    /// Remove the index of this shard from the shard index cache after it has been modified.
+    fn invalidate_cached_shard_index(&self) {
+        if let Some((shard_index_cache, key)) = self
+            .sharding_options
+            .shard_index_cache()
+            .zip(self.input_output_handle.store_key())
+        {
+            shard_index_cache.invalidate(key);
+        }
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
@@ -154,7 +164,9 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialEncoder {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl AsyncArrayPartialEncoderTraits for AsyncShardingPartialEncoder {
     async fn erase(&self) -> Result<(), super::CodecError> {
-        self.input_output_handle.erase().await
+        self.input_output_handle.erase().await?;
+        self.invalidate_cached_shard_index();
+        Ok(())
     }
 
     #[allow(clippy::too_many_lines)]
@@ -497,6 +509,7 @@ impl AsyncArrayPartialEncoderTraits for AsyncShardingPartialEncoder {
                 }
             }
         }
+        self.invalidate_cached_shard_index();
         Ok(())
     }
 
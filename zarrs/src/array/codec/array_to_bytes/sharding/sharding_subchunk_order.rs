@@ -0,0 +1,196 @@
+//! Subchunk orderings along space-filling curves.
+
+use std::cmp::Ordering;
+use std::num::NonZeroU64;
+
+use super::SubchunkWriteOrder;
+
+/// Return the linearised (C order) subchunk indices of a shard in the order they should be written.
+///
+/// The `Unordered` order is returned in C order, the encoder is free to reorder it.
+pub(super) fn subchunk_write_order_indices(
+    order: SubchunkWriteOrder,
+    chunks_per_shard: &[NonZeroU64],
+) -> Vec<usize> {
+    let n_chunks = chunks_per_shard
+        .iter()
+        .map(|i| usize::try_from(i.get()).unwrap())
+        .product::<usize>();
+    match order {
+        SubchunkWriteOrder::C | SubchunkWriteOrder::Unordered | SubchunkWriteOrder::Random => {
+            (0..n_chunks).collect()
+        }
+        SubchunkWriteOrder::Morton => curve_order(chunks_per_shard, |_| {}),
+        SubchunkWriteOrder::Hilbert => {
+            let max_extent = chunks_per_shard.iter().map(|i| i.get()).max().unwrap_or(1);
+            let bits = u64::BITS - (max_extent - 1).leading_zeros();
+            curve_order(chunks_per_shard, |coords| {
+                hilbert_axes_to_transpose(coords, bits);
+            })
+        }
+    }
+}
+
+/// Sort the subchunk indices by the Morton order of their (optionally transformed) coordinates.
+fn curve_order(chunks_per_shard: &[NonZeroU64], transform: impl Fn(&mut [u64])) -> Vec<usize> {
+    let n_chunks = chunks_per_shard
+        .iter()
+        .map(|i| usize::try_from(i.get()).unwrap())
+        .product::<usize>();
+    let mut keys: Vec<(Vec<u64>, usize)> = (0..n_chunks)
+        .map(|chunk_index| {
+            let mut coords = vec![0; chunks_per_shard.len()];
+            let mut remainder = chunk_index as u64;
+            for (coord, extent) in coords.iter_mut().zip(chunks_per_shard).rev() {
+                *coord = remainder % extent.get();
+                remainder /= extent.get();
+            }
+            transform(&mut coords);
+            (coords, chunk_index)
+        })
+        .collect();
+    keys.sort_unstable_by(|(a, _), (b, _)| morton_cmp(a, b));
+    keys.into_iter()
+        .map(|(_, chunk_index)| chunk_index)
+        .collect()
+}
+
+/// Compare two coordinates by their position along a Morton (Z-order) curve.
+///
+/// The first dimension is the most significant, consistent with C order.
+/// This compares the dimension with the most significant differing bit rather than materialising the interleaved code, so it is not limited by the total bit width.
+fn morton_cmp(a: &[u64], b: &[u64]) -> Ordering {
+    let less_msb = |x: u64, y: u64| x < y && x < (x ^ y);
+    let mut dim = 0;
+    let mut max_xor = 0;
+    for (d, (a, b)) in a.iter().zip(b).enumerate() {
+        let xor = a ^ b;
+        if less_msb(max_xor, xor) {
+            dim = d;
+            max_xor = xor;
+        }
+    }
+    a.get(dim).cmp(&b.get(dim))
+}
+
+/// Transform coordinates in place to the "transposed" Hilbert index.
+///
+/// Interleaving the bits of the result (first dimension most significant) gives the Hilbert index.
+/// See J. Skilling, "Programming the Hilbert curve", AIP Conference Proceedings 707, 381 (2004).
+#[allow(clippy::many_single_char_names)] // follows the notation of the reference
+fn hilbert_axes_to_transpose(x: &mut [u64], bits: u32) {
+    if bits == 0 || x.is_empty() {
+        return;
+    }
+    let n = x.len();
+    let m = 1u64 << (bits - 1);
+
+    // Inverse undo
+    let mut q = m;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if x[i] & q != 0 {
+                x[0] ^= p; // invert
+            } else {
+                let t = (x[0] ^ x[i]) & p; // exchange
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = m;
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for xi in x.iter_mut() {
+        *xi ^= t;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(shape: &[u64]) -> Vec<NonZeroU64> {
+        shape.iter().map(|&i| NonZeroU64::new(i).unwrap()).collect()
+    }
+
+    fn unravel(index: usize, shape: &[u64]) -> Vec<u64> {
+        let mut coords = vec![0; shape.len()];
+        let mut remainder = index as u64;
+        for (coord, extent) in coords.iter_mut().zip(shape).rev() {
+            *coord = remainder % extent;
+            remainder /= extent;
+        }
+        coords
+    }
+
+    fn assert_permutation(order: &[usize], shape: &[u64]) {
+        let n = usize::try_from(shape.iter().product::<u64>()).unwrap();
+        let mut sorted = order.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn subchunk_order_c() {
+        let order = subchunk_write_order_indices(SubchunkWriteOrder::C, &shape(&[2, 3]));
+        assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn subchunk_order_morton() {
+        let order = subchunk_write_order_indices(SubchunkWriteOrder::Morton, &shape(&[4, 4]));
+        assert_eq!(
+            order,
+            vec![0, 1, 4, 5, 2, 3, 6, 7, 8, 9, 12, 13, 10, 11, 14, 15]
+        );
+
+        // Non power of two and non-cubic shapes are a permutation
+        for s in [[3u64, 5, 2], [1, 7, 1], [6, 6, 6]] {
+            let order = subchunk_write_order_indices(SubchunkWriteOrder::Morton, &shape(&s));
+            assert_permutation(&order, &s);
+        }
+    }
+
+    #[test]
+    fn subchunk_order_hilbert_adjacent() {
+        for s in [
+            vec![2u64, 2],
+            vec![8, 8],
+            vec![4, 4, 4],
+            vec![2, 2, 2, 2],
+            vec![16],
+        ] {
+            let order = subchunk_write_order_indices(SubchunkWriteOrder::Hilbert, &shape(&s));
+            assert_permutation(&order, &s);
+            assert_eq!(order[0], 0);
+            // Consecutive subchunks along a Hilbert curve are face neighbours
+            for pair in order.windows(2) {
+                let a = unravel(pair[0], &s);
+                let b = unravel(pair[1], &s);
+                let distance: u64 = a.iter().zip(&b).map(|(a, b)| a.abs_diff(*b)).sum();
+                assert_eq!(distance, 1, "{a:?} -> {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn subchunk_order_hilbert_non_power_of_two() {
+        for s in [[3u64, 5, 2], [1, 1, 1], [5, 1, 3]] {
+            let order = subchunk_write_order_indices(SubchunkWriteOrder::Hilbert, &shape(&s));
+            assert_permutation(&order, &s);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use super::{VlenCodecConfiguration, VlenCodecConfigurationV0_1, vlen_partial_decoder};
 use crate::array::codec::BytesCodec;
+use crate::array::data_type::{list_items_decode, list_items_encode};
 use crate::array::{
     ArrayBytes, ArrayBytesOffsets, ArrayBytesRaw, BytesRepresentation, CodecChain, CodecChainBound,
     DataType, DataTypeSize, Endianness, FillValue, transmute_to_bytes_vec,
@@ -240,6 +241,8 @@ impl ArrayToBytesCodecTraits for VlenCodecBound {
         bytes.validate(num_elements, &self.data_type)?;
         let (data, offsets) = bytes.into_variable()?.into_parts();
         assert_eq!(offsets.len(), usize::try_from(num_elements).unwrap() + 1);
+        // The items of `zarrs.list` elements are stored as little-endian
+        let data = list_items_encode(&self.data_type, data, Endianness::Little)?;
 
         // Encode offsets
         let num_offsets =
@@ -314,6 +317,7 @@ impl ArrayToBytesCodecTraits for VlenCodecBound {
             self.index_location,
             options,
         )?;
+        let bytes = list_items_decode(&self.data_type, bytes.into(), Endianness::Little)?;
         let offsets = ArrayBytesOffsets::new(offsets)?;
         let array_bytes = ArrayBytes::new_vlen(bytes, offsets)?;
         Ok(array_bytes)
@@ -4,7 +4,8 @@ use std::num::NonZeroU64;
 use std::sync::Arc;
 
 use crate::array::array_bytes_internal::extract_decoded_regions_vlen;
-use crate::array::{ArrayBytes, ArrayBytesRaw, CodecChainBound, DataType, FillValue};
+use crate::array::data_type::list_items_decode;
+use crate::array::{ArrayBytes, ArrayBytesRaw, CodecChainBound, DataType, Endianness, FillValue};
 use zarrs_codec::{
     ArrayPartialDecoderNoSubchunkingTraits, ArrayPartialDecoderTraits, BytesPartialDecoderTraits,
     CodecError, CodecOptions,
@@ -70,6 +71,8 @@ fn decode_vlen_bytes<'a>(
             index_location,
             options,
         )?;
+        // The items of `zarrs.list` elements are stored as little-endian
+        let data = list_items_decode(data_type, data.into(), Endianness::Little)?;
         Ok(ArrayBytes::Variable(extract_decoded_regions_vlen(
             &data, &index, indexer, shape,
         )?))
@@ -17,14 +17,28 @@
 //! ### Codec `id` Aliases (Zarr V2)
 //! - `vlen-array`
 //!
+//! This codec encodes arrays with a variable-length data type, such as the `zarrs.list` data type.
+//!
 //! ### Codec `configuration` Example - [`VlenArrayCodecConfiguration`]:
 //! ```json
 //! {}
 //! ```
+//!
+//! The item `dtype` written by `numcodecs` is also accepted, and must match the item data type of a `zarrs.list` array:
+//! ```json
+//! {
+//!     "dtype": "<i4"
+//! }
+//! ```
 
 use crate::array::codec::array_to_bytes::vlen_v2::vlen_v2_macros;
 pub use zarrs_metadata_ext::codec::vlen_array::{
     VlenArrayCodecConfiguration, VlenArrayCodecConfigurationV1,
 };
 
-vlen_v2_macros::vlen_v2_module!(vlen_array, vlen_array_codec, VlenArrayCodec);
+vlen_v2_macros::vlen_v2_module!(
+    vlen_array,
+    vlen_array_codec,
+    VlenArrayCodec,
+    VlenArrayCodecConfiguration
+);
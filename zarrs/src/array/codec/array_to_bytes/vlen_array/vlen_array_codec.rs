@@ -1,3 +1,27 @@
+use zarrs_plugin::PluginCreateError;
+
+use super::VlenArrayCodecConfiguration;
 use crate::array::codec::array_to_bytes::vlen_v2::vlen_v2_macros;
 
 vlen_v2_macros::vlen_v2_codec!(VlenArrayCodec, "vlen-array");
+
+impl VlenArrayCodec {
+    /// Create a new `vlen-array` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &VlenArrayCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            VlenArrayCodecConfiguration::V1(configuration) => Ok(Self {
+                inner: Arc::new(VlenV2Codec::new_with_item_dtype(
+                    configuration.dtype.clone(),
+                )),
+            }),
+            _ => Err(PluginCreateError::Other(
+                "this vlen-array codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
@@ -23,4 +23,9 @@ pub use zarrs_metadata_ext::codec::vlen_bytes::{
     VlenBytesCodecConfiguration, VlenBytesCodecConfigurationV1,
 };
 
-vlen_v2_macros::vlen_v2_module!(vlen_bytes, vlen_bytes_codec, VlenBytesCodec);
+vlen_v2_macros::vlen_v2_module!(
+    vlen_bytes,
+    vlen_bytes_codec,
+    VlenBytesCodec,
+    VlenBytesCodecConfiguration
+);
@@ -1,3 +1,23 @@
+use zarrs_plugin::PluginCreateError;
+
+use super::VlenBytesCodecConfiguration;
 use crate::array::codec::array_to_bytes::vlen_v2::vlen_v2_macros;
 
 vlen_v2_macros::vlen_v2_codec!(VlenBytesCodec, "vlen-bytes");
+
+impl VlenBytesCodec {
+    /// Create a new `vlen-bytes` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &VlenBytesCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            VlenBytesCodecConfiguration::V1(_) => Ok(Self::new()),
+            _ => Err(PluginCreateError::Other(
+                "this vlen-bytes codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
@@ -23,4 +23,9 @@ pub use zarrs_metadata_ext::codec::vlen_utf8::{
     VlenUtf8CodecConfiguration, VlenUtf8CodecConfigurationV1,
 };
 
-vlen_v2_macros::vlen_v2_module!(vlen_utf8, vlen_utf8_codec, VlenUtf8Codec);
+vlen_v2_macros::vlen_v2_module!(
+    vlen_utf8,
+    vlen_utf8_codec,
+    VlenUtf8Codec,
+    VlenUtf8CodecConfiguration
+);
@@ -1,3 +1,23 @@
+use zarrs_plugin::PluginCreateError;
+
+use super::VlenUtf8CodecConfiguration;
 use crate::array::codec::array_to_bytes::vlen_v2::vlen_v2_macros;
 
 vlen_v2_macros::vlen_v2_codec!(VlenUtf8Codec, "vlen-utf8");
+
+impl VlenUtf8Codec {
+    /// Create a new `vlen-utf8` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &VlenUtf8CodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            VlenUtf8CodecConfiguration::V1(_) => Ok(Self::new()),
+            _ => Err(PluginCreateError::Other(
+                "this vlen-utf8 codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
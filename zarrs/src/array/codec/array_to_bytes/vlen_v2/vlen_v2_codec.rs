@@ -16,24 +16,74 @@ use zarrs_codec::{
 #[cfg(feature = "async")]
 use zarrs_codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
 use zarrs_metadata::Configuration;
+use zarrs_metadata::Endianness;
+use zarrs_metadata::v2::{DataTypeMetadataV2, data_type_metadata_v2_to_endianness};
+use zarrs_metadata_ext::codec::vlen_array::{
+    VlenArrayCodecConfiguration, VlenArrayCodecConfigurationV1,
+};
 use zarrs_plugin::{ExtensionAliasesV3, ZarrVersion};
 
+use crate::array::data_type::{ListDataType, list_items_decode, list_items_encode};
+use crate::convert::data_type_metadata_v2_to_v3;
+
 /// The `vlen_v2` codec implementation.
 #[derive(Debug, Clone, Default)]
-pub struct VlenV2Codec {}
+pub struct VlenV2Codec {
+    item_dtype: Option<String>,
+}
 
 /// The `vlen_v2` codec implementation bound to a data type and fill value.
 #[derive(Debug, Clone)]
 struct VlenV2CodecBound {
     data_type: DataType,
     fill_value: FillValue,
+    /// The byte order of the items of `zarrs.list` elements.
+    item_endianness: Endianness,
 }
 
 impl VlenV2Codec {
     /// Create a new `vlen_v2` codec.
     #[must_use]
     pub fn new() -> Self {
-        Self {}
+        Self { item_dtype: None }
+    }
+
+    /// Create a new `vlen_v2` codec for lists with a `numpy` item data type (e.g. `<i4`).
+    #[must_use]
+    pub(crate) fn new_with_item_dtype(item_dtype: Option<String>) -> Self {
+        Self { item_dtype }
+    }
+
+    /// Validate that `data_type` is a `zarrs.list` data type with items matching the `numpy` `item_dtype`.
+    ///
+    /// Returns the byte order of the items, if applicable.
+    fn validate_item_dtype(
+        item_dtype: &str,
+        data_type: &DataType,
+    ) -> Result<Option<Endianness>, CodecCreateError> {
+        let Some(list) = data_type.downcast_ref::<ListDataType>() else {
+            return Err(CodecCreateError::UnsupportedDataType(
+                data_type.clone(),
+                Self::aliases_v3().default_name.to_string(),
+            ));
+        };
+
+        // The items are stored in the byte order of the `dtype`
+        let item_data_type = DataTypeMetadataV2::Simple(item_dtype.to_string());
+        let endianness = data_type_metadata_v2_to_endianness(&item_data_type)
+            .map_err(|err| CodecCreateError::Other(err.to_string()))?;
+        let matches = data_type_metadata_v2_to_v3(&item_data_type)
+            .ok()
+            .and_then(|metadata| DataType::from_metadata(&metadata).ok())
+            .is_some_and(|item_data_type| &item_data_type == list.data_type());
+        if matches {
+            Ok(endianness)
+        } else {
+            Err(CodecCreateError::Other(format!(
+                "the vlen-array dtype {item_dtype} does not match the list item data type {}",
+                list.data_type()
+            )))
+        }
     }
 }
 
@@ -43,7 +93,14 @@ impl CodecTraits for VlenV2Codec {
         _version: ZarrVersion,
         _options: &CodecMetadataOptions,
     ) -> Option<Configuration> {
-        Some(Configuration::default())
+        if let Some(item_dtype) = &self.item_dtype {
+            let configuration = VlenArrayCodecConfiguration::V1(VlenArrayCodecConfigurationV1 {
+                dtype: Some(item_dtype.clone()),
+            });
+            Some(configuration.into())
+        } else {
+            Some(Configuration::default())
+        }
     }
 
     fn partial_decoder_capability(&self) -> PartialDecoderCapability {
@@ -81,9 +138,15 @@ impl UnboundArrayToBytesCodecTraits for VlenV2Codec {
                 Self::aliases_v3().default_name.to_string(),
             ));
         }
+        let item_endianness = if let Some(item_dtype) = &self.item_dtype {
+            Self::validate_item_dtype(item_dtype, &data_type)?
+        } else {
+            None
+        };
         Ok(Arc::new(VlenV2CodecBound {
             data_type,
             fill_value,
+            item_endianness: item_endianness.unwrap_or(Endianness::Little),
         }))
     }
 }
@@ -130,6 +193,7 @@ impl ArrayToBytesCodecTraits for VlenV2CodecBound {
         let num_elements = shape.iter().map(|d| d.get()).product::<u64>();
         bytes.validate(num_elements, &self.data_type)?;
         let (bytes, offsets) = bytes.into_variable()?.into_parts();
+        let bytes = list_items_encode(&self.data_type, bytes, self.item_endianness)?;
 
         debug_assert_eq!(1 + num_elements, offsets.len() as u64);
 
@@ -160,6 +224,7 @@ impl ArrayToBytesCodecTraits for VlenV2CodecBound {
         let num_elements_usize = usize::try_from(num_elements).unwrap();
         let (bytes, offsets) =
             super::get_interleaved_bytes_and_offsets(num_elements_usize, &bytes)?;
+        let bytes = list_items_decode(&self.data_type, bytes.into(), self.item_endianness)?;
         let offsets = ArrayBytesOffsets::new(offsets)?;
         let array_bytes = ArrayBytes::new_vlen(bytes, offsets)?;
         Ok(array_bytes)
@@ -177,6 +242,7 @@ impl ArrayToBytesCodecTraits for VlenV2CodecBound {
                 shape.to_vec(),
                 self.data_type.clone(),
                 self.fill_value.clone(),
+                self.item_endianness,
             ),
         ))
     }
@@ -194,6 +260,7 @@ impl ArrayToBytesCodecTraits for VlenV2CodecBound {
                 shape.to_vec(),
                 self.data_type.clone(),
                 self.fill_value.clone(),
+                self.item_endianness,
             ),
         ))
     }
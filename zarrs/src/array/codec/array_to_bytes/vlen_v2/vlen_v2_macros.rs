@@ -1,5 +1,5 @@
 macro_rules! vlen_v2_module {
-    ($module:ident, $module_codec:ident, $struct:ident) => {
+    ($module:ident, $module_codec:ident, $struct:ident, $configuration:ty) => {
         mod $module_codec;
 
         use std::sync::Arc;
@@ -21,8 +21,8 @@ macro_rules! vlen_v2_module {
 
         impl CodecTraitsV3 for $struct {
             fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
-                if metadata.configuration().is_none_or(|c| c.is_empty()) {
-                    let codec = Arc::new($struct::new());
+                if let Ok(configuration) = metadata.to_typed_configuration::<$configuration>() {
+                    let codec = Arc::new($struct::new_with_configuration(&configuration)?);
                     Ok(Codec::ArrayToBytes(codec))
                 } else {
                     Err(metadata.to_string().into())
@@ -32,8 +32,8 @@ macro_rules! vlen_v2_module {
 
         impl CodecTraitsV2 for $struct {
             fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
-                if metadata.configuration().is_empty() {
-                    let codec = Arc::new($struct::new());
+                if let Ok(configuration) = metadata.to_typed_configuration::<$configuration>() {
+                    let codec = Arc::new($struct::new_with_configuration(&configuration)?);
                     Ok(Codec::ArrayToBytes(codec))
                 } else {
                     Err(format!("{metadata:?}").into())
@@ -4,7 +4,8 @@ use std::num::NonZeroU64;
 use std::sync::Arc;
 
 use crate::array::array_bytes_internal::extract_decoded_regions_vlen;
-use crate::array::{ArrayBytes, ArrayBytesRaw, DataType, FillValue};
+use crate::array::data_type::list_items_decode;
+use crate::array::{ArrayBytes, ArrayBytesRaw, DataType, Endianness, FillValue};
 use zarrs_codec::{
     ArrayPartialDecoderNoSubchunkingTraits, ArrayPartialDecoderTraits, BytesPartialDecoderTraits,
     CodecError, CodecOptions,
@@ -19,6 +20,7 @@ pub(crate) struct VlenV2PartialDecoder {
     shape: Vec<NonZeroU64>,
     data_type: DataType,
     fill_value: FillValue,
+    item_endianness: Endianness,
 }
 
 impl VlenV2PartialDecoder {
@@ -28,12 +30,14 @@ impl VlenV2PartialDecoder {
         shape: Vec<NonZeroU64>,
         data_type: DataType,
         fill_value: FillValue,
+        item_endianness: Endianness,
     ) -> Self {
         Self {
             input_handle,
             shape,
             data_type,
             fill_value,
+            item_endianness,
         }
     }
 }
@@ -44,11 +48,13 @@ fn decode_vlen_bytes<'a>(
     data_type: &DataType,
     fill_value: &FillValue,
     shape: &[NonZeroU64],
+    item_endianness: Endianness,
 ) -> Result<ArrayBytes<'a>, CodecError> {
     if let Some(bytes) = bytes {
         let num_elements =
             usize::try_from(shape.iter().copied().map(NonZeroU64::get).product::<u64>()).unwrap();
         let (bytes, offsets) = super::get_interleaved_bytes_and_offsets(num_elements, &bytes)?;
+        let bytes = list_items_decode(data_type, bytes.into(), item_endianness)?;
         Ok(ArrayBytes::Variable(extract_decoded_regions_vlen(
             &bytes, &offsets, indexer, shape,
         )?))
@@ -87,6 +93,7 @@ impl ArrayPartialDecoderTraits for VlenV2PartialDecoder {
             &self.data_type,
             &self.fill_value,
             &self.shape,
+            self.item_endianness,
         )
     }
 
@@ -102,6 +109,7 @@ pub(crate) struct AsyncVlenV2PartialDecoder {
     shape: Vec<NonZeroU64>,
     data_type: DataType,
     fill_value: FillValue,
+    item_endianness: Endianness,
 }
 
 #[cfg(feature = "async")]
@@ -112,12 +120,14 @@ impl AsyncVlenV2PartialDecoder {
         shape: Vec<NonZeroU64>,
         data_type: DataType,
         fill_value: FillValue,
+        item_endianness: Endianness,
     ) -> Self {
         Self {
             input_handle,
             shape,
             data_type,
             fill_value,
+            item_endianness,
         }
     }
 }
@@ -154,6 +164,7 @@ impl AsyncArrayPartialDecoderTraits for AsyncVlenV2PartialDecoder {
             &self.data_type,
             &self.fill_value,
             &self.shape,
+            self.item_endianness,
         )
     }
 
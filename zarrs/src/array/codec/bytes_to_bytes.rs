@@ -2,27 +2,45 @@
 
 #[cfg(feature = "adler32")]
 pub mod adler32;
+#[cfg(feature = "aead")]
+pub mod aead;
+#[cfg(feature = "bitshuffle")]
+pub mod bitshuffle;
 #[cfg(feature = "blosc")]
 pub mod blosc;
+#[cfg(feature = "brotli")]
+pub mod brotli;
 #[cfg(feature = "bz2")]
 pub mod bz2;
+#[cfg(feature = "crc32")]
+pub mod crc32;
 #[cfg(feature = "crc32c")]
 pub mod crc32c;
+#[cfg(feature = "digest")]
+pub mod digest;
 #[cfg(feature = "fletcher32")]
 pub mod fletcher32;
 #[cfg(feature = "gdeflate")]
 pub mod gdeflate;
 #[cfg(feature = "gzip")]
 pub mod gzip;
+#[cfg(feature = "jenkins_lookup3")]
+pub mod jenkins_lookup3;
 pub mod shuffle;
+#[cfg(feature = "snappy")]
+pub mod snappy;
 #[cfg(feature = "zlib")]
 pub mod zlib;
 #[cfg(feature = "zstd")]
 pub mod zstd;
+#[cfg(feature = "zstd")]
+pub mod zstd_dictionary;
 
 #[cfg(test)]
 pub mod test_unbounded;
 
+mod checksum_partial_decoder;
+
 mod strip_prefix_partial_decoder;
 
 mod strip_suffix_partial_decoder;
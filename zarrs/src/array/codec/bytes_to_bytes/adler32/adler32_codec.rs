@@ -4,12 +4,10 @@ use std::sync::Arc;
 use zarrs_plugin::{PluginCreateError, ZarrVersion};
 
 use super::{Adler32CodecConfiguration, Adler32CodecConfigurationV1, CHECKSUM_SIZE};
-use crate::array::codec::bytes_to_bytes::strip_prefix_partial_decoder::StripPrefixPartialDecoder;
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::StripSuffixPartialDecoder;
 #[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::{
-    strip_prefix_partial_decoder::AsyncStripPrefixPartialDecoder,
-    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
 };
 use crate::array::{ArrayBytesRaw, BytesRepresentation};
 #[cfg(feature = "async")]
@@ -51,6 +49,13 @@ impl Adler32Codec {
             )),
         }
     }
+
+    const fn checksum_position(&self) -> ChecksumPosition {
+        match self.location {
+            Adler32CodecConfigurationChecksumLocation::Start => ChecksumPosition::Start,
+            Adler32CodecConfigurationChecksumLocation::End => ChecksumPosition::End,
+        }
+    }
 }
 
 impl CodecTraits for Adler32Codec {
@@ -160,34 +165,36 @@ impl BytesToBytesCodecTraits for Adler32Codec {
     fn partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        match self.location {
-            Adler32CodecConfigurationChecksumLocation::Start => Ok(Arc::new(
-                StripPrefixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-            Adler32CodecConfigurationChecksumLocation::End => Ok(Arc::new(
-                StripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-        }
+        let position = self.checksum_position();
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
     }
 
     #[cfg(feature = "async")]
     async fn async_partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
-        match self.location {
-            Adler32CodecConfigurationChecksumLocation::Start => Ok(Arc::new(
-                AsyncStripPrefixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-            Adler32CodecConfigurationChecksumLocation::End => Ok(Arc::new(
-                AsyncStripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-        }
+        let position = self.checksum_position();
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
     }
 
     fn encoded_representation(
@@ -0,0 +1,321 @@
+//! The `aead` bytes to bytes codec (Experimental).
+//!
+//! Encrypts and authenticates each chunk with an authenticated encryption with associated data (AEAD) algorithm: AES-256-GCM or ChaCha20-Poly1305.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `aead` feature, which is disabled by default.
+//!
+//! ### Keys
+//! The 256-bit key is never serialised into array metadata.
+//! It must be supplied at runtime with [`AeadCodecOptions`] through [`CodecSpecificOptions`](zarrs_codec::CodecSpecificOptions), for example with [`ArrayBuilder::codec_specific_options`](crate::array::ArrayBuilder::codec_specific_options) or [`Array::with_codec_specific_options`](crate::array::Array::with_codec_specific_options).
+//! Encoding or decoding without a key is an error.
+//!
+//! ### Encoded Representation
+//! Each encoded chunk is the 12 byte nonce, followed by the ciphertext, followed by the 16 byte authentication tag.
+//! The nonce is generated according to the [`AeadNonceMode`]:
+//!  - `random`: a random nonce for each encoded chunk. Keys should be rotated well before 2<sup>32</sup> chunks have been encoded with the same key.
+//!  - `derived`: a nonce derived from a keyed BLAKE3 hash of the plaintext.
+//!    Encoding is deterministic, so anyone with read access to the store can tell which chunks have identical content, even without the key.
+//!
+//! Decoding fails with [`CodecError::AuthenticationFailed`](zarrs_codec::CodecError::AuthenticationFailed) if the key is incorrect or if the encoded chunk has been modified or truncated.
+//!
+//! ### Limitations
+//! <div class="warning">
+//! Chunks are encrypted without associated data, so an encoded chunk is not bound to its chunk position or array.
+//! </div>
+//!
+//! An attacker with write access to the store can move, swap, or replay encoded chunks between chunk keys, or between arrays encrypted with the same key, and decoding will succeed.
+//! Deleting a chunk is also undetectable, as the fill value is returned instead.
+//! Detecting these requires integrity protection outside of this codec, such as a signed manifest of chunk keys and encoded chunk digests.
+//! Using a distinct key per array limits substitution to chunks within the same array.
+//!
+//! ### Sharding
+//! [`AeadCodecOptions`] are forwarded to the inner and index codecs of the `sharding_indexed` codec.
+//!  - As an inner codec of `sharding_indexed`, each subchunk is encrypted independently and subchunks can still be partially read from a shard.
+//!    The shard index (subchunk offsets and sizes) is not encrypted unless this codec is also an index codec.
+//!  - After the `sharding_indexed` codec, entire shards are encrypted and must be read in full.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/aead>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.aead`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/aead`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`AeadCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "algorithm": "aes-256-gcm",
+//!     "nonce": "random"
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::aead::AeadCodecConfiguration;
+//! # serde_json::from_str::<AeadCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod aead_codec;
+mod aead_options;
+
+use std::sync::Arc;
+
+pub use aead_codec::AeadCodec;
+pub use aead_options::{AeadCodecOptions, AeadKey};
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::aead::{
+    AeadAlgorithm, AeadCodecConfiguration, AeadCodecConfigurationV0, AeadNonceMode,
+};
+
+zarrs_plugin::impl_extension_aliases!(AeadCodec, v3: "zarrs.aead");
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<AeadCodec>()
+}
+
+impl CodecTraitsV3 for AeadCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: AeadCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(AeadCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::codec::ShardingCodecBuilder;
+    use crate::array::{ArrayBuilder, BytesRepresentation, data_type};
+    use zarrs_codec::{
+        BytesToBytesCodecTraits, CodecMetadataOptions, CodecOptions, CodecSpecificOptions,
+    };
+    use zarrs_storage::store::MemoryStore;
+
+    const KEY: [u8; 32] = [7; 32];
+    const ALGORITHMS: [AeadAlgorithm; 2] =
+        [AeadAlgorithm::Aes256Gcm, AeadAlgorithm::ChaCha20Poly1305];
+    const NONCE_MODES: [AeadNonceMode; 2] = [AeadNonceMode::Random, AeadNonceMode::Derived];
+
+    #[test]
+    fn codec_aead_round_trip() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            for nonce_mode in NONCE_MODES {
+                let codec = AeadCodec::new(algorithm, nonce_mode).with_key(KEY);
+                let encoded = codec
+                    .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                    .unwrap();
+                assert_eq!(
+                    encoded.len() as u64,
+                    codec
+                        .encoded_representation(&bytes_representation)
+                        .size()
+                        .unwrap()
+                );
+                assert!(!encoded.windows(8).any(|w| w == &bytes[..8]));
+                let decoded = codec
+                    .decode(encoded, &bytes_representation, &CodecOptions::default())
+                    .unwrap();
+                assert_eq!(bytes, decoded.to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn codec_aead_nonce_modes() {
+        let bytes: Vec<u8> = (0..100).collect();
+        for algorithm in ALGORITHMS {
+            let codec = AeadCodec::new(algorithm, AeadNonceMode::Random).with_key(KEY);
+            let encoded0 = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            let encoded1 = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_ne!(encoded0, encoded1);
+
+            let codec = AeadCodec::new(algorithm, AeadNonceMode::Derived).with_key(KEY);
+            let encoded0 = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            let encoded1 = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_eq!(encoded0, encoded1);
+            let encoded2 = codec
+                .encode(Cow::Borrowed(&bytes[1..]), &CodecOptions::default())
+                .unwrap();
+            assert_ne!(encoded0[..NONCE_SIZE], encoded2[..NONCE_SIZE]);
+        }
+    }
+
+    #[test]
+    fn codec_aead_tampered_or_wrong_key() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            let codec = AeadCodec::new(algorithm, AeadNonceMode::Random).with_key(KEY);
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap()
+                .into_owned();
+
+            for i in [0, NONCE_SIZE, encoded.len() - 1] {
+                let mut tampered = encoded.clone();
+                tampered[i] ^= 0x01;
+                assert!(matches!(
+                    codec.decode(
+                        Cow::Owned(tampered),
+                        &bytes_representation,
+                        &CodecOptions::default()
+                    ),
+                    Err(zarrs_codec::CodecError::AuthenticationFailed)
+                ));
+            }
+            assert!(matches!(
+                codec.decode(
+                    Cow::Borrowed(&encoded[..NONCE_SIZE + TAG_SIZE - 1]),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                ),
+                Err(zarrs_codec::CodecError::AuthenticationFailed)
+            ));
+
+            let codec_wrong_key =
+                AeadCodec::new(algorithm, AeadNonceMode::Random).with_key([8; 32]);
+            assert!(matches!(
+                codec_wrong_key.decode(
+                    Cow::Borrowed(&encoded),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                ),
+                Err(zarrs_codec::CodecError::AuthenticationFailed)
+            ));
+        }
+    }
+
+    #[test]
+    fn codec_aead_no_key() {
+        let codec = AeadCodec::new(AeadAlgorithm::Aes256Gcm, AeadNonceMode::Random);
+        assert!(
+            codec
+                .encode(Cow::Borrowed(&[1, 2, 3]), &CodecOptions::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn codec_aead_key_not_serialised() {
+        let codec = Arc::new(AeadCodec::new(
+            AeadAlgorithm::ChaCha20Poly1305,
+            AeadNonceMode::Derived,
+        ))
+        .with_codec_specific_options(
+            &CodecSpecificOptions::default().with_option(AeadCodecOptions::new(KEY)),
+        )
+        .unwrap();
+        let configuration = codec
+            .configuration_v3(&CodecMetadataOptions::default())
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&configuration).unwrap(),
+            r#"{"algorithm":"chacha20-poly1305","nonce":"derived"}"#
+        );
+        assert!(
+            codec
+                .encode(Cow::Borrowed(&[1, 2, 3]), &CodecOptions::default())
+                .is_ok()
+        );
+    }
+
+    fn codec_aead_array_round_trip(builder: &mut ArrayBuilder) {
+        let store = Arc::new(MemoryStore::new());
+        let opts = CodecSpecificOptions::default().with_option(AeadCodecOptions::new(KEY));
+        let array = builder
+            .codec_specific_options(opts.clone())
+            .build(store.clone(), "/")
+            .unwrap();
+        array.store_metadata().unwrap();
+        let elements: Vec<u8> = (0..64).collect();
+        array
+            .store_array_subset(&[0..8, 0..8], elements.clone())
+            .unwrap();
+        let encoded = array.retrieve_encoded_chunk(&[0, 0]).unwrap().unwrap();
+        assert!(!encoded.windows(4).any(|w| w == [0, 1, 2, 3]));
+
+        // Reopen the array, the key is not stored with the array
+        let array = crate::array::Array::open(store, "/").unwrap();
+        assert!(
+            array
+                .retrieve_array_subset::<Vec<u8>>(&[0..8, 0..8])
+                .is_err()
+        );
+        let array = array.with_codec_specific_options(&opts).unwrap();
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u8>>(&[0..8, 0..8])
+                .unwrap(),
+            elements
+        );
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u8>>(&[1..3, 2..4])
+                .unwrap(),
+            vec![10, 11, 18, 19]
+        );
+    }
+
+    #[test]
+    fn codec_aead_sharding_subchunks() {
+        let mut builder = ArrayBuilder::new(vec![8, 8], [8, 8], data_type::uint8(), 0u8);
+        builder
+            .subchunk_shape(vec![4, 4])
+            .bytes_to_bytes_codecs(vec![Arc::new(AeadCodec::new(
+                AeadAlgorithm::Aes256Gcm,
+                AeadNonceMode::Random,
+            ))]);
+        codec_aead_array_round_trip(&mut builder);
+    }
+
+    #[test]
+    fn codec_aead_sharding_shards() {
+        let mut builder = ArrayBuilder::new(vec![8, 8], [8, 8], data_type::uint8(), 0u8);
+        builder
+            .array_to_bytes_codec(Arc::new(
+                ShardingCodecBuilder::new(
+                    vec![
+                        std::num::NonZeroU64::new(4).unwrap(),
+                        std::num::NonZeroU64::new(4).unwrap(),
+                    ],
+                    &data_type::uint8(),
+                )
+                .build(),
+            ))
+            .bytes_to_bytes_codecs(vec![Arc::new(AeadCodec::new(
+                AeadAlgorithm::ChaCha20Poly1305,
+                AeadNonceMode::Derived,
+            ))]);
+        codec_aead_array_round_trip(&mut builder);
+    }
+}
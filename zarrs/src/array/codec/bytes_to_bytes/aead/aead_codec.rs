@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::{Aes256Gcm, KeyInit};
+use chacha20poly1305::ChaCha20Poly1305;
+use zarrs_metadata_ext::codec::aead::{AeadAlgorithm, AeadNonceMode};
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use super::{
+    AeadCodecConfiguration, AeadCodecConfigurationV0, AeadCodecOptions, AeadKey, NONCE_SIZE,
+    TAG_SIZE,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+use zarrs_codec::{
+    BytesToBytesCodecTraits, CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions,
+    CodecSpecificOptions, CodecTraits, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+
+const NONCE_DERIVATION_CONTEXT: &str = "zarrs.aead 2026-10-18 nonce derivation";
+
+/// An `aead` codec implementation.
+#[derive(Clone, Debug)]
+pub struct AeadCodec {
+    algorithm: AeadAlgorithm,
+    nonce_mode: AeadNonceMode,
+    key: Option<Arc<AeadKey>>,
+}
+
+impl AeadCodec {
+    /// Create a new `aead` codec without a key.
+    ///
+    /// A key must be supplied with [`with_key`](AeadCodec::with_key) or through [`AeadCodecOptions`] before encoding or decoding.
+    #[must_use]
+    pub const fn new(algorithm: AeadAlgorithm, nonce_mode: AeadNonceMode) -> Self {
+        Self {
+            algorithm,
+            nonce_mode,
+            key: None,
+        }
+    }
+
+    /// Create a new `aead` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &AeadCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            AeadCodecConfiguration::V0(configuration) => {
+                Ok(Self::new(configuration.algorithm, configuration.nonce))
+            }
+            _ => Err(PluginCreateError::Other(
+                "this aead codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    /// Return a version of this codec with the provided encryption `key`.
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<AeadKey>) -> Self {
+        self.key = Some(Arc::new(key.into()));
+        self
+    }
+
+    fn key(&self) -> Result<&AeadKey, CodecError> {
+        self.key.as_deref().ok_or_else(|| {
+            CodecError::Other(
+                "the aead codec has no key, supply one with AeadCodecOptions".to_string(),
+            )
+        })
+    }
+
+    fn nonce(&self, key: &AeadKey, plaintext: &[u8]) -> Result<[u8; NONCE_SIZE], CodecError> {
+        let mut nonce = [0u8; NONCE_SIZE];
+        match self.nonce_mode {
+            AeadNonceMode::Random => getrandom::fill(&mut nonce)
+                .map_err(|err| CodecError::Other(format!("failed to generate nonce: {err}")))?,
+            AeadNonceMode::Derived => {
+                let nonce_key = blake3::derive_key(NONCE_DERIVATION_CONTEXT, key.as_bytes());
+                let hash = blake3::keyed_hash(&nonce_key, plaintext);
+                nonce.copy_from_slice(&hash.as_bytes()[..NONCE_SIZE]);
+            }
+            _ => {
+                return Err(CodecError::Other(format!(
+                    "the {} aead nonce mode is unsupported",
+                    self.nonce_mode
+                )));
+            }
+        }
+        Ok(nonce)
+    }
+}
+
+impl CodecTraits for AeadCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = AeadCodecConfiguration::V0(AeadCodecConfigurationV0 {
+            algorithm: self.algorithm,
+            nonce: self.nonce_mode,
+        });
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for AeadCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn with_codec_specific_options(
+        self: Arc<Self>,
+        opts: &CodecSpecificOptions,
+    ) -> Result<Arc<dyn BytesToBytesCodecTraits>, CodecCreateError> {
+        if let Some(aead_opts) = opts.get_option::<AeadCodecOptions>() {
+            let mut codec = self;
+            Arc::make_mut(&mut codec).key = Some(aead_opts.key().clone());
+            Ok(codec)
+        } else {
+            Ok(self.into_dyn())
+        }
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let key = self.key()?;
+        let nonce = self.nonce(key, &decoded_value)?;
+
+        let mut encoded_value = Vec::with_capacity(NONCE_SIZE + decoded_value.len() + TAG_SIZE);
+        encoded_value.extend_from_slice(&nonce);
+        encoded_value.extend_from_slice(&decoded_value);
+        let buffer = &mut encoded_value[NONCE_SIZE..];
+        // No associated data: the encoded chunk is not bound to its chunk key (see the module documentation)
+        let tag = match self.algorithm {
+            AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(key.as_bytes().into())
+                .encrypt_in_place_detached(&nonce.into(), &[], buffer)
+                .map(|tag| tag.to_vec()),
+            AeadAlgorithm::ChaCha20Poly1305 => ChaCha20Poly1305::new(key.as_bytes().into())
+                .encrypt_in_place_detached(&nonce.into(), &[], buffer)
+                .map(|tag| tag.to_vec()),
+            _ => {
+                return Err(CodecError::Other(format!(
+                    "the {} aead algorithm is unsupported",
+                    self.algorithm
+                )));
+            }
+        }
+        .map_err(|_| CodecError::Other("aead encryption failed".to_string()))?;
+        encoded_value.extend_from_slice(&tag);
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let key = self.key()?;
+        if encoded_value.len() < NONCE_SIZE + TAG_SIZE {
+            // A truncated encoded value cannot be authenticated
+            return Err(CodecError::AuthenticationFailed);
+        }
+
+        let mut decoded_value = encoded_value.into_owned();
+        let tag_start = decoded_value.len() - TAG_SIZE;
+        let (nonce_and_buffer, tag) = decoded_value.split_at_mut(tag_start);
+        let (nonce, buffer) = nonce_and_buffer.split_at_mut(NONCE_SIZE);
+        let nonce: [u8; NONCE_SIZE] = (&*nonce).try_into().unwrap();
+        let tag: [u8; TAG_SIZE] = (&*tag).try_into().unwrap();
+        match self.algorithm {
+            AeadAlgorithm::Aes256Gcm => Aes256Gcm::new(key.as_bytes().into())
+                .decrypt_in_place_detached(&nonce.into(), &[], buffer, &tag.into()),
+            AeadAlgorithm::ChaCha20Poly1305 => ChaCha20Poly1305::new(key.as_bytes().into())
+                .decrypt_in_place_detached(&nonce.into(), &[], buffer, &tag.into()),
+            _ => {
+                return Err(CodecError::Other(format!(
+                    "the {} aead algorithm is unsupported",
+                    self.algorithm
+                )));
+            }
+        }
+        .map_err(|_| CodecError::AuthenticationFailed)?;
+
+        decoded_value.truncate(tag_start);
+        decoded_value.drain(..NONCE_SIZE);
+        Ok(Cow::Owned(decoded_value))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        let overhead = (NONCE_SIZE + TAG_SIZE) as u64;
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => BytesRepresentation::FixedSize(size + overhead),
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + overhead)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Runtime options for the `aead` codec.
+
+use std::sync::Arc;
+
+use zeroize::Zeroize;
+
+/// A 256-bit `aead` codec encryption key.
+///
+/// The key is zeroed on drop and redacted from [`Debug`] output.
+#[derive(Clone)]
+pub struct AeadKey([u8; 32]);
+
+impl AeadKey {
+    /// Create a new key from its raw bytes.
+    #[must_use]
+    pub const fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Return the raw key bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for AeadKey {
+    fn from(key: [u8; 32]) -> Self {
+        Self::new(key)
+    }
+}
+
+impl Drop for AeadKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for AeadKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AeadKey(<redacted>)")
+    }
+}
+
+/// Runtime options for the [`AeadCodec`](super::AeadCodec).
+///
+/// The key is supplied at runtime through [`CodecSpecificOptions`](zarrs_codec::CodecSpecificOptions) and is never serialised into array metadata.
+/// These options are also forwarded to the inner and index codecs of the `sharding_indexed` codec.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct AeadCodecOptions {
+    key: Arc<AeadKey>,
+}
+
+impl AeadCodecOptions {
+    /// Create new `aead` codec options with an encryption `key`.
+    #[must_use]
+    pub fn new(key: impl Into<AeadKey>) -> Self {
+        Self {
+            key: Arc::new(key.into()),
+        }
+    }
+
+    /// Return the encryption key.
+    #[must_use]
+    pub fn key(&self) -> &Arc<AeadKey> {
+        &self.key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aead_key_debug_redacted() {
+        let options = AeadCodecOptions::new([0x42; 32]);
+        assert!(!format!("{options:?}").contains("66"));
+        assert_eq!(options.key().as_bytes(), &[0x42; 32]);
+    }
+}
@@ -0,0 +1,592 @@
+//! The `bitshuffle` bytes to bytes codec (Experimental).
+//!
+//! Applies the [bitshuffle](https://github.com/kiyo-masui/bitshuffle) bit transposition, optionally followed by LZ4 compression of each block.
+//!
+//! Bit shuffling groups together the bits at the same position of each element.
+//! This increases the compressibility of data with slowly varying or sparse values, particularly when combined with a fast compressor such as `zstd` or LZ4.
+//! Unlike `blosc` with `"shuffle": "bitshuffle"`, this codec can be freely combined with any other bytes to bytes codec (including within the inner codecs of `sharding_indexed`).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `bitshuffle` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/bitshuffle>
+//!
+//! The byte length of the input to this codec must be an integer multiple of the `elementsize`.
+//! The `elementsize` defaults to the size of the data type decoded by the array-to-bytes codec (e.g. 4 for `float32` with the `bytes` codec), which requires a fixed-size data type.
+//!
+//! The input is split into blocks of `blocksize` elements, and the bits of each block are transposed independently.
+//! If the number of elements in the final block is not a multiple of 8, the trailing elements are stored without transposition.
+//! If `blocksize` is zero, it is chosen automatically such that a block is approximately 8 KiB.
+//!
+//! With the `"lz4"` compressor, the encoded data matches the HDF5 bitshuffle filter with LZ4 compression:
+//!  - `DECODED_LENGTH`: a big-endian 64-bit unsigned integer holding the decoded length in bytes,
+//!  - `BLOCK_LENGTH`: a big-endian 32-bit unsigned integer holding the length of each block in bytes,
+//!  - for each bit-shuffled block, a big-endian 32-bit unsigned integer holding the compressed block length followed by the LZ4 compressed block, and
+//!  - the trailing elements that were not bit-shuffled.
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.bitshuffle`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/bitshuffle`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`BitshuffleCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "elementsize": 4,
+//!     "blocksize": 0,
+//!     "cname": "lz4"
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::bitshuffle::BitshuffleCodecConfiguration;
+//! # serde_json::from_str::<BitshuffleCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod bitshuffle_codec;
+
+use std::sync::Arc;
+
+pub use bitshuffle_codec::BitshuffleCodec;
+use zarrs_metadata::v3::MetadataV3;
+pub use zarrs_metadata_ext::codec::bitshuffle::{
+    BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV0, BitshuffleCompressor,
+};
+
+use crate::array::BytesRepresentation;
+use zarrs_codec::{Codec, CodecError, CodecPluginV3, CodecTraitsV3, InvalidBytesLengthError};
+
+zarrs_plugin::impl_extension_aliases!(BitshuffleCodec, v3: "zarrs.bitshuffle");
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<BitshuffleCodec>()
+}
+
+impl CodecTraitsV3 for BitshuffleCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: BitshuffleCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+const BITSHUFFLE_TARGET_BLOCK_SIZE_BYTES: usize = 8192;
+const BITSHUFFLE_MIN_BLOCK_SIZE: usize = 128;
+const BITSHUFFLE_LZ4_HEADER_LENGTH: usize = size_of::<u64>() + size_of::<u32>();
+
+/// The default number of elements in a block, matching the bitshuffle library.
+fn bitshuffle_default_blocksize(elementsize: usize) -> usize {
+    let blocksize = (BITSHUFFLE_TARGET_BLOCK_SIZE_BYTES / elementsize) / 8 * 8;
+    blocksize.max(BITSHUFFLE_MIN_BLOCK_SIZE)
+}
+
+/// Returns the byte lengths of each bit-shuffled block, followed by the length of the trailing bytes that are not bit-shuffled.
+fn bitshuffle_block_lengths(
+    len: usize,
+    elementsize: usize,
+    blocksize: usize,
+) -> (impl Iterator<Item = usize>, usize) {
+    let num_elements = len / elementsize;
+    let num_elements_trailing = num_elements % 8;
+    let num_elements_blocked = num_elements - num_elements_trailing;
+    let block_lengths = (0..num_elements_blocked)
+        .step_by(blocksize)
+        .map(move |start| (num_elements_blocked - start).min(blocksize) * elementsize);
+    (block_lengths, num_elements_trailing * elementsize)
+}
+
+/// Transpose the bits of a block with a multiple of 8 elements.
+///
+/// The encoded block has `8 * elementsize` rows, where each row holds one bit of every element.
+fn bitshuffle_block(src: &[u8], dst: &mut [u8], elementsize: usize) {
+    let row_length = src.len() / elementsize / 8;
+    for group in 0..row_length {
+        for byte_index in 0..elementsize {
+            let mut bytes = [0u8; 8];
+            for (element, byte) in bytes.iter_mut().enumerate() {
+                *byte = src[(group * 8 + element) * elementsize + byte_index];
+            }
+            for bit in 0..8 {
+                let mut row_byte = 0u8;
+                for (element, byte) in bytes.iter().enumerate() {
+                    row_byte |= ((byte >> bit) & 1) << element;
+                }
+                dst[(byte_index * 8 + bit) * row_length + group] = row_byte;
+            }
+        }
+    }
+}
+
+/// The inverse of [`bitshuffle_block`].
+fn bitunshuffle_block(src: &[u8], dst: &mut [u8], elementsize: usize) {
+    let row_length = src.len() / elementsize / 8;
+    for group in 0..row_length {
+        for byte_index in 0..elementsize {
+            let mut bytes = [0u8; 8];
+            for bit in 0..8 {
+                let row_byte = src[(byte_index * 8 + bit) * row_length + group];
+                for (element, byte) in bytes.iter_mut().enumerate() {
+                    *byte |= ((row_byte >> element) & 1) << bit;
+                }
+            }
+            for (element, byte) in bytes.into_iter().enumerate() {
+                dst[(group * 8 + element) * elementsize + byte_index] = byte;
+            }
+        }
+    }
+}
+
+fn bitshuffle(src: &[u8], elementsize: usize, blocksize: usize) -> Vec<u8> {
+    let mut dst = vec![0; src.len()];
+    let (block_lengths, _) = bitshuffle_block_lengths(src.len(), elementsize, blocksize);
+    let mut offset = 0;
+    for block_length in block_lengths {
+        let block = offset..offset + block_length;
+        bitshuffle_block(&src[block.clone()], &mut dst[block], elementsize);
+        offset += block_length;
+    }
+    dst[offset..].copy_from_slice(&src[offset..]);
+    dst
+}
+
+fn bitunshuffle(src: &[u8], elementsize: usize, blocksize: usize) -> Vec<u8> {
+    let mut dst = vec![0; src.len()];
+    let (block_lengths, _) = bitshuffle_block_lengths(src.len(), elementsize, blocksize);
+    let mut offset = 0;
+    for block_length in block_lengths {
+        let block = offset..offset + block_length;
+        bitunshuffle_block(&src[block.clone()], &mut dst[block], elementsize);
+        offset += block_length;
+    }
+    dst[offset..].copy_from_slice(&src[offset..]);
+    dst
+}
+
+fn bitshuffle_lz4_compress(
+    src: &[u8],
+    elementsize: usize,
+    blocksize: usize,
+) -> Result<Vec<u8>, CodecError> {
+    let block_length_bytes = u32::try_from(blocksize * elementsize)
+        .map_err(|_| CodecError::Other("the bitshuffle block size is too large".to_string()))?;
+    let mut dst = Vec::with_capacity(BITSHUFFLE_LZ4_HEADER_LENGTH + src.len());
+    dst.extend_from_slice(&(src.len() as u64).to_be_bytes());
+    dst.extend_from_slice(&block_length_bytes.to_be_bytes());
+
+    let (block_lengths, _) = bitshuffle_block_lengths(src.len(), elementsize, blocksize);
+    let mut shuffled = vec![0; blocksize * elementsize];
+    let mut offset = 0;
+    for block_length in block_lengths {
+        let shuffled = &mut shuffled[..block_length];
+        bitshuffle_block(&src[offset..offset + block_length], shuffled, elementsize);
+        let compressed = lz4_flex::block::compress(shuffled);
+        let compressed_length = u32::try_from(compressed.len()).map_err(|_| {
+            CodecError::Other("a compressed bitshuffle block is too large".to_string())
+        })?;
+        dst.extend_from_slice(&compressed_length.to_be_bytes());
+        dst.extend_from_slice(&compressed);
+        offset += block_length;
+    }
+    dst.extend_from_slice(&src[offset..]);
+    Ok(dst)
+}
+
+fn bitshuffle_lz4_decompress(
+    src: &[u8],
+    elementsize: usize,
+    decoded_representation: &BytesRepresentation,
+) -> Result<Vec<u8>, CodecError> {
+    let invalid = || CodecError::Other("the bitshuffle encoded value is invalid".to_string());
+    if src.len() < BITSHUFFLE_LZ4_HEADER_LENGTH {
+        return Err(InvalidBytesLengthError::new(src.len(), BITSHUFFLE_LZ4_HEADER_LENGTH).into());
+    }
+    let (header, mut src) = src.split_at(BITSHUFFLE_LZ4_HEADER_LENGTH);
+    let decoded_length = u64::from_be_bytes(header[..8].try_into().expect("8 bytes"));
+    let decoded_length_valid = match decoded_representation {
+        BytesRepresentation::FixedSize(size) => decoded_length == *size,
+        BytesRepresentation::BoundedSize(size) => decoded_length <= *size,
+        BytesRepresentation::UnboundedSize => true,
+    };
+    if !decoded_length_valid {
+        return Err(CodecError::Other(format!(
+            "the bitshuffle decoded length {decoded_length} is incompatible with the decoded representation {decoded_representation}"
+        )));
+    }
+    let decoded_length = usize::try_from(decoded_length).map_err(|_| invalid())?;
+    let block_length_bytes = u32::from_be_bytes(header[8..].try_into().expect("4 bytes")) as usize;
+    if block_length_bytes == 0 || !block_length_bytes.is_multiple_of(8 * elementsize) {
+        return Err(invalid());
+    }
+
+    let mut dst = vec![0; decoded_length];
+    let (block_lengths, trailing_length) = bitshuffle_block_lengths(
+        decoded_length,
+        elementsize,
+        block_length_bytes / elementsize,
+    );
+    let mut offset = 0;
+    for block_length in block_lengths {
+        if src.len() < size_of::<u32>() {
+            return Err(invalid());
+        }
+        let (compressed_length, rest) = src.split_at(size_of::<u32>());
+        let compressed_length =
+            u32::from_be_bytes(compressed_length.try_into().expect("4 bytes")) as usize;
+        if rest.len() < compressed_length {
+            return Err(invalid());
+        }
+        let (compressed, rest) = rest.split_at(compressed_length);
+        let shuffled = lz4_flex::block::decompress(compressed, block_length)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        if shuffled.len() != block_length {
+            return Err(invalid());
+        }
+        bitunshuffle_block(
+            &shuffled,
+            &mut dst[offset..offset + block_length],
+            elementsize,
+        );
+        offset += block_length;
+        src = rest;
+    }
+    if src.len() != trailing_length || dst.len() - offset < trailing_length {
+        return Err(invalid());
+    }
+    dst[offset..offset + trailing_length].copy_from_slice(src);
+    offset += trailing_length;
+    dst.truncate(offset);
+    Ok(dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::num::NonZeroU64;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::codec::ShardingCodecBuilder;
+    use crate::array::{ArrayBytes, BytesRepresentation, FillValue, data_type};
+    use zarrs_codec::{
+        BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecOptions,
+        UnboundArrayToBytesCodecTraits,
+    };
+    use zarrs_storage::byte_range::ByteRange;
+
+    const JSON_VALID: &str = r#"{
+        "elementsize": 2,
+        "blocksize": 16,
+        "cname": "none"
+    }"#;
+
+    const JSON_VALID_LZ4: &str = r#"{
+        "elementsize": 2,
+        "cname": "lz4"
+    }"#;
+
+    // 45 u16 elements `i * 1000` with a block size of 16 elements, encoded with the bit transposition of the bitshuffle library (as vendored in c-blosc) and liblz4.
+    // The LZ4 encoding follows `bshuf_compress_lz4` with the header of the bitshuffle HDF5 filter.
+    const REFERENCE_SHUFFLED: [u8; 90] = [
+        0, 0, 0, 0, 0, 0, 170, 170, 204, 204, 90, 90, 198, 57, 62, 248, 254, 7, 254, 255, 84, 85,
+        152, 153, 224, 225, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 170, 204, 204, 90, 90, 198,
+        57, 193, 7, 192, 255, 63, 0, 85, 85, 153, 153, 225, 225, 1, 254, 254, 255, 0, 0, 0, 0, 0,
+        170, 204, 90, 198, 62, 1, 0, 85, 153, 225, 1, 1, 254, 64, 156, 40, 160, 16, 164, 248, 167,
+        224, 171,
+    ];
+    const REFERENCE_LZ4: [u8; 116] = [
+        0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 32, 0, 0, 0, 32, 17, 0, 1, 0, 240, 11, 170, 170, 204,
+        204, 90, 90, 198, 57, 62, 248, 254, 7, 254, 255, 84, 85, 152, 153, 224, 225, 0, 254, 0, 0,
+        0, 0, 0, 0, 0, 32, 17, 0, 1, 0, 240, 11, 170, 170, 204, 204, 90, 90, 198, 57, 193, 7, 192,
+        255, 63, 0, 85, 85, 153, 153, 225, 225, 1, 254, 254, 255, 0, 0, 0, 0, 0, 18, 240, 1, 0, 0,
+        0, 170, 204, 90, 198, 62, 1, 0, 85, 153, 225, 1, 1, 254, 64, 156, 40, 160, 16, 164, 248,
+        167, 224, 171,
+    ];
+
+    fn reference_decoded() -> Vec<u8> {
+        (0..45u16).flat_map(|i| (i * 1000).to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn codec_bitshuffle_reference() {
+        let decoded = reference_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(decoded.len() as u64);
+        let options = CodecOptions::default();
+
+        let codec = BitshuffleCodec::new(Some(2), Some(16), BitshuffleCompressor::None).unwrap();
+        let encoded = codec.encode(Cow::Borrowed(&decoded), &options).unwrap();
+        assert_eq!(encoded, REFERENCE_SHUFFLED.as_slice());
+        let decoded_reference = codec
+            .decode(
+                Cow::Borrowed(&REFERENCE_SHUFFLED),
+                &bytes_representation,
+                &options,
+            )
+            .unwrap();
+        assert_eq!(decoded_reference, decoded);
+
+        let codec = BitshuffleCodec::new(Some(2), Some(16), BitshuffleCompressor::LZ4).unwrap();
+        let decoded_reference = codec
+            .decode(
+                Cow::Borrowed(&REFERENCE_LZ4),
+                &bytes_representation,
+                &options,
+            )
+            .unwrap();
+        assert_eq!(decoded_reference, decoded);
+        let encoded = codec.encode(Cow::Borrowed(&decoded), &options).unwrap();
+        assert_eq!(
+            encoded[..BITSHUFFLE_LZ4_HEADER_LENGTH],
+            REFERENCE_LZ4[..BITSHUFFLE_LZ4_HEADER_LENGTH]
+        );
+    }
+
+    #[test]
+    fn codec_bitshuffle_lz4_decoded_length_mismatch() {
+        let codec = BitshuffleCodec::new(Some(2), Some(16), BitshuffleCompressor::LZ4).unwrap();
+        let mut encoded = REFERENCE_LZ4;
+        encoded[..8].copy_from_slice(&u64::MAX.to_be_bytes());
+        for bytes_representation in [
+            BytesRepresentation::FixedSize(90),
+            BytesRepresentation::BoundedSize(90),
+        ] {
+            assert!(
+                codec
+                    .decode(
+                        Cow::Borrowed(&encoded),
+                        &bytes_representation,
+                        &CodecOptions::default()
+                    )
+                    .is_err()
+            );
+        }
+        assert!(
+            codec
+                .decode(
+                    Cow::Borrowed(&REFERENCE_LZ4),
+                    &BytesRepresentation::FixedSize(88),
+                    &CodecOptions::default()
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn codec_bitshuffle_default_elementsize() {
+        let configuration: BitshuffleCodecConfiguration =
+            serde_json::from_str(r#"{"blocksize": 16, "cname": "lz4"}"#).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration).unwrap());
+        assert!(
+            codec
+                .encode(Cow::Owned(vec![0; 8]), &CodecOptions::default())
+                .is_err()
+        );
+        assert!(codec.clone().with_data_type(&data_type::string()).is_err());
+
+        let bound = codec.with_data_type(&data_type::uint16()).unwrap();
+        let decoded = reference_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(decoded.len() as u64);
+        let decoded_reference = bound
+            .decode(
+                Cow::Borrowed(&REFERENCE_LZ4),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(decoded_reference, decoded);
+    }
+
+    #[test]
+    fn codec_bitshuffle_block() {
+        // 8 u16 elements, only bit 0 of byte 0 set in elements 0 and 2
+        let src = [1u8, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let mut dst = [0u8; 16];
+        bitshuffle_block(&src, &mut dst, 2);
+        assert_eq!(
+            dst,
+            [0b0000_0101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        let mut decoded = [0u8; 16];
+        bitunshuffle_block(&dst, &mut decoded, 2);
+        assert_eq!(src, decoded);
+    }
+
+    #[test]
+    fn codec_bitshuffle_invalid_blocksize() {
+        assert!(BitshuffleCodec::new(Some(2), Some(12), BitshuffleCompressor::None).is_err());
+        assert!(BitshuffleCodec::new(Some(0), None, BitshuffleCompressor::None).is_err());
+    }
+
+    #[test]
+    fn codec_bitshuffle_data_type() {
+        assert!(
+            BitshuffleCodec::new_with_data_type(&data_type::uint32(), BitshuffleCompressor::LZ4)
+                .is_ok()
+        );
+        assert!(
+            BitshuffleCodec::new_with_data_type(&data_type::string(), BitshuffleCompressor::LZ4)
+                .is_err()
+        );
+    }
+
+    fn codec_bitshuffle_round_trip_impl(json: &str, num_elements: u16) {
+        let elements: Vec<u16> = (0..num_elements).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(json).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(
+            (encoded.len() as u64)
+                <= codec
+                    .encoded_representation(&bytes_representation)
+                    .size()
+                    .unwrap()
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_bitshuffle_round_trip() {
+        for num_elements in [0, 5, 8, 32, 45] {
+            codec_bitshuffle_round_trip_impl(JSON_VALID, num_elements);
+        }
+    }
+
+    #[test]
+    fn codec_bitshuffle_lz4_round_trip() {
+        for num_elements in [0, 5, 8, 32, 45, 10000] {
+            codec_bitshuffle_round_trip_impl(JSON_VALID_LZ4, num_elements);
+        }
+    }
+
+    #[test]
+    fn codec_bitshuffle_lz4_compresses() {
+        let elements: Vec<u32> = (0..4096).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let codec =
+            BitshuffleCodec::new_with_data_type(&data_type::uint32(), BitshuffleCompressor::LZ4)
+                .unwrap();
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(encoded.len() < bytes.len() / 4);
+    }
+
+    #[test]
+    fn codec_bitshuffle_invalid_length() {
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration).unwrap();
+        assert!(
+            codec
+                .encode(Cow::Owned(vec![0; 3]), &CodecOptions::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn codec_bitshuffle_lz4_decode_invalid() {
+        let configuration: BitshuffleCodecConfiguration =
+            serde_json::from_str(JSON_VALID_LZ4).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration).unwrap();
+        let bytes_representation = BytesRepresentation::FixedSize(64);
+        let encoded = codec
+            .encode(Cow::Owned(vec![1; 64]), &CodecOptions::default())
+            .unwrap();
+        for len in [4, BITSHUFFLE_LZ4_HEADER_LENGTH + 2, encoded.len() - 1] {
+            assert!(
+                codec
+                    .decode(
+                        Cow::Borrowed(&encoded[..len]),
+                        &bytes_representation,
+                        &CodecOptions::default()
+                    )
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn codec_bitshuffle_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BitshuffleCodecConfiguration =
+            serde_json::from_str(JSON_VALID_LZ4).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(encoded);
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(partial_decoder.size_held(), input_handle.size_held()); // bitshuffle partial decoder does not hold bytes
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_many(
+                Box::new(decoded_regions.into_iter()),
+                &CodecOptions::default(),
+            )
+            .unwrap()
+            .unwrap()
+            .concat();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|b| u16::from_ne_bytes(*b))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+
+    #[test]
+    fn codec_bitshuffle_sharding_inner_codec() {
+        let shape = vec![NonZeroU64::new(8).unwrap(); 2];
+        let data_type = data_type::uint16();
+        let elements: Vec<u16> = (0..64).collect();
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes_vec(elements).into();
+
+        // The elementsize is derived from the data type when the inner codec chain is bound
+        let bitshuffle =
+            Arc::new(BitshuffleCodec::new(None, None, BitshuffleCompressor::LZ4).unwrap());
+        let codec = ShardingCodecBuilder::new(vec![NonZeroU64::new(4).unwrap(); 2], &data_type)
+            .bytes_to_bytes_codecs(vec![bitshuffle])
+            .build()
+            .with_context(data_type, FillValue::from(0u16))
+            .unwrap();
+
+        let options = CodecOptions::default();
+        let encoded = codec.encode(bytes.clone(), &shape, &options).unwrap();
+        let decoded = codec.decode(encoded, &shape, &options).unwrap();
+        assert_eq!(bytes, decoded);
+    }
+}
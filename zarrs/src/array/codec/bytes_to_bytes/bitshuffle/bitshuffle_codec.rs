@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use super::{
+    BITSHUFFLE_LZ4_HEADER_LENGTH, BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV0,
+    BitshuffleCompressor, bitshuffle, bitshuffle_default_blocksize, bitshuffle_lz4_compress,
+    bitshuffle_lz4_decompress, bitunshuffle,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation, DataType};
+use zarrs_codec::{
+    BytesToBytesCodecTraits, CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions,
+    CodecTraits, PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+
+/// A `bitshuffle` codec implementation.
+#[derive(Clone, Debug)]
+pub struct BitshuffleCodec {
+    elementsize: Option<usize>,
+    blocksize: usize,
+    cname: BitshuffleCompressor,
+}
+
+impl BitshuffleCodec {
+    /// Create a new `bitshuffle` codec.
+    ///
+    /// The element size is the size of the data type of the codec chain if `elementsize` is none.
+    /// The block size is chosen automatically if `blocksize` is none or zero.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if
+    ///  - `elementsize` is zero, or
+    ///  - `blocksize` is not a multiple of 8.
+    pub fn new(
+        elementsize: Option<usize>,
+        blocksize: Option<usize>,
+        cname: BitshuffleCompressor,
+    ) -> Result<Self, PluginCreateError> {
+        if elementsize == Some(0) {
+            return Err(PluginCreateError::from(
+                "bitshuffle elementsize must be a positive integer",
+            ));
+        }
+        let blocksize = blocksize.unwrap_or_default();
+        if !blocksize.is_multiple_of(8) {
+            return Err(PluginCreateError::from(
+                "bitshuffle blocksize must be a multiple of 8",
+            ));
+        }
+        Ok(Self {
+            elementsize,
+            blocksize,
+            cname,
+        })
+    }
+
+    /// Create a new `bitshuffle` codec with the element size of a fixed-size data type.
+    ///
+    /// The block size is chosen automatically.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `data_type` is not fixed-size.
+    pub fn new_with_data_type(
+        data_type: &DataType,
+        cname: BitshuffleCompressor,
+    ) -> Result<Self, PluginCreateError> {
+        Self::new(Some(data_type_elementsize(data_type)?), None, cname)
+    }
+
+    /// Create a new `bitshuffle` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &BitshuffleCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            BitshuffleCodecConfiguration::V0(configuration) => Self::new(
+                configuration.elementsize,
+                Some(configuration.blocksize),
+                configuration.cname,
+            ),
+            _ => Err(PluginCreateError::Other(
+                "this bitshuffle codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    fn elementsize(&self) -> Result<usize, CodecError> {
+        self.elementsize.ok_or_else(|| {
+            CodecError::Other(
+                "the bitshuffle codec elementsize is unspecified and has not been derived from a data type".to_string(),
+            )
+        })
+    }
+
+    /// The number of elements in each block.
+    fn blocksize(&self, elementsize: usize) -> usize {
+        if self.blocksize == 0 {
+            bitshuffle_default_blocksize(elementsize)
+        } else {
+            self.blocksize
+        }
+    }
+}
+
+fn data_type_elementsize(data_type: &DataType) -> Result<usize, PluginCreateError> {
+    data_type.fixed_size().ok_or_else(|| {
+        PluginCreateError::from("the bitshuffle codec requires a fixed-size data type")
+    })
+}
+
+fn validate_length(len: usize, elementsize: usize) -> Result<(), CodecError> {
+    if len.is_multiple_of(elementsize) {
+        Ok(())
+    } else {
+        Err(CodecError::Other("the bitshuffle codec expects the input byte length to be an integer multiple of the elementsize".to_string()))
+    }
+}
+
+impl CodecTraits for BitshuffleCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = BitshuffleCodecConfiguration::V0(BitshuffleCodecConfigurationV0 {
+            elementsize: self.elementsize,
+            blocksize: self.blocksize,
+            cname: self.cname,
+        });
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for BitshuffleCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn with_data_type(
+        self: Arc<Self>,
+        data_type: &DataType,
+    ) -> Result<Arc<dyn BytesToBytesCodecTraits>, CodecCreateError> {
+        if self.elementsize.is_some() {
+            Ok(self)
+        } else {
+            Ok(Arc::new(Self {
+                elementsize: Some(data_type_elementsize(data_type)?),
+                ..(*self).clone()
+            }))
+        }
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let elementsize = self.elementsize()?;
+        validate_length(decoded_value.len(), elementsize)?;
+        let blocksize = self.blocksize(elementsize);
+        let encoded_value = match self.cname {
+            BitshuffleCompressor::None => bitshuffle(&decoded_value, elementsize, blocksize),
+            BitshuffleCompressor::LZ4 => {
+                bitshuffle_lz4_compress(&decoded_value, elementsize, blocksize)?
+            }
+        };
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let elementsize = self.elementsize()?;
+        let decoded_value = match self.cname {
+            BitshuffleCompressor::None => {
+                validate_length(encoded_value.len(), elementsize)?;
+                bitunshuffle(&encoded_value, elementsize, self.blocksize(elementsize))
+            }
+            BitshuffleCompressor::LZ4 => {
+                let decoded_value =
+                    bitshuffle_lz4_decompress(&encoded_value, elementsize, decoded_representation)?;
+                validate_length(decoded_value.len(), elementsize)?;
+                decoded_value
+            }
+        };
+        Ok(Cow::Owned(decoded_value))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match self.cname {
+            BitshuffleCompressor::None => *decoded_representation,
+            BitshuffleCompressor::LZ4 => {
+                decoded_representation.size().zip(self.elementsize).map_or(
+                    BytesRepresentation::UnboundedSize,
+                    |(size, elementsize)| {
+                        let blocksize_bytes = (self.blocksize(elementsize) * elementsize) as u64;
+                        let num_blocks = size.div_ceil(blocksize_bytes);
+                        // Each block has a 4 byte size prefix and the LZ4 worst case expansion.
+                        let block_overhead = 4 + 16 + 4;
+                        BytesRepresentation::BoundedSize(
+                            BITSHUFFLE_LZ4_HEADER_LENGTH as u64
+                                + size
+                                + size.div_ceil(10)
+                                + num_blocks * block_overhead,
+                        )
+                    },
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! The `brotli` bytes to bytes codec (Experimental).
+//!
+//! Applies [Brotli](https://datatracker.ietf.org/doc/html/rfc7932) compression.
+//!
+//! Brotli typically achieves higher compression ratios than `gzip` or `zstd` on text-heavy data (e.g. `string` arrays encoded with `vlen-utf8`), at the cost of slower encoding at high compression levels.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `brotli` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/brotli>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.brotli`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/brotli`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`BrotliCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "level": 11,
+//!     "lgwin": 22
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::brotli::BrotliCodecConfiguration;
+//! # serde_json::from_str::<BrotliCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod brotli_codec;
+
+use std::sync::Arc;
+
+pub use brotli_codec::BrotliCodec;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::brotli::{
+    BrotliCodecConfiguration, BrotliCodecConfigurationV0, BrotliCompressionLevel,
+    BrotliCompressionLevelError, BrotliWindowLog, BrotliWindowLogError,
+};
+
+zarrs_plugin::impl_extension_aliases!(BrotliCodec, v3: "zarrs.brotli");
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<BrotliCodec>()
+}
+
+impl CodecTraitsV3 for BrotliCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: BrotliCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(BrotliCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::BytesRepresentation;
+    use zarrs_codec::{BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecOptions};
+    use zarrs_storage::byte_range::ByteRange;
+
+    const JSON_VALID: &str = r#"{
+        "level": 5,
+        "lgwin": 22
+    }"#;
+
+    #[test]
+    fn codec_brotli_configuration_valid() {
+        assert!(serde_json::from_str::<BrotliCodecConfiguration>(JSON_VALID).is_ok());
+    }
+
+    #[test]
+    fn codec_brotli_configuration_invalid() {
+        const JSON_INVALID: &str = r#"{
+        "level": -1,
+        "lgwin": 22
+    }"#;
+        assert!(serde_json::from_str::<BrotliCodecConfiguration>(JSON_INVALID).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_brotli_round_trip1() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BrotliCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = BrotliCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(
+            (encoded.len() as u64)
+                <= codec
+                    .encoded_representation(&bytes_representation)
+                    .size()
+                    .unwrap()
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_brotli_round_trip_text() {
+        let text = "the quick brown fox jumps over the lazy dog ".repeat(64);
+        let bytes = text.as_bytes().to_vec();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec = BrotliCodec::new(
+            BrotliCompressionLevel::try_from(11u8).unwrap(),
+            BrotliWindowLog::default(),
+        );
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(encoded.len() < bytes.len());
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_brotli_encoded_representation_incompressible() {
+        // xorshift pseudo-random bytes are incompressible
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes: Vec<u8> = (0..100_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state.to_le_bytes()[0]
+            })
+            .collect();
+        for len in [0, 1, 100, 10_000, 100_000] {
+            let bytes = &bytes[..len];
+            let bytes_representation = BytesRepresentation::FixedSize(len as u64);
+            for level in 0..=11u8 {
+                let codec = BrotliCodec::new(
+                    BrotliCompressionLevel::try_from(level).unwrap(),
+                    BrotliWindowLog::default(),
+                );
+                let encoded = codec
+                    .encode(Cow::Borrowed(bytes), &CodecOptions::default())
+                    .unwrap();
+                assert!(
+                    (encoded.len() as u64)
+                        <= codec
+                            .encoded_representation(&bytes_representation)
+                            .size()
+                            .unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_brotli_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: BrotliCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = Arc::new(BrotliCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(encoded);
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(partial_decoder.size_held(), input_handle.size_held()); // brotli partial decoder does not hold bytes
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_many(
+                Box::new(decoded_regions.into_iter()),
+                &CodecOptions::default(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .concat()
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|b| u16::from_ne_bytes(*b))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use super::strip_prefix_partial_decoder::StripPrefixPartialDecoder;
+use super::strip_suffix_partial_decoder::StripSuffixPartialDecoder;
+#[cfg(feature = "async")]
+use super::{
+    strip_prefix_partial_decoder::AsyncStripPrefixPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
+use crate::array::BytesRepresentation;
+#[cfg(feature = "async")]
+use zarrs_codec::AsyncBytesPartialDecoderTraits;
+use zarrs_codec::{BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecPartialDefault};
+
+/// The position of a checksum in the encoded bytes.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ChecksumPosition {
+    /// The checksum precedes the decoded bytes.
+    Start,
+    /// The checksum follows the decoded bytes.
+    End,
+}
+
+/// Create a partial decoder for a codec that adds a checksum of `checksum_size` bytes covering the entire input.
+///
+/// If `validate` is true, the checksum can only be validated against the entire input, so it is decoded in full with the `codec`.
+/// Otherwise, the checksum is stripped and only the requested byte ranges are read.
+pub(crate) fn checksum_partial_decoder(
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    decoded_representation: &BytesRepresentation,
+    validate: bool,
+    position: ChecksumPosition,
+    checksum_size: usize,
+) -> Arc<dyn BytesPartialDecoderTraits> {
+    if validate {
+        Arc::new(CodecPartialDefault::new_bytes(
+            input_handle,
+            *decoded_representation,
+            codec,
+        ))
+    } else {
+        match position {
+            ChecksumPosition::Start => {
+                Arc::new(StripPrefixPartialDecoder::new(input_handle, checksum_size))
+            }
+            ChecksumPosition::End => {
+                Arc::new(StripSuffixPartialDecoder::new(input_handle, checksum_size))
+            }
+        }
+    }
+}
+
+/// Create an asynchronous partial decoder for a codec that adds a checksum of `checksum_size` bytes covering the entire input.
+///
+/// See [`checksum_partial_decoder`].
+#[cfg(feature = "async")]
+pub(crate) fn async_checksum_partial_decoder(
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    decoded_representation: &BytesRepresentation,
+    validate: bool,
+    position: ChecksumPosition,
+    checksum_size: usize,
+) -> Arc<dyn AsyncBytesPartialDecoderTraits> {
+    if validate {
+        Arc::new(CodecPartialDefault::new_bytes(
+            input_handle,
+            *decoded_representation,
+            codec,
+        ))
+    } else {
+        match position {
+            ChecksumPosition::Start => Arc::new(AsyncStripPrefixPartialDecoder::new(
+                input_handle,
+                checksum_size,
+            )),
+            ChecksumPosition::End => Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle,
+                checksum_size,
+            )),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! The `crc32` bytes to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Adds a CRC-32 checksum (as computed by `zlib.crc32`) of the input bytestream.
+//! The checksum is stored at the start of the encoded bytes unless `location` is `end`.
+//!
+//! This is distinct from the [`crc32c`](super::crc32c) codec, which uses the Castagnoli polynomial.
+//!
+//! This codec requires the `crc32` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! This codec is fully compatible with the `numcodecs.crc32` codec in `zarr-python`.
+//!
+//! ### Specification
+//! - <https://github.com/zarr-developers/zarr-extensions/tree/numcodecs/codecs/numcodecs.crc32>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.crc32`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `crc32`
+//!
+//! ### Codec `configuration` Example - [`Crc32CodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {}
+//! # "#;
+//! # use zarrs::metadata_ext::codec::crc32::Crc32CodecConfiguration;
+//! # serde_json::from_str::<Crc32CodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod crc32_codec;
+
+use std::sync::Arc;
+
+pub use crc32_codec::Crc32Codec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::crc32::{
+    Crc32CodecConfiguration, Crc32CodecConfigurationChecksumLocation, Crc32CodecConfigurationV1,
+};
+
+zarrs_plugin::impl_extension_aliases!(Crc32Codec,
+    v3: "numcodecs.crc32", [],
+    v2: "crc32"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<Crc32Codec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<Crc32Codec>()
+}
+
+impl CodecTraitsV3 for Crc32Codec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(Crc32Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for Crc32Codec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: Crc32CodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(Crc32Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+const CHECKSUM_SIZE: usize = size_of::<u32>();
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::BytesRepresentation;
+    use zarrs_codec::{
+        BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecMetadataOptions, CodecOptions,
+        CodecTraits,
+    };
+    use zarrs_storage::byte_range::ByteRange;
+
+    const JSON1: &str = r"{}";
+    const JSON2: &str = r#"{"location":"start"}"#;
+    const JSON3: &str = r#"{"location":"end"}"#;
+
+    #[test]
+    fn codec_crc32_configuration_none() {
+        let codec_configuration: Crc32CodecConfiguration = serde_json::from_str(r"{}").unwrap();
+        let codec = Crc32Codec::new_with_configuration(&codec_configuration).unwrap();
+        let configuration = codec
+            .configuration_v3(&CodecMetadataOptions::default())
+            .unwrap();
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), r"{}");
+    }
+
+    #[test]
+    fn codec_crc32() {
+        let elements: Vec<u8> = (0..6).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for json in [JSON1, JSON2, JSON3] {
+            let codec_configuration: Crc32CodecConfiguration = serde_json::from_str(json).unwrap();
+            let codec = Crc32Codec::new_with_configuration(&codec_configuration).unwrap();
+
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            let decoded = codec
+                .decode(
+                    encoded.clone(),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+
+            // Check that the checksum is correct
+            let checksum: &[u8; 4] = if json.contains("end") {
+                &encoded[encoded.len() - size_of::<u32>()..]
+                    .try_into()
+                    .unwrap()
+            } else {
+                &encoded[..size_of::<u32>()].try_into().unwrap()
+            };
+            assert_eq!(checksum, &[74, 207, 235, 48]);
+        }
+    }
+
+    #[test]
+    fn codec_crc32_partial_decode() {
+        let elements: Vec<u8> = (0..32).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for json in [JSON1, JSON2, JSON3] {
+            let codec_configuration: Crc32CodecConfiguration = serde_json::from_str(json).unwrap();
+            let codec = Arc::new(Crc32Codec::new_with_configuration(&codec_configuration).unwrap());
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes.clone()), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2))];
+            let input_handle = Arc::new(encoded);
+            let partial_decoder = codec
+                .partial_decoder(
+                    input_handle.clone(),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            assert_eq!(partial_decoder.size_held(), input_handle.size_held()); // crc32 partial decoder does not hold bytes
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode_many(
+                    Box::new(decoded_regions.into_iter()),
+                    &CodecOptions::default(),
+                )
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn codec_crc32_check_value() {
+        let codec = Crc32Codec::new(Crc32CodecConfigurationChecksumLocation::End);
+        let encoded = codec
+            .encode(Cow::Borrowed(b"123456789"), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(&encoded[9..], &0xCBF4_3926u32.to_le_bytes());
+    }
+
+    #[test]
+    fn codec_crc32_partial_decode_validate_checksums() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for json in [JSON2, JSON3] {
+            let codec_configuration: Crc32CodecConfiguration = serde_json::from_str(json).unwrap();
+            let codec = Arc::new(Crc32Codec::new_with_configuration(&codec_configuration).unwrap());
+
+            let mut encoded = codec
+                .encode(Cow::Owned(bytes.clone()), &CodecOptions::default())
+                .unwrap()
+                .into_owned();
+            // Corrupt the checksum
+            if json.contains("end") {
+                *encoded.last_mut().unwrap() ^= 0xff;
+            } else {
+                encoded[0] ^= 0xff;
+            }
+            let input_handle = Arc::new(encoded);
+
+            let options = CodecOptions::default().with_validate_checksums(true);
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(input_handle.clone(), &bytes_representation, &options)
+                .unwrap();
+            assert!(
+                partial_decoder
+                    .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                    .is_err()
+            );
+
+            let options = CodecOptions::default().with_validate_checksums(false);
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(input_handle, &bytes_representation, &options)
+                .unwrap();
+            let decoded = partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.to_vec(), vec![3, 4]);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_crc32_async_partial_decode() {
+        let elements: Vec<u8> = (0..32).collect();
+        let bytes = elements;
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for json in [JSON1, JSON2, JSON3] {
+            let codec_configuration: Crc32CodecConfiguration = serde_json::from_str(json).unwrap();
+            let codec = Arc::new(Crc32Codec::new_with_configuration(&codec_configuration).unwrap());
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes.clone()), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2))];
+            let input_handle = Arc::new(encoded);
+            let partial_decoder = codec
+                .async_partial_decoder(
+                    input_handle,
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .await
+                .unwrap();
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode_many(
+                    Box::new(decoded_regions.into_iter()),
+                    &CodecOptions::default(),
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
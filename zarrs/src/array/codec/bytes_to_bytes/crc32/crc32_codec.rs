@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use super::{CHECKSUM_SIZE, Crc32CodecConfiguration, Crc32CodecConfigurationV1};
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+#[cfg(feature = "async")]
+use zarrs_codec::AsyncBytesPartialDecoderTraits;
+use zarrs_codec::{
+    BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecError, CodecMetadataOptions,
+    CodecOptions, CodecTraits, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+use zarrs_metadata_ext::codec::crc32::Crc32CodecConfigurationChecksumLocation;
+
+/// A `crc32` codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct Crc32Codec {
+    location: Crc32CodecConfigurationChecksumLocation,
+}
+
+impl Crc32Codec {
+    /// Create a new `crc32` codec.
+    #[must_use]
+    pub const fn new(location: Crc32CodecConfigurationChecksumLocation) -> Self {
+        Self { location }
+    }
+
+    /// Create a new `crc32` codec.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &Crc32CodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            Crc32CodecConfiguration::V1(configuration) => Ok(Self {
+                location: configuration.location,
+            }),
+            _ => Err(PluginCreateError::Other(
+                "this crc32 codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    const fn checksum_position(&self) -> ChecksumPosition {
+        match self.location {
+            Crc32CodecConfigurationChecksumLocation::Start => ChecksumPosition::Start,
+            Crc32CodecConfigurationChecksumLocation::End => ChecksumPosition::End,
+        }
+    }
+}
+
+impl CodecTraits for Crc32Codec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = Crc32CodecConfiguration::V1(Crc32CodecConfigurationV1 {
+            location: self.location,
+        });
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for Crc32Codec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let checksum = crc32fast::hash(&decoded_value).to_le_bytes();
+
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        match self.location {
+            Crc32CodecConfigurationChecksumLocation::Start => {
+                encoded_value.extend_from_slice(&checksum);
+                encoded_value.extend_from_slice(&decoded_value);
+            }
+            Crc32CodecConfigurationChecksumLocation::End => {
+                encoded_value.extend_from_slice(&decoded_value);
+                encoded_value.extend_from_slice(&checksum);
+            }
+        }
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        if encoded_value.len() >= CHECKSUM_SIZE {
+            let (decoded_value, checksum) = match self.location {
+                Crc32CodecConfigurationChecksumLocation::Start => {
+                    let (checksum, decoded_value) = encoded_value.split_at(CHECKSUM_SIZE);
+                    let checksum: [u8; CHECKSUM_SIZE] = checksum.try_into().unwrap();
+                    (Cow::Owned(decoded_value.to_vec()), checksum)
+                }
+                Crc32CodecConfigurationChecksumLocation::End => {
+                    let mut owned = encoded_value.into_owned();
+                    let checksum_start = owned.len() - CHECKSUM_SIZE;
+                    let checksum: [u8; CHECKSUM_SIZE] = owned[checksum_start..].try_into().unwrap();
+                    owned.truncate(checksum_start);
+                    (Cow::Owned(owned), checksum)
+                }
+            };
+
+            if options.validate_checksums()
+                && crc32fast::hash(&decoded_value).to_le_bytes() != checksum
+            {
+                return Err(CodecError::InvalidChecksum);
+            }
+
+            Ok(decoded_value)
+        } else {
+            Err(CodecError::Other(
+                "crc32 decoder expects a 32 bit input".to_string(),
+            ))
+        }
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        let position = self.checksum_position();
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        let position = self.checksum_position();
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
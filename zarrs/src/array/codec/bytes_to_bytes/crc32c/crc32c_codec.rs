@@ -6,11 +6,10 @@ use zarrs_plugin::ZarrVersion;
 
 use super::{CHECKSUM_SIZE, Crc32cCodecConfiguration, Crc32cCodecConfigurationV1};
 #[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::strip_prefix_partial_decoder::AsyncStripPrefixPartialDecoder;
-use crate::array::codec::bytes_to_bytes::strip_prefix_partial_decoder::StripPrefixPartialDecoder;
-#[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder;
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::StripSuffixPartialDecoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
+};
 use crate::array::{ArrayBytesRaw, BytesRepresentation};
 #[cfg(feature = "async")]
 use zarrs_codec::AsyncBytesPartialDecoderTraits;
@@ -42,6 +41,13 @@ impl Crc32cCodec {
         };
         Self(location)
     }
+
+    const fn checksum_position(&self) -> ChecksumPosition {
+        match self.0 {
+            Crc32cCodecConfigurationLocation::Start => ChecksumPosition::Start,
+            Crc32cCodecConfigurationLocation::End => ChecksumPosition::End,
+        }
+    }
 }
 
 impl CodecTraits for Crc32cCodec {
@@ -143,35 +149,36 @@ impl BytesToBytesCodecTraits for Crc32cCodec {
     fn partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        match self.0 {
-            Crc32cCodecConfigurationLocation::End => Ok(Arc::new(StripSuffixPartialDecoder::new(
-                input_handle,
-                CHECKSUM_SIZE,
-            ))),
-            Crc32cCodecConfigurationLocation::Start => Ok(Arc::new(
-                StripPrefixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-        }
+        let position = self.checksum_position();
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
     }
 
     #[cfg(feature = "async")]
     async fn async_partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
-        match self.0 {
-            Crc32cCodecConfigurationLocation::End => Ok(Arc::new(
-                AsyncStripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-            Crc32cCodecConfigurationLocation::Start => Ok(Arc::new(
-                AsyncStripPrefixPartialDecoder::new(input_handle, CHECKSUM_SIZE),
-            )),
-        }
+        let position = self.checksum_position();
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            position,
+            CHECKSUM_SIZE,
+        ))
     }
 
     fn encoded_representation(
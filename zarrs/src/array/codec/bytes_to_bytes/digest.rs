@@ -0,0 +1,355 @@
+//! The `digest` bytes to bytes codec (Experimental).
+//!
+//! Appends a cryptographic digest (SHA-256 or BLAKE3) of the input bytestream, and verifies it on decode.
+//!
+//! An unkeyed digest detects accidental corruption, like the `crc32c` checksum codec, but it is not tamper evident: anyone able to modify a chunk can also recompute its digest.
+//! A keyed digest (HMAC-SHA256 or keyed BLAKE3) can only be computed with the key, so it detects deliberate modification of a chunk by anyone without the key.
+//! A digest mismatch is reported as [`CodecError::InvalidDigest`](zarrs_codec::CodecError::InvalidDigest).
+//! Verification of an unkeyed digest is skipped if [`CodecOptions::validate_checksums`](zarrs_codec::CodecOptions::validate_checksums) is disabled.
+//! A keyed digest is always verified.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `digest` feature, which is disabled by default.
+//!
+//! ### Keys
+//! If `keyed` is `true`, the 256-bit key is never serialised into array metadata.
+//! It must be supplied at runtime with [`DigestCodecOptions`] through [`CodecSpecificOptions`](zarrs_codec::CodecSpecificOptions), for example with [`ArrayBuilder::codec_specific_options`](crate::array::ArrayBuilder::codec_specific_options) or [`Array::with_codec_specific_options`](crate::array::Array::with_codec_specific_options).
+//! Encoding or decoding without a key is an error.
+//!
+//! A keyed digest does not bind a chunk to its chunk position, so encoded chunks can still be moved, swapped, or replayed between chunk keys of arrays sharing a key.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/digest>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.digest`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/digest`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`DigestCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "algorithm": "sha256",
+//!     "keyed": true
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::digest::DigestCodecConfiguration;
+//! # serde_json::from_str::<DigestCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod digest_codec;
+mod digest_options;
+
+use std::sync::Arc;
+
+pub use digest_codec::DigestCodec;
+pub use digest_options::{DigestCodecOptions, DigestKey};
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::digest::{
+    DigestAlgorithm, DigestCodecConfiguration, DigestCodecConfigurationV0,
+};
+
+zarrs_plugin::impl_extension_aliases!(DigestCodec, v3: "zarrs.digest");
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<DigestCodec>()
+}
+
+impl CodecTraitsV3 for DigestCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: DigestCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(DigestCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::BytesRepresentation;
+    use zarrs_codec::{
+        BytesToBytesCodecTraits, CodecError, CodecMetadataOptions, CodecOptions,
+        CodecSpecificOptions, CodecTraits,
+    };
+    use zarrs_storage::byte_range::ByteRange;
+
+    const ALGORITHMS: [DigestAlgorithm; 2] = [DigestAlgorithm::Sha256, DigestAlgorithm::Blake3];
+
+    #[test]
+    fn codec_digest_known_values() {
+        let codec = DigestCodec::new(DigestAlgorithm::Sha256);
+        let encoded = codec
+            .encode(Cow::Borrowed(b"abc"), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            &encoded[3..],
+            &[
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad
+            ]
+        );
+
+        let codec = DigestCodec::new(DigestAlgorithm::Blake3);
+        let encoded = codec
+            .encode(Cow::Borrowed(b""), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            &encoded[..],
+            &[
+                0xaf, 0x13, 0x49, 0xb9, 0xf5, 0xf9, 0xa1, 0xa6, 0xa0, 0x40, 0x4d, 0xea, 0x36, 0xdc,
+                0xc9, 0x49, 0x9b, 0xcb, 0x25, 0xc9, 0xad, 0xc1, 0x12, 0xb7, 0xcc, 0x9a, 0x93, 0xca,
+                0xe4, 0x1f, 0x32, 0x62
+            ]
+        );
+    }
+
+    #[test]
+    fn codec_digest_round_trip() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            let codec = DigestCodec::new(algorithm);
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_eq!(
+                encoded.len() as u64,
+                codec
+                    .encoded_representation(&bytes_representation)
+                    .size()
+                    .unwrap()
+            );
+            let decoded = codec
+                .decode(encoded, &bytes_representation, &CodecOptions::default())
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+        }
+    }
+
+    #[test]
+    fn codec_digest_tampered() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            let codec = DigestCodec::new(algorithm);
+            let mut encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap()
+                .into_owned();
+            encoded[10] ^= 0x01;
+            assert!(matches!(
+                codec.decode(
+                    Cow::Borrowed(&encoded),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                ),
+                Err(CodecError::InvalidDigest)
+            ));
+            let decoded = codec
+                .decode(
+                    Cow::Borrowed(&encoded),
+                    &bytes_representation,
+                    &CodecOptions::default().with_validate_checksums(false),
+                )
+                .unwrap();
+            assert_eq!(decoded[10], 11);
+        }
+    }
+
+    #[test]
+    fn codec_digest_keyed() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            let codec = Arc::new(DigestCodec::new_keyed(algorithm))
+                .with_codec_specific_options(
+                    &CodecSpecificOptions::default().with_option(DigestCodecOptions::new([1; 32])),
+                )
+                .unwrap();
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap()
+                .into_owned();
+            let decoded = codec
+                .decode(
+                    Cow::Borrowed(&encoded),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+
+            // The unkeyed digest differs, so it cannot be recomputed without the key
+            let unkeyed = DigestCodec::new(algorithm)
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_ne!(encoded[100..], unkeyed[100..]);
+
+            let codec_wrong_key = DigestCodec::new_keyed(algorithm).with_key([2; 32]);
+            assert!(matches!(
+                codec_wrong_key.decode(
+                    Cow::Borrowed(&encoded),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                ),
+                Err(CodecError::InvalidDigest)
+            ));
+
+            // Keyed digests are verified even if checksum validation is disabled
+            let mut tampered = encoded.clone();
+            tampered[50] ^= 0x01;
+            assert!(matches!(
+                codec.decode(
+                    Cow::Borrowed(&tampered),
+                    &bytes_representation,
+                    &CodecOptions::default().with_validate_checksums(false)
+                ),
+                Err(CodecError::InvalidDigest)
+            ));
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(
+                    Arc::new(tampered),
+                    &bytes_representation,
+                    &CodecOptions::default().with_validate_checksums(false),
+                )
+                .unwrap();
+            assert!(matches!(
+                partial_decoder.partial_decode(
+                    ByteRange::FromStart(3, Some(2)),
+                    &CodecOptions::default().with_validate_checksums(false)
+                ),
+                Err(CodecError::InvalidDigest)
+            ));
+
+            let codec_no_key = DigestCodec::new_keyed(algorithm);
+            assert!(
+                codec_no_key
+                    .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                    .is_err()
+            );
+            assert!(
+                codec_no_key
+                    .decode(
+                        Cow::Borrowed(&encoded),
+                        &bytes_representation,
+                        &CodecOptions::default()
+                    )
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn codec_digest_key_not_serialised() {
+        let codec = DigestCodec::new_keyed(DigestAlgorithm::Sha256).with_key([0x42; 32]);
+        let configuration = codec
+            .configuration_v3(&CodecMetadataOptions::default())
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&configuration).unwrap(),
+            r#"{"algorithm":"sha256","keyed":true}"#
+        );
+        assert!(!format!("{codec:?}").contains("66"));
+    }
+
+    #[test]
+    fn codec_digest_partial_decode() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for algorithm in ALGORITHMS {
+            let codec = Arc::new(DigestCodec::new(algorithm));
+            let mut encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap()
+                .into_owned();
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(
+                    Arc::new(encoded.clone()),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            let decoded = partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &CodecOptions::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.to_vec(), vec![3, 4]);
+
+            encoded[50] ^= 0x01;
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(
+                    Arc::new(encoded.clone()),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            assert!(matches!(
+                partial_decoder
+                    .partial_decode(ByteRange::FromStart(3, Some(2)), &CodecOptions::default()),
+                Err(CodecError::InvalidDigest)
+            ));
+
+            let options = CodecOptions::default().with_validate_checksums(false);
+            let partial_decoder = codec
+                .partial_decoder(Arc::new(encoded), &bytes_representation, &options)
+                .unwrap();
+            let decoded = partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.to_vec(), vec![3, 4]);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_digest_async_partial_decode() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec = Arc::new(DigestCodec::new(DigestAlgorithm::Blake3));
+        let mut encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        encoded[50] ^= 0x01;
+        let partial_decoder = codec
+            .async_partial_decoder(
+                Arc::new(encoded),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &CodecOptions::default())
+                .await,
+            Err(CodecError::InvalidDigest)
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_metadata_ext::codec::digest::DigestAlgorithm;
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use super::{DigestCodecConfiguration, DigestCodecConfigurationV0, DigestCodecOptions, DigestKey};
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+#[cfg(feature = "async")]
+use zarrs_codec::AsyncBytesPartialDecoderTraits;
+use zarrs_codec::{
+    BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecCreateError, CodecError,
+    CodecMetadataOptions, CodecOptions, CodecSpecificOptions, CodecTraits,
+    PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+
+/// A `digest` codec implementation.
+#[derive(Clone, Debug)]
+pub struct DigestCodec {
+    algorithm: DigestAlgorithm,
+    keyed: bool,
+    key: Option<Arc<DigestKey>>,
+}
+
+impl DigestCodec {
+    /// Create a new unkeyed `digest` codec.
+    #[must_use]
+    pub const fn new(algorithm: DigestAlgorithm) -> Self {
+        Self {
+            algorithm,
+            keyed: false,
+            key: None,
+        }
+    }
+
+    /// Create a new keyed `digest` codec without a key.
+    ///
+    /// A key must be supplied with [`with_key`](DigestCodec::with_key) or through [`DigestCodecOptions`] before encoding or verifying.
+    #[must_use]
+    pub const fn new_keyed(algorithm: DigestAlgorithm) -> Self {
+        Self {
+            algorithm,
+            keyed: true,
+            key: None,
+        }
+    }
+
+    /// Create a new `digest` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &DigestCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            DigestCodecConfiguration::V0(configuration) => {
+                if configuration.keyed {
+                    Ok(Self::new_keyed(configuration.algorithm))
+                } else {
+                    Ok(Self::new(configuration.algorithm))
+                }
+            }
+            _ => Err(PluginCreateError::Other(
+                "this digest codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    /// Return a version of this codec with the provided `key`.
+    ///
+    /// The key is ignored if the codec is not keyed.
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<DigestKey>) -> Self {
+        self.key = Some(Arc::new(key.into()));
+        self
+    }
+
+    fn key(&self) -> Result<Option<&DigestKey>, CodecError> {
+        if self.keyed {
+            self.key.as_deref().map(Some).ok_or_else(|| {
+                CodecError::Other(
+                    "the keyed digest codec has no key, supply one with DigestCodecOptions"
+                        .to_string(),
+                )
+            })
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn digest(&self, data: &[u8]) -> Result<Vec<u8>, CodecError> {
+        match (self.algorithm, self.key()?) {
+            (DigestAlgorithm::Sha256, None) => {
+                use sha2::Digest;
+                Ok(sha2::Sha256::digest(data).to_vec())
+            }
+            (DigestAlgorithm::Sha256, Some(key)) => {
+                use hmac::Mac;
+                let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(key.as_bytes())
+                    .map_err(|err| CodecError::Other(err.to_string()))?;
+                mac.update(data);
+                Ok(mac.finalize().into_bytes().to_vec())
+            }
+            (DigestAlgorithm::Blake3, None) => Ok(blake3::hash(data).as_bytes().to_vec()),
+            (DigestAlgorithm::Blake3, Some(key)) => {
+                Ok(blake3::keyed_hash(key.as_bytes(), data).as_bytes().to_vec())
+            }
+            _ => Err(CodecError::Other(format!(
+                "the {} digest algorithm is unsupported",
+                self.algorithm
+            ))),
+        }
+    }
+
+    /// Return true if the digest is verified on decode.
+    ///
+    /// Keyed digests are always verified, since skipping verification would silently accept tampered chunks.
+    fn validate(&self, options: &CodecOptions) -> bool {
+        self.keyed || options.validate_checksums()
+    }
+
+    fn verify(&self, data: &[u8], digest_stored: &[u8]) -> Result<bool, CodecError> {
+        let digest = self.digest(data)?;
+        // Compare in constant time so that keyed digests cannot be forged byte by byte
+        Ok(digest.len() == digest_stored.len()
+            && digest
+                .iter()
+                .zip(digest_stored)
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0)
+    }
+}
+
+impl CodecTraits for DigestCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = DigestCodecConfiguration::V0(DigestCodecConfigurationV0 {
+            algorithm: self.algorithm,
+            keyed: self.keyed,
+        });
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for DigestCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn with_codec_specific_options(
+        self: Arc<Self>,
+        opts: &CodecSpecificOptions,
+    ) -> Result<Arc<dyn BytesToBytesCodecTraits>, CodecCreateError> {
+        if let Some(digest_opts) = opts.get_option::<DigestCodecOptions>() {
+            let mut codec = self;
+            Arc::make_mut(&mut codec).key = Some(digest_opts.key().clone());
+            Ok(codec)
+        } else {
+            Ok(self.into_dyn())
+        }
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let digest = self.digest(&decoded_value)?;
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + digest.len());
+        encoded_value.extend_from_slice(&decoded_value);
+        encoded_value.extend_from_slice(&digest);
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let digest_size = self.algorithm.digest_size();
+        if encoded_value.len() < digest_size {
+            return Err(CodecError::Other(format!(
+                "digest decoder expects an input of at least {digest_size} bytes"
+            )));
+        }
+
+        let decoded_len = encoded_value.len() - digest_size;
+        if self.validate(options) {
+            let (decoded_value, digest_stored) = encoded_value.split_at(decoded_len);
+            if !self.verify(decoded_value, digest_stored)? {
+                return Err(CodecError::InvalidDigest);
+            }
+        }
+
+        let mut decoded_value = encoded_value.into_owned();
+        decoded_value.truncate(decoded_len);
+        Ok(Cow::Owned(decoded_value))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        let digest_size = self.algorithm.digest_size();
+        let validate = self.validate(options);
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            validate,
+            ChecksumPosition::End,
+            digest_size,
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        let digest_size = self.algorithm.digest_size();
+        let validate = self.validate(options);
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            validate,
+            ChecksumPosition::End,
+            digest_size,
+        ))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        let digest_size = self.algorithm.digest_size() as u64;
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + digest_size)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + digest_size)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Runtime options for the `digest` codec.
+
+use std::sync::Arc;
+
+use zeroize::Zeroize;
+
+/// A 256-bit `digest` codec key for keyed digests.
+///
+/// The key is zeroed on drop and redacted from [`Debug`] output.
+#[derive(Clone)]
+pub struct DigestKey([u8; 32]);
+
+impl DigestKey {
+    /// Create a new key from its raw bytes.
+    #[must_use]
+    pub const fn new(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Return the raw key bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for DigestKey {
+    fn from(key: [u8; 32]) -> Self {
+        Self::new(key)
+    }
+}
+
+impl Drop for DigestKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl std::fmt::Debug for DigestKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DigestKey(<redacted>)")
+    }
+}
+
+/// Runtime options for the [`DigestCodec`](super::DigestCodec).
+///
+/// The key is only used if the codec is `keyed`, and is never serialised into array metadata.
+/// These options are also forwarded to the inner and index codecs of the `sharding_indexed` codec.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DigestCodecOptions {
+    key: Arc<DigestKey>,
+}
+
+impl DigestCodecOptions {
+    /// Create new `digest` codec options with a `key`.
+    #[must_use]
+    pub fn new(key: impl Into<DigestKey>) -> Self {
+        Self {
+            key: Arc::new(key.into()),
+        }
+    }
+
+    /// Return the key.
+    #[must_use]
+    pub fn key(&self) -> &Arc<DigestKey> {
+        &self.key
+    }
+}
@@ -6,8 +6,10 @@ use zarrs_plugin::ZarrVersion;
 
 use super::{CHECKSUM_SIZE, Fletcher32CodecConfiguration, Fletcher32CodecConfigurationV1};
 #[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder;
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::StripSuffixPartialDecoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
+};
 use crate::array::{ArrayBytesRaw, BytesRepresentation};
 #[cfg(feature = "async")]
 use zarrs_codec::AsyncBytesPartialDecoderTraits;
@@ -158,26 +160,34 @@ impl BytesToBytesCodecTraits for Fletcher32Codec {
     fn partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(StripSuffixPartialDecoder::new(
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
             input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            ChecksumPosition::End,
             CHECKSUM_SIZE,
-        )))
+        ))
     }
 
     #[cfg(feature = "async")]
     async fn async_partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(AsyncStripSuffixPartialDecoder::new(
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
             input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            ChecksumPosition::End,
             CHECKSUM_SIZE,
-        )))
+        ))
     }
 
     fn encoded_representation(
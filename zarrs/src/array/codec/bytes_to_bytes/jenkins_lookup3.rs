@@ -0,0 +1,253 @@
+//! The `jenkins_lookup3` bytes to bytes codec (Experimental).
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! Appends a Bob Jenkins lookup3 checksum of the input bytestream.
+//! This is the checksum used by the HDF5 library for chunk and metadata checksums, so this codec is useful for virtualised HDF5/netCDF-4 datasets.
+//!
+//! This codec requires the `jenkins_lookup3` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! This codec is fully compatible with the `numcodecs.jenkins_lookup3` codec in `zarr-python`.
+//!
+//! ### Specification
+//! - <https://github.com/zarr-developers/zarr-extensions/tree/numcodecs/codecs/numcodecs.jenkins_lookup3>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `numcodecs.jenkins_lookup3`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! - `jenkins_lookup3`
+//!
+//! ### Codec `configuration` Example - [`JenkinsLookup3CodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "initval": 0,
+//!     "prefix": null
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::jenkins_lookup3::JenkinsLookup3CodecConfiguration;
+//! # serde_json::from_str::<JenkinsLookup3CodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod jenkins_lookup3_codec;
+
+use std::sync::Arc;
+
+pub use jenkins_lookup3_codec::JenkinsLookup3Codec;
+use zarrs_metadata::v2::MetadataV2;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV2, CodecPluginV3, CodecTraitsV2, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::jenkins_lookup3::{
+    JenkinsLookup3CodecConfiguration, JenkinsLookup3CodecConfigurationV1,
+};
+
+zarrs_plugin::impl_extension_aliases!(JenkinsLookup3Codec,
+    v3: "numcodecs.jenkins_lookup3", [],
+    v2: "jenkins_lookup3"
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<JenkinsLookup3Codec>()
+}
+// Register the V2 codec.
+inventory::submit! {
+    CodecPluginV2::new::<JenkinsLookup3Codec>()
+}
+
+impl CodecTraitsV3 for JenkinsLookup3Codec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(JenkinsLookup3Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+impl CodecTraitsV2 for JenkinsLookup3Codec {
+    fn create(metadata: &MetadataV2) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        let configuration: JenkinsLookup3CodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(JenkinsLookup3Codec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+const CHECKSUM_SIZE: usize = size_of::<u32>();
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::jenkins_lookup3_codec::h5_checksum_lookup3;
+    use super::*;
+    use crate::array::BytesRepresentation;
+    use zarrs_codec::{
+        BytesToBytesCodecTraits, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    };
+    use zarrs_storage::byte_range::ByteRange;
+
+    const JSON1: &str = r#"{"initval":0,"prefix":null}"#;
+
+    #[test]
+    fn codec_jenkins_lookup3_hash() {
+        // Test vectors from lookup3.c
+        assert_eq!(h5_checksum_lookup3(b"", 0), 0xdead_beef);
+        assert_eq!(
+            h5_checksum_lookup3(b"Four score and seven years ago", 0),
+            0x1777_0551
+        );
+        assert_eq!(
+            h5_checksum_lookup3(b"Four score and seven years ago", 1),
+            0xcd62_8161
+        );
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_configuration() {
+        let codec_configuration: JenkinsLookup3CodecConfiguration =
+            serde_json::from_str(JSON1).unwrap();
+        let codec = JenkinsLookup3Codec::new_with_configuration(&codec_configuration).unwrap();
+        let configuration = codec
+            .configuration_v3(&CodecMetadataOptions::default())
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&configuration).unwrap(),
+            r#"{"initval":0}"#
+        );
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_round_trip() {
+        let bytes: Vec<u8> = (0..30).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec_configuration: JenkinsLookup3CodecConfiguration =
+            serde_json::from_str(JSON1).unwrap();
+        let codec = JenkinsLookup3Codec::new_with_configuration(&codec_configuration).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            &encoded[bytes.len()..],
+            &h5_checksum_lookup3(&bytes, 0).to_le_bytes()
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_prefix() {
+        let codec = JenkinsLookup3Codec::new(0, Some(b"Four score".to_vec()));
+        let encoded = codec
+            .encode(
+                Cow::Borrowed(b" and seven years ago"),
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(&encoded[20..], &0x1777_0551u32.to_le_bytes());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_invalid_checksum() {
+        let bytes: Vec<u8> = (0..30).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = JenkinsLookup3Codec::new(0, None);
+
+        let mut encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        *encoded.last_mut().unwrap() ^= 0xff;
+        assert!(matches!(
+            codec.decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default()
+            ),
+            Err(CodecError::InvalidChecksum)
+        ));
+        let decoded = codec
+            .decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default().with_validate_checksums(false),
+            )
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_partial_decode() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Arc::new(JenkinsLookup3Codec::new(0, None));
+
+        let mut encoded = codec
+            .encode(Cow::Owned(bytes.clone()), &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        for validate_checksums in [true, false] {
+            let options = CodecOptions::default().with_validate_checksums(validate_checksums);
+            let partial_decoder = codec
+                .clone()
+                .partial_decoder(Arc::new(encoded.clone()), &bytes_representation, &options)
+                .unwrap();
+            let decoded = partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.to_vec(), vec![3, 4]);
+        }
+
+        // Corrupt the checksum
+        *encoded.last_mut().unwrap() ^= 0xff;
+        let options = CodecOptions::default();
+        let partial_decoder = codec
+            .clone()
+            .partial_decoder(Arc::new(encoded.clone()), &bytes_representation, &options)
+            .unwrap();
+        assert!(
+            partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_jenkins_lookup3_async_partial_decode() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Arc::new(JenkinsLookup3Codec::new(0, None));
+
+        let mut encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        *encoded.last_mut().unwrap() ^= 0xff;
+        for validate_checksums in [true, false] {
+            let options = CodecOptions::default().with_validate_checksums(validate_checksums);
+            let partial_decoder = codec
+                .clone()
+                .async_partial_decoder(Arc::new(encoded.clone()), &bytes_representation, &options)
+                .await
+                .unwrap();
+            let decoded = partial_decoder
+                .partial_decode(ByteRange::FromStart(3, Some(2)), &options)
+                .await;
+            if validate_checksums {
+                assert!(decoded.is_err());
+            } else {
+                assert_eq!(decoded.unwrap().unwrap().to_vec(), vec![3, 4]);
+            }
+        }
+    }
+}
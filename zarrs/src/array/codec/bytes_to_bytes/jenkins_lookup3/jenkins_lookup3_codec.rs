@@ -0,0 +1,276 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use super::{CHECKSUM_SIZE, JenkinsLookup3CodecConfiguration, JenkinsLookup3CodecConfigurationV1};
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::async_checksum_partial_decoder;
+use crate::array::codec::bytes_to_bytes::checksum_partial_decoder::{
+    ChecksumPosition, checksum_partial_decoder,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+#[cfg(feature = "async")]
+use zarrs_codec::AsyncBytesPartialDecoderTraits;
+use zarrs_codec::{
+    BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecError, CodecMetadataOptions,
+    CodecOptions, CodecTraits, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+
+/// A `jenkins_lookup3` codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct JenkinsLookup3Codec {
+    initval: u32,
+    prefix: Option<Vec<u8>>,
+}
+
+impl JenkinsLookup3Codec {
+    /// Create a new `jenkins_lookup3` codec.
+    ///
+    /// `initval` seeds the hash, and `prefix` (if set) is prepended to the input before it is hashed.
+    #[must_use]
+    pub const fn new(initval: u32, prefix: Option<Vec<u8>>) -> Self {
+        Self { initval, prefix }
+    }
+
+    /// Create a new `jenkins_lookup3` codec.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &JenkinsLookup3CodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            JenkinsLookup3CodecConfiguration::V1(configuration) => Ok(Self::new(
+                configuration.initval,
+                configuration.prefix.clone(),
+            )),
+            _ => Err(PluginCreateError::Other(
+                "this jenkins_lookup3 codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    fn checksum(&self, data: &[u8]) -> [u8; CHECKSUM_SIZE] {
+        let checksum = if let Some(prefix) = &self.prefix {
+            let prefixed = [prefix.as_slice(), data].concat();
+            h5_checksum_lookup3(&prefixed, self.initval)
+        } else {
+            h5_checksum_lookup3(data, self.initval)
+        };
+        checksum.to_le_bytes()
+    }
+}
+
+impl CodecTraits for JenkinsLookup3Codec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration =
+            JenkinsLookup3CodecConfiguration::V1(JenkinsLookup3CodecConfigurationV1 {
+                initval: self.initval,
+                prefix: self.prefix.clone(),
+            });
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+/// HDF5 Jenkins lookup3 (`hashlittle`).
+///
+/// Based on <https://github.com/HDFGroup/hdf5/blob/develop/src/H5checksum.c>.
+pub(super) fn h5_checksum_lookup3(key: &[u8], initval: u32) -> u32 {
+    #[allow(clippy::cast_possible_truncation)]
+    let length = key.len() as u32;
+    let mut a: u32 = 0xdead_beef_u32.wrapping_add(length).wrapping_add(initval);
+    let mut b = a;
+    let mut c = a;
+
+    let word = |bytes: &[u8]| {
+        let mut word = [0u8; 4];
+        word[..bytes.len()].copy_from_slice(bytes);
+        u32::from_le_bytes(word)
+    };
+
+    // All but the last block: affect some 32 bits of (a,b,c)
+    let mut key = key;
+    while key.len() > 12 {
+        a = a.wrapping_add(word(&key[0..4]));
+        b = b.wrapping_add(word(&key[4..8]));
+        c = c.wrapping_add(word(&key[8..12]));
+
+        // mix(a,b,c)
+        a = a.wrapping_sub(c);
+        a ^= c.rotate_left(4);
+        c = c.wrapping_add(b);
+        b = b.wrapping_sub(a);
+        b ^= a.rotate_left(6);
+        a = a.wrapping_add(c);
+        c = c.wrapping_sub(b);
+        c ^= b.rotate_left(8);
+        b = b.wrapping_add(a);
+        a = a.wrapping_sub(c);
+        a ^= c.rotate_left(16);
+        c = c.wrapping_add(b);
+        b = b.wrapping_sub(a);
+        b ^= a.rotate_left(19);
+        a = a.wrapping_add(c);
+        c = c.wrapping_sub(b);
+        c ^= b.rotate_left(4);
+        b = b.wrapping_add(a);
+
+        key = &key[12..];
+    }
+
+    // Last block: affect all 32 bits of (c)
+    if key.is_empty() {
+        return c;
+    }
+    a = a.wrapping_add(word(&key[..key.len().min(4)]));
+    if key.len() > 4 {
+        b = b.wrapping_add(word(&key[4..key.len().min(8)]));
+    }
+    if key.len() > 8 {
+        c = c.wrapping_add(word(&key[8..]));
+    }
+
+    // final(a,b,c)
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(14));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(11));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(25));
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(16));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(4));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(14));
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(24));
+    c
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for JenkinsLookup3Codec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let checksum = self.checksum(&decoded_value);
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        encoded_value.extend_from_slice(&decoded_value);
+        encoded_value.extend_from_slice(&checksum);
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        if encoded_value.len() >= CHECKSUM_SIZE {
+            if options.validate_checksums() {
+                let decoded_value = &encoded_value[..encoded_value.len() - CHECKSUM_SIZE];
+                let checksum = self.checksum(decoded_value);
+                let checksum_stored: [u8; CHECKSUM_SIZE] = encoded_value
+                    [encoded_value.len() - CHECKSUM_SIZE..]
+                    .try_into()
+                    .unwrap();
+                if checksum != checksum_stored {
+                    return Err(CodecError::InvalidChecksum);
+                }
+            }
+
+            let mut decoded_value = encoded_value.into_owned();
+            decoded_value.truncate(decoded_value.len() - CHECKSUM_SIZE);
+            Ok(Cow::Owned(decoded_value))
+        } else {
+            Err(CodecError::Other(
+                "jenkins_lookup3 decoder expects a 32 bit input".to_string(),
+            ))
+        }
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            ChecksumPosition::End,
+            CHECKSUM_SIZE,
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(async_checksum_partial_decoder(
+            self.into_dyn(),
+            input_handle,
+            decoded_representation,
+            options.validate_checksums(),
+            ChecksumPosition::End,
+            CHECKSUM_SIZE,
+        ))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! The `snappy` bytes to bytes codec (Experimental).
+//!
+//! Applies [Snappy](https://github.com/google/snappy) compression using the raw (unframed) [Snappy format](https://github.com/google/snappy/blob/main/format_description.txt).
+//!
+//! Snappy favours encoding and decoding speed over compression ratio, which makes it well suited to frequently accessed scratch arrays.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `snappy` feature, which is disabled by default.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/snappy>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.snappy`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/snappy`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`SnappyCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {}
+//! # "#;
+//! # use zarrs::metadata_ext::codec::snappy::SnappyCodecConfiguration;
+//! # serde_json::from_str::<SnappyCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod snappy_codec;
+
+use std::sync::Arc;
+
+pub use snappy_codec::SnappyCodec;
+use zarrs_metadata::v3::MetadataV3;
+
+use zarrs_codec::{Codec, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::snappy::{SnappyCodecConfiguration, SnappyCodecConfigurationV0};
+
+zarrs_plugin::impl_extension_aliases!(SnappyCodec, v3: "zarrs.snappy");
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<SnappyCodec>()
+}
+
+impl CodecTraitsV3 for SnappyCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: SnappyCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = Arc::new(SnappyCodec::new_with_configuration(&configuration)?);
+        Ok(Codec::BytesToBytes(codec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::BytesRepresentation;
+    use zarrs_codec::{BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecOptions};
+    use zarrs_storage::byte_range::ByteRange;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_snappy_round_trip1() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: SnappyCodecConfiguration = serde_json::from_str("{}").unwrap();
+        let codec = SnappyCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(
+            (encoded.len() as u64)
+                <= codec
+                    .encoded_representation(&bytes_representation)
+                    .size()
+                    .unwrap()
+        );
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_snappy_decode_invalid() {
+        let codec = SnappyCodec::new();
+        let bytes_representation = BytesRepresentation::FixedSize(8);
+        assert!(
+            codec
+                .decode(
+                    Cow::Owned(vec![0xff; 4]),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_snappy_partial_decode() {
+        let elements: Vec<u16> = (0..8).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let codec = Arc::new(SnappyCodec::new());
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [
+            ByteRange::FromStart(4, Some(4)),
+            ByteRange::FromStart(10, Some(2)),
+        ];
+
+        let input_handle = Arc::new(encoded);
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(partial_decoder.size_held(), input_handle.size_held()); // snappy partial decoder does not hold bytes
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_many(
+                Box::new(decoded_regions.into_iter()),
+                &CodecOptions::default(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let decoded_partial_chunk: Vec<u16> = decoded_partial_chunk
+            .concat()
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|b| u16::from_ne_bytes(*b))
+            .collect();
+        let answer: Vec<u16> = vec![2, 3, 5];
+        assert_eq!(answer, decoded_partial_chunk);
+    }
+}
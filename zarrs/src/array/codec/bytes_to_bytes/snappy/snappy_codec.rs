@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use super::{SnappyCodecConfiguration, SnappyCodecConfigurationV0};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+use zarrs_codec::{
+    BytesToBytesCodecTraits, CodecError, CodecMetadataOptions, CodecOptions, CodecTraits,
+    PartialDecoderCapability, PartialEncoderCapability, RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+/// A `snappy` codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct SnappyCodec;
+
+impl SnappyCodec {
+    /// Create a new `snappy` codec.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Create a new `snappy` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &SnappyCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            SnappyCodecConfiguration::V0(SnappyCodecConfigurationV0 {}) => Ok(Self),
+            _ => Err(PluginCreateError::Other(
+                "this snappy codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+}
+
+impl CodecTraits for SnappyCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration = SnappyCodecConfiguration::V0(SnappyCodecConfigurationV0 {});
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for SnappyCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let out = snap::raw::Encoder::new()
+            .compress_vec(&decoded_value)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let out = snap::raw::Decoder::new()
+            .decompress_vec(&encoded_value)
+            .map_err(|err| CodecError::Other(err.to_string()))?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        decoded_representation
+            .size()
+            .map_or(BytesRepresentation::UnboundedSize, |size| {
+                // via snappy MaxCompressedLength
+                // https://github.com/google/snappy/blob/1.2.1/snappy.cc#L208-L226
+                BytesRepresentation::BoundedSize(32 + size + size / 6)
+            })
+    }
+}
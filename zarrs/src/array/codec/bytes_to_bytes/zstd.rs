@@ -2,6 +2,8 @@
 //!
 //! Applies [Zstd](https://tools.ietf.org/html/rfc8878) compression.
 //!
+//! See the [`zstd_dictionary`](super::zstd_dictionary) codec for compression with a dictionary.
+//!
 //! ### Compatible Implementations
 //! This is expected to become a standardised extension.
 //!
@@ -29,6 +31,7 @@
 //! # "#;
 //! # use zarrs::metadata_ext::codec::zstd::ZstdCodecConfiguration;
 //! # serde_json::from_str::<ZstdCodecConfiguration>(JSON).unwrap();
+//! ```
 
 mod zstd_codec;
 
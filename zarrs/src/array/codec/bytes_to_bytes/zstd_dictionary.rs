@@ -0,0 +1,292 @@
+//! The `zstd_dictionary` bytes to bytes codec (Experimental).
+//!
+//! Applies [Zstd](https://tools.ietf.org/html/rfc8878) compression with a compression dictionary.
+//!
+//! Small chunks that share structure compress poorly individually, but can compress well with a [`ZstdDictionary`] trained from sample chunks with [`ZstdDictionary::train`].
+//! The dictionary is referenced in the codec configuration by its key relative to the array (e.g. `"dictionary": "zstd.dict"`).
+//! Keys that are absolute, contain `.` or `..` segments, or collide with array metadata or chunk keys are rejected.
+//! A key must not contain a metadata segment (e.g. `zarr.json`, `.zarray`), start with a `c` segment, or consist only of digits, `.` and `/`.
+//!  - Create a codec with [`ZstdDictionaryCodec::new`] and [`ZstdDictionaryCodec::with_dictionary`], and store the dictionary with [`Array::store_zstd_dictionary`](crate::array::Array::store_zstd_dictionary).
+//!  - Referenced dictionaries are loaded once when an array is opened with [`Array::open`](crate::array::Array::open), or explicitly with [`Array::load_zstd_dictionaries`](crate::array::Array::load_zstd_dictionaries).
+//!    They can otherwise be supplied with [`ZstdDictionaryCodecOptions`] through [`CodecSpecificOptions`](zarrs_codec::CodecSpecificOptions).
+//!  - [`ZstdDictionaryCodecOptions`] are forwarded to the inner and index codecs of the `sharding_indexed` codec, so dictionaries also apply to subchunks.
+//!
+//! Encoding or decoding without the dictionary is an error, including if a referenced dictionary is missing when the array is opened.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `zstd` feature, which is enabled by default.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! - <https://codec.zarrs.dev/bytes_to_bytes/zstd_dictionary>
+//!
+//! ### Codec `name` Aliases (Zarr V3)
+//! - `zarrs.zstd_dictionary`
+//! - `https://codec.zarrs.dev/bytes_to_bytes/zstd_dictionary`
+//!
+//! ### Codec `id` Aliases (Zarr V2)
+//! None
+//!
+//! ### Codec `configuration` Example - [`ZstdDictionaryCodecConfiguration`]:
+//! ```rust
+//! # let JSON = r#"
+//! {
+//!     "level": 3,
+//!     "checksum": false,
+//!     "dictionary": "zstd.dict"
+//! }
+//! # "#;
+//! # use zarrs::metadata_ext::codec::zstd_dictionary::ZstdDictionaryCodecConfiguration;
+//! # serde_json::from_str::<ZstdDictionaryCodecConfiguration>(JSON).unwrap();
+//! ```
+
+mod zstd_dictionary_codec;
+mod zstd_dictionary_options;
+
+use std::sync::Arc;
+
+use zarrs_metadata::v3::MetadataV3;
+pub use zstd_dictionary_codec::ZstdDictionaryCodec;
+pub(crate) use zstd_dictionary_codec::is_valid_dictionary_key;
+pub use zstd_dictionary_options::{ZstdDictionary, ZstdDictionaryCodecOptions};
+
+use zarrs_codec::{Codec, CodecPluginV3, CodecTraitsV3};
+pub use zarrs_metadata_ext::codec::zstd_dictionary::{
+    ZstdDictionaryCodecConfiguration, ZstdDictionaryCodecConfigurationV0,
+};
+
+zarrs_plugin::impl_extension_aliases!(ZstdDictionaryCodec,
+    v3: "zarrs.zstd_dictionary", ["https://codec.zarrs.dev/bytes_to_bytes/zstd_dictionary"]
+);
+
+// Register the V3 codec.
+inventory::submit! {
+    CodecPluginV3::new::<ZstdDictionaryCodec>()
+}
+
+impl CodecTraitsV3 for ZstdDictionaryCodec {
+    fn create(metadata: &MetadataV3) -> Result<Codec, zarrs_codec::CodecCreateError> {
+        crate::warn_experimental_extension(metadata.name(), "codec");
+        let configuration: ZstdDictionaryCodecConfiguration = metadata.to_typed_configuration()?;
+        let codec = ZstdDictionaryCodec::new_with_configuration(&configuration)?;
+        Ok(Codec::BytesToBytes(Arc::new(codec)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::codec::{ShardingCodecBuilder, ZstdCodec};
+    use crate::array::{ArrayBuilder, BytesRepresentation, data_type};
+    use zarrs_codec::{
+        BytesToBytesCodecTraits, CodecMetadataOptions, CodecOptions, CodecSpecificOptions,
+        CodecTraits,
+    };
+    use zarrs_storage::store::MemoryStore;
+    use zarrs_storage::{ReadableStorageTraits, WritableStorageTraits};
+
+    fn sample_chunks() -> Vec<Vec<u8>> {
+        (0..256u32)
+            .map(|i| {
+                format!(
+                    r#"{{"id":{i},"name":"sample","values":[{i},{},{}],"flag":true}}"#,
+                    i * 2,
+                    i * 3
+                )
+                .repeat(4)
+                .into_bytes()
+            })
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_zstd_dictionary() {
+        let samples = sample_chunks();
+        let dictionary = ZstdDictionary::train(&samples, 4096).unwrap();
+        assert!(dictionary.id().is_some());
+
+        let codec = Arc::new(
+            ZstdDictionaryCodec::new(3, true, "zstd.dict")
+                .unwrap()
+                .with_dictionary(dictionary.clone()),
+        );
+        let codec_no_dictionary = Arc::new(ZstdCodec::new(3, true));
+        let bytes = &samples[7];
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let encoded = codec
+            .encode(Cow::Borrowed(bytes), &CodecOptions::default())
+            .unwrap();
+        let encoded_no_dictionary = codec_no_dictionary
+            .encode(Cow::Borrowed(bytes), &CodecOptions::default())
+            .unwrap();
+        assert!(encoded.len() < encoded_no_dictionary.len());
+        let decoded = codec
+            .decode(
+                encoded.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(decoded.as_ref(), bytes.as_slice());
+
+        // The dictionary is referenced in the configuration, but not loaded
+        let configuration = codec
+            .configuration(
+                zarrs_plugin::ZarrVersion::V3,
+                &CodecMetadataOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(configuration["dictionary"], "zstd.dict");
+        let configuration: ZstdDictionaryCodecConfiguration =
+            serde_json::from_value(serde_json::Value::Object(configuration.into())).unwrap();
+        let codec = Arc::new(ZstdDictionaryCodec::new_with_configuration(&configuration).unwrap());
+        assert_eq!(codec.dictionary_key(), "zstd.dict");
+        assert!(codec.dictionary().is_none());
+        assert!(
+            codec
+                .clone()
+                .decode(
+                    encoded.clone(),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                )
+                .is_err()
+        );
+        let codec = codec
+            .with_codec_specific_options(&CodecSpecificOptions::default().with_option(
+                ZstdDictionaryCodecOptions::new().with_dictionary("zstd.dict", dictionary),
+            ))
+            .unwrap();
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(decoded.as_ref(), bytes.as_slice());
+    }
+
+    #[test]
+    fn codec_zstd_dictionary_key() {
+        assert!(ZstdDictionaryCodec::new(3, false, "zstd.dict").is_ok());
+        assert!(ZstdDictionaryCodec::new(3, false, "dictionaries/zstd.dict").is_ok());
+        assert!(ZstdDictionaryCodec::new(3, false, "c.dict").is_ok());
+        assert!(ZstdDictionaryCodec::new(3, false, "0.dict").is_ok());
+        for key in [
+            "",
+            "/zstd.dict",
+            "../zstd.dict",
+            "dictionaries/../../zstd.dict",
+            "./zstd.dict",
+            "dictionaries//zstd.dict",
+            "dictionaries/",
+            "zarr.json",
+            ".zarray",
+            ".zattrs",
+            "dictionaries/.zgroup",
+            "c/0/0",
+            "c",
+            "0.0",
+            "0/0",
+            "0",
+        ] {
+            assert!(ZstdDictionaryCodec::new(3, false, key).is_err(), "{key}");
+        }
+        let configuration: ZstdDictionaryCodecConfiguration = serde_json::from_str(
+            r#"{"level":3,"checksum":false,"dictionary":"../other/zstd.dict"}"#,
+        )
+        .unwrap();
+        assert!(ZstdDictionaryCodec::new_with_configuration(&configuration).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_zstd_dictionary_sharding() {
+        let samples: Vec<Vec<u8>> = sample_chunks()
+            .into_iter()
+            .map(|s| s[..64].to_vec())
+            .collect();
+        let dictionary = ZstdDictionary::train(&samples, 1024).unwrap();
+
+        let store = Arc::new(MemoryStore::new());
+        let array = ArrayBuilder::new(vec![4, 64], [2, 64], data_type::uint8(), 0u8)
+            .array_to_bytes_codec(Arc::new(
+                ShardingCodecBuilder::new(
+                    vec![
+                        std::num::NonZeroU64::new(1).unwrap(),
+                        std::num::NonZeroU64::new(64).unwrap(),
+                    ],
+                    &data_type::uint8(),
+                )
+                .bytes_to_bytes_codecs(vec![Arc::new(
+                    ZstdDictionaryCodec::new(5, false, "zstd.dict")
+                        .unwrap()
+                        .with_dictionary(dictionary.clone()),
+                )])
+                .build(),
+            ))
+            .attributes(
+                serde_json::json!({"codec": {"name": "zarrs.zstd_dictionary", "configuration": {"dictionary": "unrelated.dict"}}})
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            )
+            .build(store.clone(), "/array")
+            .unwrap();
+        array.store_metadata().unwrap();
+        array
+            .store_zstd_dictionary("zstd.dict", &dictionary)
+            .unwrap();
+        let elements = samples[..4].concat();
+        array
+            .store_array_subset(&[0..4, 0..64], elements.clone())
+            .unwrap();
+        assert!(
+            store
+                .get(&"array/zstd.dict".try_into().unwrap())
+                .unwrap()
+                .is_some()
+        );
+
+        // The dictionary is loaded when the array is opened, and attributes are not searched for dictionaries
+        let array = crate::array::Array::open(store.clone(), "/array").unwrap();
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u8>>(&[0..4, 0..64])
+                .unwrap(),
+            elements
+        );
+        assert_eq!(
+            array
+                .retrieve_array_subset::<Vec<u8>>(&[1..2, 0..64])
+                .unwrap(),
+            samples[1]
+        );
+
+        // Dictionaries cannot be stored outside of the array
+        assert!(
+            array
+                .store_zstd_dictionary("../zstd.dict", &dictionary)
+                .is_err()
+        );
+
+        // Dictionaries cannot collide with metadata or chunks
+        for key in ["zarr.json", "c/0/0"] {
+            assert!(array.store_zstd_dictionary(key, &dictionary).is_err());
+        }
+
+        // A missing dictionary is an error when decoding, not when opening
+        store.erase(&"array/zstd.dict".try_into().unwrap()).unwrap();
+        let array = crate::array::Array::open(store, "/array").unwrap();
+        let error = array
+            .retrieve_array_subset::<Vec<u8>>(&[0..4, 0..64])
+            .unwrap_err();
+        assert!(error.to_string().contains("zstd.dict"), "{error}");
+    }
+}
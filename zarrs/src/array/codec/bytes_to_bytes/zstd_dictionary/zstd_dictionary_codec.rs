@@ -0,0 +1,245 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+use zstd::zstd_safe;
+
+use super::zstd_dictionary_options::ZstdDictionaryPrepared;
+use super::{
+    ZstdDictionary, ZstdDictionaryCodecConfiguration, ZstdDictionaryCodecConfigurationV0,
+    ZstdDictionaryCodecOptions,
+};
+use crate::array::{ArrayBytesRaw, BytesRepresentation};
+use zarrs_codec::{
+    BytesToBytesCodecTraits, CodecCreateError, CodecError, CodecMetadataOptions, CodecOptions,
+    CodecSpecificOptions, CodecTraits, PartialDecoderCapability, PartialEncoderCapability,
+    RecommendedConcurrency,
+};
+use zarrs_metadata::Configuration;
+
+/// A `zstd_dictionary` codec implementation.
+#[derive(Clone, Debug)]
+pub struct ZstdDictionaryCodec {
+    compression: zstd_safe::CompressionLevel,
+    checksum: bool,
+    dictionary_key: String,
+    dictionary: Option<Arc<ZstdDictionaryPrepared>>,
+}
+
+/// Return true if `key` is a valid dictionary key relative to an array.
+///
+/// The key must be a relative store key without `.` or `..` segments, so that it cannot refer to a location outside of the array.
+/// It must also not collide with array metadata (e.g. `zarr.json`, `.zarray`) or chunk keys.
+/// Keys starting with a `c` segment are reserved for the `default` chunk key encoding (e.g. `c/0/0`), and keys of digits and `.` are reserved for the `v2` chunk key encoding (e.g. `0.0`, `0/0`).
+pub(crate) fn is_valid_dictionary_key(key: &str) -> bool {
+    const METADATA_KEYS: [&str; 5] = ["zarr.json", ".zarray", ".zattrs", ".zgroup", ".zmetadata"];
+    let is_v2_chunk_key = key
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.' || c == '/');
+    !key.is_empty()
+        && !key.starts_with('/')
+        && key
+            .split('/')
+            .all(|segment| !matches!(segment, "" | "." | "..") && !METADATA_KEYS.contains(&segment))
+        && key.split('/').next() != Some("c")
+        && !is_v2_chunk_key
+}
+
+impl ZstdDictionaryCodec {
+    /// Create a new `zstd_dictionary` codec that references the dictionary at `key` relative to the array.
+    ///
+    /// The dictionary must be supplied with [`with_dictionary`](ZstdDictionaryCodec::with_dictionary) or through [`ZstdDictionaryCodecOptions`] before encoding or decoding.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `key` is not a relative key within the array, or collides with array metadata or chunk keys.
+    pub fn new(
+        compression: zstd_safe::CompressionLevel,
+        checksum: bool,
+        key: impl Into<String>,
+    ) -> Result<Self, PluginCreateError> {
+        let dictionary_key = key.into();
+        if !is_valid_dictionary_key(&dictionary_key) {
+            return Err(PluginCreateError::Other(format!(
+                "the zstd dictionary key {dictionary_key} is not a relative key within the array, or collides with array metadata or chunk keys"
+            )));
+        }
+        Ok(Self {
+            compression,
+            checksum,
+            dictionary_key,
+            dictionary: None,
+        })
+    }
+
+    /// Create a new `zstd_dictionary` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported or the dictionary key is invalid.
+    pub fn new_with_configuration(
+        configuration: &ZstdDictionaryCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        match configuration {
+            ZstdDictionaryCodecConfiguration::V0(configuration) => Self::new(
+                configuration.level.into(),
+                configuration.checksum,
+                configuration.dictionary.clone(),
+            ),
+            _ => Err(PluginCreateError::Other(
+                "this zstd_dictionary codec configuration variant is unsupported".to_string(),
+            )),
+        }
+    }
+
+    /// Return a version of this codec that compresses with `dictionary`.
+    ///
+    /// The dictionary must be stored with [`Array::store_zstd_dictionary`](crate::array::Array::store_zstd_dictionary) so that the array can be decoded when reopened.
+    #[must_use]
+    pub fn with_dictionary(mut self, dictionary: ZstdDictionary) -> Self {
+        self.dictionary = Some(Arc::new(ZstdDictionaryPrepared::new(
+            dictionary,
+            self.compression,
+        )));
+        self
+    }
+
+    /// Return the key of the dictionary relative to the array.
+    #[must_use]
+    pub fn dictionary_key(&self) -> &str {
+        &self.dictionary_key
+    }
+
+    /// Return the dictionary, if it has been loaded.
+    #[must_use]
+    pub fn dictionary(&self) -> Option<&ZstdDictionary> {
+        self.dictionary
+            .as_deref()
+            .map(ZstdDictionaryPrepared::dictionary)
+    }
+
+    fn prepared_dictionary(&self) -> Result<&ZstdDictionaryPrepared, CodecError> {
+        self.dictionary.as_deref().ok_or_else(|| {
+            CodecError::Other(format!(
+                "the zstd dictionary {} does not exist or has not been loaded, store it with Array::store_zstd_dictionary or supply it with ZstdDictionaryCodecOptions",
+                self.dictionary_key
+            ))
+        })
+    }
+}
+
+impl CodecTraits for ZstdDictionaryCodec {
+    fn configuration(
+        &self,
+        _version: ZarrVersion,
+        _options: &CodecMetadataOptions,
+    ) -> Option<Configuration> {
+        let configuration =
+            ZstdDictionaryCodecConfiguration::V0(ZstdDictionaryCodecConfigurationV0::new(
+                self.compression.into(),
+                self.checksum,
+                self.dictionary_key.clone(),
+            ));
+        Some(configuration.into())
+    }
+
+    fn partial_decoder_capability(&self) -> PartialDecoderCapability {
+        PartialDecoderCapability {
+            partial_read: false,
+            partial_decode: false,
+        }
+    }
+
+    fn partial_encoder_capability(&self) -> PartialEncoderCapability {
+        PartialEncoderCapability {
+            partial_encode: false,
+        }
+    }
+}
+
+#[cfg_attr(
+    all(feature = "async", not(target_arch = "wasm32")),
+    async_trait::async_trait
+)]
+#[cfg_attr(all(feature = "async", target_arch = "wasm32"), async_trait::async_trait(?Send))]
+impl BytesToBytesCodecTraits for ZstdDictionaryCodec {
+    fn into_dyn(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn with_codec_specific_options(
+        self: Arc<Self>,
+        opts: &CodecSpecificOptions,
+    ) -> Result<Arc<dyn BytesToBytesCodecTraits>, CodecCreateError> {
+        if let Some(zstd_opts) = opts.get_option::<ZstdDictionaryCodecOptions>()
+            && let Some(dictionary) = zstd_opts.dictionary(&self.dictionary_key)
+        {
+            let dictionary = Arc::new(ZstdDictionaryPrepared::new(
+                dictionary.clone(),
+                self.compression,
+            ));
+            let mut codec = self;
+            Arc::make_mut(&mut codec).dictionary = Some(dictionary);
+            Ok(codec)
+        } else {
+            Ok(self.into_dyn())
+        }
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: ArrayBytesRaw<'a>,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let dictionary = self.prepared_dictionary()?;
+        let mut compressor =
+            zstd::bulk::Compressor::with_prepared_dictionary(dictionary.encoder())?;
+        compressor.include_checksum(self.checksum)?;
+        let result = compressor.compress(&decoded_value)?;
+        Ok(Cow::Owned(result))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: ArrayBytesRaw<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytesRaw<'a>, CodecError> {
+        let decoder = self.prepared_dictionary()?.decoder();
+        let upper_bound = zstd::bulk::Decompressor::upper_bound(&encoded_value); // requires zstd experimental feature
+        if let Some(upper_bound) = upper_bound {
+            // Bulk decompression
+            let mut decompressor = zstd::bulk::Decompressor::with_prepared_dictionary(decoder)?;
+            Ok(Cow::Owned(
+                decompressor.decompress(&encoded_value, upper_bound)?,
+            ))
+        } else {
+            // Streaming decompression (slower)
+            let mut decoder = zstd::Decoder::with_prepared_dictionary(&encoded_value[..], decoder)?;
+            let mut result = Vec::new();
+            std::io::Read::read_to_end(&mut decoder, &mut result)?;
+            Ok(Cow::Owned(result))
+        }
+    }
+
+    fn encoded_representation(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        decoded_representation
+            .size()
+            .map_or(BytesRepresentation::UnboundedSize, |size| {
+                // https://github.com/facebook/zstd/blob/dev/doc/zstd_compression_format.md
+                const HEADER_TRAILER_OVERHEAD: u64 = 4 + 14 + 4;
+                const MIN_WINDOW_SIZE: u64 = 1000; // 1KB
+                const BLOCK_OVERHEAD: u64 = 3;
+                let blocks_overhead = BLOCK_OVERHEAD * size.div_ceil(MIN_WINDOW_SIZE);
+                BytesRepresentation::BoundedSize(size + HEADER_TRAILER_OVERHEAD + blocks_overhead)
+            })
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::sync::{Arc, OnceLock};
+
+use zstd::dict::{DecoderDictionary, EncoderDictionary};
+use zstd::zstd_safe;
+
+/// A `zstd` compression dictionary.
+///
+/// Dictionaries improve the compression of small chunks that share structure.
+/// A dictionary can be [trained](ZstdDictionary::train) from sample chunks, and is stored alongside an array as a sidecar object referenced from the `zstd_dictionary` codec configuration.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ZstdDictionary(Arc<[u8]>);
+
+impl ZstdDictionary {
+    /// Create a new dictionary from its raw bytes.
+    #[must_use]
+    pub fn new(bytes: impl Into<Arc<[u8]>>) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Train a dictionary with a maximum size of `max_size` bytes from `samples`.
+    ///
+    /// Samples are typically encoded chunks of an array prior to `zstd` compression.
+    /// Training requires a reasonable number of samples (e.g. hundreds) that share structure.
+    ///
+    /// # Errors
+    /// Returns an error if training fails, e.g. if there are too few samples.
+    pub fn train<S: AsRef<[u8]>>(samples: &[S], max_size: usize) -> std::io::Result<Self> {
+        zstd::dict::from_samples(samples, max_size).map(Self::new)
+    }
+
+    /// Return the raw bytes of the dictionary.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Return the dictionary ID, or [`None`] if the dictionary is a raw content dictionary.
+    #[must_use]
+    pub fn id(&self) -> Option<NonZeroU32> {
+        zstd_safe::get_dict_id_from_dict(&self.0)
+    }
+}
+
+impl std::fmt::Debug for ZstdDictionary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZstdDictionary")
+            .field("id", &self.id())
+            .field("size", &self.0.len())
+            .finish()
+    }
+}
+
+/// A dictionary referenced by a `zstd_dictionary` codec, with lazily prepared encoder and decoder dictionaries.
+pub(super) struct ZstdDictionaryPrepared {
+    dictionary: ZstdDictionary,
+    level: i32,
+    encoder: OnceLock<EncoderDictionary<'static>>,
+    decoder: OnceLock<DecoderDictionary<'static>>,
+}
+
+impl ZstdDictionaryPrepared {
+    pub(super) fn new(dictionary: ZstdDictionary, level: i32) -> Self {
+        Self {
+            dictionary,
+            level,
+            encoder: OnceLock::new(),
+            decoder: OnceLock::new(),
+        }
+    }
+
+    pub(super) fn dictionary(&self) -> &ZstdDictionary {
+        &self.dictionary
+    }
+
+    pub(super) fn encoder(&self) -> &EncoderDictionary<'static> {
+        self.encoder
+            .get_or_init(|| EncoderDictionary::copy(self.dictionary.as_bytes(), self.level))
+    }
+
+    pub(super) fn decoder(&self) -> &DecoderDictionary<'static> {
+        self.decoder
+            .get_or_init(|| DecoderDictionary::copy(self.dictionary.as_bytes()))
+    }
+}
+
+impl std::fmt::Debug for ZstdDictionaryPrepared {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dictionary.fmt(f)
+    }
+}
+
+/// Runtime options for the [`ZstdDictionaryCodec`](super::ZstdDictionaryCodec).
+///
+/// Supplies the compression dictionaries referenced by `zstd_dictionary` codec configurations, keyed by their key relative to the array.
+/// Dictionaries are loaded automatically when opening an array with [`Array::open`](crate::array::Array::open), so these options are only needed to supply dictionaries from elsewhere.
+/// These options are also forwarded to the inner and index codecs of the `sharding_indexed` codec.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ZstdDictionaryCodecOptions {
+    dictionaries: HashMap<String, ZstdDictionary>,
+}
+
+impl ZstdDictionaryCodecOptions {
+    /// Create new `zstd_dictionary` codec options without any dictionaries.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a dictionary with the key `key` relative to the array.
+    #[must_use]
+    pub fn with_dictionary(mut self, key: impl Into<String>, dictionary: ZstdDictionary) -> Self {
+        self.dictionaries.insert(key.into(), dictionary);
+        self
+    }
+
+    /// Return the dictionary with the key `key`, if it exists.
+    #[must_use]
+    pub fn dictionary(&self, key: &str) -> Option<&ZstdDictionary> {
+        self.dictionaries.get(key)
+    }
+}
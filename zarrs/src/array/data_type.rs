@@ -16,17 +16,25 @@ mod macros;
 // Individual data type modules with substantial implementations
 mod bool;
 mod bytes;
+mod categorical;
 mod complex_float;
+mod complex_int;
 mod complex_subfloat;
+mod decimal;
 mod fixed_length_utf32;
 mod float;
 mod int;
 mod int2;
 mod int4;
+mod json;
+mod list;
+mod null_terminated_bytes;
 mod numpy_datetime64;
 mod numpy_timedelta64;
+mod packed_int;
 mod raw_bits;
 mod string;
+mod structured;
 mod subfloat;
 mod uint;
 mod uint2;
@@ -37,6 +45,7 @@ use std::num::{NonZeroU32, NonZeroU64};
 use std::sync::Arc;
 
 use zarrs_data_type::DataType;
+use zarrs_metadata::v2::{DataTypeMetadataV2, DataTypeMetadataV2Structured};
 use zarrs_metadata::v3::MetadataV3;
 use zarrs_plugin::ExtensionName;
 
@@ -49,13 +58,19 @@ pub use zarrs_data_type as api;
 pub use zarrs_metadata_ext::data_type::NumpyTimeUnit;
 
 pub use self::bool::BoolDataType;
+pub use self::categorical::{CategoricalCodeDataType, CategoricalDataType};
+pub use self::decimal::DecimalDataType;
 pub use self::fixed_length_utf32::FixedLengthUTF32DataType;
+pub use self::list::ListDataType;
+pub use self::null_terminated_bytes::NullTerminatedBytesDataType;
+pub use self::packed_int::PackedIntDataType;
 pub use self::string::StringDataType;
 pub use bytes::BytesDataType;
 pub use complex_float::{
     Complex64DataType, Complex128DataType, ComplexBFloat16DataType, ComplexFloat16DataType,
     ComplexFloat32DataType, ComplexFloat64DataType,
 };
+pub use complex_int::{ComplexInt8DataType, ComplexInt16DataType, ComplexInt32DataType};
 pub use complex_subfloat::{
     ComplexFloat4E2M1FNDataType, ComplexFloat6E2M3FNDataType, ComplexFloat6E3M2FNDataType,
     ComplexFloat8E3M4DataType, ComplexFloat8E4M3B11FNUZDataType, ComplexFloat8E4M3DataType,
@@ -66,9 +81,13 @@ pub use float::{BFloat16DataType, Float16DataType, Float32DataType, Float64DataT
 pub use int::{Int8DataType, Int16DataType, Int32DataType, Int64DataType};
 pub use int2::Int2DataType;
 pub use int4::Int4DataType;
+pub use json::JsonDataType;
+pub(crate) use json::sort_json_object_keys;
+pub(crate) use list::{list_items_decode, list_items_encode};
 pub use numpy_datetime64::NumpyDateTime64DataType;
 pub use numpy_timedelta64::NumpyTimeDelta64DataType;
 pub use raw_bits::RawBitsDataType;
+pub use structured::{StructDataType, StructField};
 pub use subfloat::{
     Float4E2M1FNDataType, Float6E2M3FNDataType, Float6E3M2FNDataType, Float8E3M4DataType,
     Float8E4M3B11FNUZDataType, Float8E4M3DataType, Float8E4M3FNUZDataType, Float8E5M2DataType,
@@ -145,6 +164,40 @@ pub fn uint32() -> DataType {
 pub fn uint64() -> DataType {
     Arc::new(UInt64DataType).into()
 }
+/// Create an `int{bits}` data type with a bit width from 1 to 64.
+///
+/// Returns the dedicated data type for bit widths of 2, 4, 8, 16, 32, and 64 (e.g. [`int4`]), otherwise a [`PackedIntDataType`].
+///
+/// # Errors
+/// Returns an error if `bits` is not in the range 1 to 64.
+pub fn int_bits(bits: u32) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(match bits {
+        2 => int2(),
+        4 => int4(),
+        8 => int8(),
+        16 => int16(),
+        32 => int32(),
+        64 => int64(),
+        _ => Arc::new(PackedIntDataType::new(bits, true)?).into(),
+    })
+}
+/// Create a `uint{bits}` data type with a bit width from 1 to 64.
+///
+/// Returns the dedicated data type for bit widths of 2, 4, 8, 16, 32, and 64 (e.g. [`uint4`]), otherwise a [`PackedIntDataType`].
+///
+/// # Errors
+/// Returns an error if `bits` is not in the range 1 to 64.
+pub fn uint_bits(bits: u32) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(match bits {
+        2 => uint2(),
+        4 => uint4(),
+        8 => uint8(),
+        16 => uint16(),
+        32 => uint32(),
+        64 => uint64(),
+        _ => Arc::new(PackedIntDataType::new(bits, false)?).into(),
+    })
+}
 
 // Standard floats
 /// Create a `bfloat16` data type.
@@ -252,6 +305,23 @@ pub fn complex_float64() -> DataType {
     Arc::new(ComplexFloat64DataType).into()
 }
 
+// Complex integers
+/// Create a `complex_int8` data type.
+#[must_use]
+pub fn complex_int8() -> DataType {
+    Arc::new(ComplexInt8DataType).into()
+}
+/// Create a `complex_int16` data type.
+#[must_use]
+pub fn complex_int16() -> DataType {
+    Arc::new(ComplexInt16DataType).into()
+}
+/// Create a `complex_int32` data type.
+#[must_use]
+pub fn complex_int32() -> DataType {
+    Arc::new(ComplexInt32DataType).into()
+}
+
 // Complex subfloats
 /// Create a `complex_float4_e2m1fn` data type.
 #[must_use]
@@ -315,6 +385,11 @@ pub fn string() -> DataType {
 pub fn bytes() -> DataType {
     Arc::new(BytesDataType).into()
 }
+/// Create a `zarrs.json` data type.
+#[must_use]
+pub fn json() -> DataType {
+    Arc::new(JsonDataType).into()
+}
 /// Create an `r*` (raw bits) data type with the given size in bytes.
 #[must_use]
 pub fn raw_bits(size_bytes: usize) -> DataType {
@@ -333,6 +408,53 @@ pub fn fixed_length_utf32(
     Ok(Arc::new(FixedLengthUTF32DataType::new(length_bytes)?).into())
 }
 
+/// Create a `null_terminated_bytes` data type with the given size in bytes.
+///
+/// # Errors
+/// Returns an error if `length_bytes` exceeds [`usize::MAX`].
+pub fn null_terminated_bytes(
+    length_bytes: NonZeroU64,
+) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(Arc::new(NullTerminatedBytesDataType::new(length_bytes)?).into())
+}
+
+/// Create a `zarrs.categorical` data type with the given category table.
+///
+/// The smallest code data type supporting the `categories` is used.
+///
+/// # Errors
+/// Returns an error if a category is repeated or there are more than 2<sup>32</sup> categories.
+pub fn categorical(categories: Vec<String>) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(Arc::new(CategoricalDataType::new(categories)?).into())
+}
+
+/// Create a `zarrs.decimal` data type with `precision` significant digits, of which `scale` are after the decimal point.
+///
+/// # Errors
+/// Returns an error if `precision` exceeds 38 or `scale` exceeds `precision`.
+pub fn decimal(
+    precision: NonZeroU32,
+    scale: u32,
+) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(Arc::new(DecimalDataType::new(precision, scale)?).into())
+}
+
+/// Create a `zarrs.list` data type with items of `data_type`.
+///
+/// # Errors
+/// Returns an error if `data_type` is not fixed size or is optional.
+pub fn list(data_type: DataType) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(Arc::new(ListDataType::new(data_type)?).into())
+}
+
+/// Create a `struct` data type with the given fields.
+///
+/// # Errors
+/// Returns an error if there are no fields, a field name is repeated, or a field data type is unsupported.
+pub fn structured(fields: Vec<StructField>) -> Result<DataType, zarrs_plugin::PluginCreateError> {
+    Ok(Arc::new(StructDataType::new(fields)?).into())
+}
+
 // NumPy time types
 /// Create a `numpy.datetime64` data type.
 #[must_use]
@@ -383,6 +505,30 @@ pub(crate) fn data_type_v2_default_name(v2_name: &str) -> Cow<'static, str> {
     Cow::Owned(v2_name.to_string())
 }
 
+/// Get the V2 data type metadata with default V2 names, including the fields of structured data types.
+#[must_use]
+pub(crate) fn data_type_metadata_v2_default_names(
+    data_type: &DataTypeMetadataV2,
+) -> DataTypeMetadataV2 {
+    match data_type {
+        DataTypeMetadataV2::Simple(dtype) => {
+            DataTypeMetadataV2::Simple(data_type_v2_default_name(dtype).into_owned())
+        }
+        DataTypeMetadataV2::Structured(fields) => DataTypeMetadataV2::Structured(
+            fields
+                .iter()
+                .map(|field| {
+                    DataTypeMetadataV2Structured::new(
+                        field.fieldname(),
+                        data_type_metadata_v2_default_names(field.datatype()),
+                        field.shape().map(<[u64]>::to_vec),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use half::bf16;
@@ -1555,6 +1701,66 @@ mod tests {
         assert!(data_type.fill_value_v3(&metadata).is_err());
     }
 
+    #[test]
+    fn data_type_complex_int16() {
+        let json = r#""complex_int16""#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(
+            json,
+            serde_json::to_string(&data_type_metadata(&data_type)).unwrap()
+        );
+        assert!(data_type.is::<ComplexInt16DataType>());
+        assert_eq!(data_type.fixed_size(), Some(4));
+
+        let metadata = serde_json::from_str::<FillValueMetadata>(r"[-7, 32767]").unwrap();
+        let fill_value = data_type.fill_value_v3(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            (-7i16)
+                .to_ne_bytes()
+                .iter()
+                .chain(i16::MAX.to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(
+            metadata,
+            data_type.metadata_fill_value(&fill_value).unwrap()
+        );
+
+        for metadata in [r"-7", r"[-7, 32768]", r"[-7.5, 0]", r"[0, 0, 0]"] {
+            let metadata = serde_json::from_str::<FillValueMetadata>(metadata).unwrap();
+            assert!(data_type.fill_value_v3(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn data_type_complex_int8_int32() {
+        let data_type = complex_int8();
+        assert_eq!(data_type.fixed_size(), Some(2));
+        let metadata = serde_json::from_str::<FillValueMetadata>(r"[-128, 127]").unwrap();
+        let fill_value = data_type.fill_value_v3(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0x80, 0x7f]);
+        assert_eq!(
+            metadata,
+            data_type.metadata_fill_value(&fill_value).unwrap()
+        );
+
+        let data_type = complex_int32();
+        assert_eq!(data_type.fixed_size(), Some(8));
+        let metadata = serde_json::from_str::<FillValueMetadata>(r"[-2147483648, 1]").unwrap();
+        let fill_value = data_type.fill_value_v3(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            FillValue::from(num::complex::Complex::new(i32::MIN, 1)).as_ne_bytes()
+        );
+        assert_eq!(
+            metadata,
+            data_type.metadata_fill_value(&fill_value).unwrap()
+        );
+    }
+
     #[test]
     fn data_type_complex64() {
         let json = r#""complex64""#;
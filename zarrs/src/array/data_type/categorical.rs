@@ -0,0 +1,330 @@
+//! The `zarrs.categorical` data type.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use zarrs_data_type::codec_traits::bytes::{BytesCodecEndiannessMissingError, BytesDataTypeTraits};
+use zarrs_data_type::{
+    DataType, DataTypeFillValueError, DataTypeFillValueMetadataError, DataTypeTraits, FillValue,
+};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, Endianness, FillValueMetadata};
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+pub use zarrs_metadata_ext::data_type::categorical::CategoricalCodeDataType;
+use zarrs_metadata_ext::data_type::categorical::CategoricalDataTypeConfigurationV1;
+
+/// The `zarrs.categorical` data type.
+///
+/// A dictionary encoded string data type with a category table stored in the data type metadata.
+/// Elements are stored as fixed size integer codes indexing the category table, so arrays of repeated strings are compact and support partial decoding.
+///
+/// [`String`] and [`&str`] elements are encoded to and decoded from their codes.
+/// The raw codes can be accessed with [`u8`], [`u16`], or [`u32`] elements matching the [`CategoricalCodeDataType`].
+///
+/// Fill values are categories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoricalDataType {
+    categories: Vec<String>,
+    code_data_type: CategoricalCodeDataType,
+    codes: HashMap<String, u32>,
+}
+
+zarrs_plugin::impl_extension_aliases!(CategoricalDataType, v3: "zarrs.categorical");
+
+impl zarrs_data_type::DataTypeTraitsV3 for CategoricalDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        let config: CategoricalDataTypeConfigurationV1 = metadata.to_typed_configuration()?;
+        Ok(Arc::new(Self::new_with_code_data_type(
+            config.categories,
+            config.code_data_type,
+        )?)
+        .into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<CategoricalDataType>()
+}
+
+impl CategoricalDataType {
+    /// Create a new categorical data type with the smallest code data type supporting the `categories`.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if a category is repeated or there are more than 2<sup>32</sup> categories.
+    pub fn new(categories: Vec<String>) -> Result<Self, PluginCreateError> {
+        let code_data_type = CategoricalCodeDataType::for_num_categories(categories.len())
+            .ok_or_else(|| {
+                PluginCreateError::Other(format!(
+                    "categorical data type has too many categories ({})",
+                    categories.len()
+                ))
+            })?;
+        Self::new_with_code_data_type(categories, code_data_type)
+    }
+
+    /// Create a new categorical data type with the given code data type.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if a category is repeated or the `code_data_type` cannot index all `categories`.
+    pub fn new_with_code_data_type(
+        categories: Vec<String>,
+        code_data_type: CategoricalCodeDataType,
+    ) -> Result<Self, PluginCreateError> {
+        let max_categories = 1_u64 << (8 * code_data_type.size());
+        if u64::try_from(categories.len()).map_or(true, |len| len > max_categories) {
+            return Err(PluginCreateError::Other(format!(
+                "{code_data_type} codes cannot index {} categories",
+                categories.len()
+            )));
+        }
+        let mut codes = HashMap::with_capacity(categories.len());
+        for (category, code) in categories.iter().zip(0_u32..) {
+            if codes.insert(category.clone(), code).is_some() {
+                return Err(PluginCreateError::Other(format!(
+                    "categorical data type category {category:?} is repeated"
+                )));
+            }
+        }
+        Ok(Self {
+            categories,
+            code_data_type,
+            codes,
+        })
+    }
+
+    /// Returns the category table.
+    #[must_use]
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Returns the data type of the category codes.
+    #[must_use]
+    pub const fn code_data_type(&self) -> CategoricalCodeDataType {
+        self.code_data_type
+    }
+
+    /// Returns the code of `category`, if it is in the category table.
+    #[must_use]
+    pub fn code(&self, category: &str) -> Option<u32> {
+        self.codes.get(category).copied()
+    }
+
+    /// Returns the category with `code`, if it is in the category table.
+    #[must_use]
+    pub fn category(&self, code: u32) -> Option<&str> {
+        self.categories
+            .get(usize::try_from(code).ok()?)
+            .map(String::as_str)
+    }
+
+    /// Read a code from native-endian bytes.
+    pub(crate) fn read_code(&self, bytes: &[u8]) -> Option<u32> {
+        match self.code_data_type {
+            CategoricalCodeDataType::UInt8 => {
+                Some(u32::from(u8::from_ne_bytes(bytes.try_into().ok()?)))
+            }
+            CategoricalCodeDataType::UInt16 => {
+                Some(u32::from(u16::from_ne_bytes(bytes.try_into().ok()?)))
+            }
+            CategoricalCodeDataType::UInt32 => Some(u32::from_ne_bytes(bytes.try_into().ok()?)),
+        }
+    }
+
+    /// Write a code that is within the range of the code data type as native-endian bytes.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn write_code(&self, code: u32, output: &mut Vec<u8>) {
+        match self.code_data_type {
+            CategoricalCodeDataType::UInt8 => output.push(code as u8),
+            CategoricalCodeDataType::UInt16 => {
+                output.extend_from_slice(&(code as u16).to_ne_bytes());
+            }
+            CategoricalCodeDataType::UInt32 => output.extend_from_slice(&code.to_ne_bytes()),
+        }
+    }
+}
+
+impl DataTypeTraits for CategoricalDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::from(CategoricalDataTypeConfigurationV1 {
+            categories: self.categories.clone(),
+            code_data_type: self.code_data_type,
+        })
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Fixed(self.code_data_type.size())
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        _version: ZarrVersion,
+    ) -> Result<FillValue, DataTypeFillValueMetadataError> {
+        let code = fill_value_metadata
+            .as_str()
+            .and_then(|category| self.code(category))
+            .ok_or(DataTypeFillValueMetadataError)?;
+        let mut bytes = Vec::with_capacity(self.code_data_type.size());
+        self.write_code(code, &mut bytes);
+        Ok(FillValue::new(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadata, DataTypeFillValueError> {
+        self.read_code(fill_value.as_ne_bytes())
+            .and_then(|code| self.category(code))
+            .map(FillValueMetadata::from)
+            .ok_or(DataTypeFillValueError)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn compatible_element_types(&self) -> &'static [TypeId] {
+        const U8: [TypeId; 3] = [
+            TypeId::of::<&str>(),
+            TypeId::of::<String>(),
+            TypeId::of::<u8>(),
+        ];
+        const U16: [TypeId; 3] = [
+            TypeId::of::<&str>(),
+            TypeId::of::<String>(),
+            TypeId::of::<u16>(),
+        ];
+        const U32: [TypeId; 3] = [
+            TypeId::of::<&str>(),
+            TypeId::of::<String>(),
+            TypeId::of::<u32>(),
+        ];
+        match self.code_data_type {
+            CategoricalCodeDataType::UInt8 => &U8,
+            CategoricalCodeDataType::UInt16 => &U16,
+            CategoricalCodeDataType::UInt32 => &U32,
+        }
+    }
+}
+
+impl BytesDataTypeTraits for CategoricalDataType {
+    fn encode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        let code_size = self.code_data_type.size();
+        if code_size == 1 {
+            return Ok(bytes);
+        }
+        let endianness = endianness.ok_or(BytesCodecEndiannessMissingError)?;
+        if endianness == Endianness::native() {
+            Ok(bytes)
+        } else {
+            let mut bytes = bytes.into_owned();
+            for code in bytes.chunks_exact_mut(code_size) {
+                code.reverse();
+            }
+            Ok(Cow::Owned(bytes))
+        }
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        self.encode(bytes, endianness)
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    CategoricalDataType,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypePlugin,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypeTraits
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn categories(categories: &[&str]) -> Vec<String> {
+        categories.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn from_metadata() {
+        let json = r#"{"name":"zarrs.categorical","configuration":{"categories":["red","green","blue"],"code_data_type":"uint16"}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        let categorical = data_type.downcast_ref::<CategoricalDataType>().unwrap();
+        assert_eq!(categorical.categories(), ["red", "green", "blue"]);
+        assert_eq!(categorical.code("blue"), Some(2));
+        assert_eq!(categorical.category(1), Some("green"));
+        assert_eq!(categorical.category(3), None);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(2));
+        assert_eq!(
+            Some(&data_type.configuration(ZarrVersion::V3)),
+            metadata.configuration()
+        );
+
+        let json = r#"{"name":"zarrs.categorical","configuration":{"categories":["a","a"],"code_data_type":"uint8"}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn code_data_type() {
+        let data_type = CategoricalDataType::new(categories(&["a", "b"])).unwrap();
+        assert_eq!(data_type.code_data_type(), CategoricalCodeDataType::UInt8);
+        let many = (0..300).map(|i| i.to_string()).collect::<Vec<_>>();
+        let data_type = CategoricalDataType::new(many.clone()).unwrap();
+        assert_eq!(data_type.code_data_type(), CategoricalCodeDataType::UInt16);
+        assert!(
+            CategoricalDataType::new_with_code_data_type(many, CategoricalCodeDataType::UInt8)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn equality() {
+        let a = DataType::new(CategoricalDataType::new(categories(&["a", "b"])).unwrap());
+        let b = DataType::new(CategoricalDataType::new(categories(&["a", "b"])).unwrap());
+        let c = DataType::new(CategoricalDataType::new(categories(&["b", "a"])).unwrap());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn fill_value() {
+        let data_type = DataType::new(CategoricalDataType::new(categories(&["a", "b"])).unwrap());
+        let fill_value = data_type
+            .fill_value(&FillValueMetadata::from("b"), ZarrVersion::V3)
+            .unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [1]);
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            FillValueMetadata::from("b")
+        );
+        assert!(
+            data_type
+                .fill_value(&FillValueMetadata::from("c"), ZarrVersion::V3)
+                .is_err()
+        );
+        assert!(
+            data_type
+                .fill_value(&FillValueMetadata::from(1), ZarrVersion::V3)
+                .is_err()
+        );
+        assert!(
+            data_type
+                .metadata_fill_value(&FillValue::new(vec![2]))
+                .is_err()
+        );
+    }
+}
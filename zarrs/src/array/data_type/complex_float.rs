@@ -180,6 +180,15 @@ impl_bitround_codec!(ComplexFloat64DataType, 8, float64, 52);
 impl_bitround_codec!(Complex64DataType, 4, float32, 23);
 impl_bitround_codec!(Complex128DataType, 8, float64, 52);
 
+// CastValue implementations for standard complex types
+use zarrs_data_type::codec_traits::impl_cast_value_data_type_traits_complex;
+impl_cast_value_data_type_traits_complex!(ComplexBFloat16DataType, bf16);
+impl_cast_value_data_type_traits_complex!(ComplexFloat16DataType, f16);
+impl_cast_value_data_type_traits_complex!(ComplexFloat32DataType, f32);
+impl_cast_value_data_type_traits_complex!(ComplexFloat64DataType, f64);
+impl_cast_value_data_type_traits_complex!(Complex64DataType, f32);
+impl_cast_value_data_type_traits_complex!(Complex128DataType, f64);
+
 // Pcodec implementations for standard complex types
 use zarrs_data_type::codec_traits::impl_pcodec_data_type_traits;
 // impl_pcodec_data_type_traits!(ComplexBFloat16DataType, BF16, 2);
@@ -0,0 +1,120 @@
+//! Complex integer data types (`complex_int8`, `complex_int16`, `complex_int32`).
+
+use super::macros::register_data_type_plugin;
+
+/// Macro to implement `DataTypeTraits` for complex integer types.
+macro_rules! impl_complex_int_data_type {
+    ($marker:ty, $size:tt, $component_type:ty) => {
+        impl zarrs_data_type::DataTypeTraits for $marker {
+            fn configuration(
+                &self,
+                _version: zarrs_plugin::ZarrVersion,
+            ) -> zarrs_metadata::Configuration {
+                zarrs_metadata::Configuration::default()
+            }
+
+            fn size(&self) -> zarrs_metadata::DataTypeSize {
+                zarrs_metadata::DataTypeSize::Fixed($size)
+            }
+
+            fn fill_value(
+                &self,
+                fill_value_metadata: &zarrs_metadata::FillValueMetadata,
+                _version: zarrs_plugin::ZarrVersion,
+            ) -> Result<zarrs_data_type::FillValue, zarrs_data_type::DataTypeFillValueMetadataError>
+            {
+                let err = || zarrs_data_type::DataTypeFillValueMetadataError;
+                let [re, im] = fill_value_metadata.as_array().ok_or_else(err)? else {
+                    return Err(err());
+                };
+                let re =
+                    <$component_type>::try_from(re.as_i64().ok_or_else(err)?).map_err(|_| err())?;
+                let im =
+                    <$component_type>::try_from(im.as_i64().ok_or_else(err)?).map_err(|_| err())?;
+                Ok(zarrs_data_type::FillValue::from(
+                    num::complex::Complex::new(re, im),
+                ))
+            }
+
+            fn metadata_fill_value(
+                &self,
+                fill_value: &zarrs_data_type::FillValue,
+            ) -> Result<zarrs_metadata::FillValueMetadata, zarrs_data_type::DataTypeFillValueError>
+            {
+                let bytes: [u8; $size] = fill_value
+                    .as_ne_bytes()
+                    .try_into()
+                    .map_err(|_| zarrs_data_type::DataTypeFillValueError)?;
+                let value: num::complex::Complex<$component_type> = bytemuck::cast(bytes);
+                Ok(zarrs_metadata::FillValueMetadata::from(vec![
+                    zarrs_metadata::FillValueMetadata::from(value.re),
+                    zarrs_metadata::FillValueMetadata::from(value.im),
+                ]))
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn compatible_element_types(&self) -> &'static [std::any::TypeId] {
+                const TYPES: [std::any::TypeId; 1] =
+                    [std::any::TypeId::of::<num::complex::Complex<$component_type>>()];
+                &TYPES
+            }
+        }
+
+        zarrs_data_type::codec_traits::impl_bytes_data_type_traits!($marker, { $size / 2 });
+    };
+}
+
+/// The `complex_int8` data type.
+///
+/// A complex number with `int8` real and imaginary components.
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexInt8DataType;
+register_data_type_plugin!(ComplexInt8DataType);
+zarrs_plugin::impl_extension_aliases!(ComplexInt8DataType, v3: "complex_int8");
+
+/// The `complex_int16` data type.
+///
+/// A complex number with `int16` real and imaginary components.
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexInt16DataType;
+register_data_type_plugin!(ComplexInt16DataType);
+zarrs_plugin::impl_extension_aliases!(ComplexInt16DataType, v3: "complex_int16");
+
+/// The `complex_int32` data type.
+///
+/// A complex number with `int32` real and imaginary components.
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexInt32DataType;
+register_data_type_plugin!(ComplexInt32DataType);
+zarrs_plugin::impl_extension_aliases!(ComplexInt32DataType, v3: "complex_int32");
+
+// DataTypeTraits implementations for complex integer types
+impl_complex_int_data_type!(ComplexInt8DataType, 2, i8);
+impl_complex_int_data_type!(ComplexInt16DataType, 4, i16);
+impl_complex_int_data_type!(ComplexInt32DataType, 8, i32);
+
+// Bitround implementations for complex integer types
+use zarrs_data_type::codec_traits::impl_bitround_codec;
+impl_bitround_codec!(ComplexInt8DataType, 1, int8);
+impl_bitround_codec!(ComplexInt16DataType, 2, int16);
+impl_bitround_codec!(ComplexInt32DataType, 4, int32);
+
+// CastValue implementations for complex integer types
+use zarrs_data_type::codec_traits::impl_cast_value_data_type_traits_complex;
+impl_cast_value_data_type_traits_complex!(ComplexInt8DataType, i8);
+impl_cast_value_data_type_traits_complex!(ComplexInt16DataType, i16);
+impl_cast_value_data_type_traits_complex!(ComplexInt32DataType, i32);
+
+// Pcodec implementations for complex integer types (complex_int8 not supported)
+use zarrs_data_type::codec_traits::impl_pcodec_data_type_traits;
+impl_pcodec_data_type_traits!(ComplexInt16DataType, I16, 2);
+impl_pcodec_data_type_traits!(ComplexInt32DataType, I32, 2);
+
+// PackBits implementations for complex integer types
+use zarrs_data_type::codec_traits::impl_pack_bits_data_type_traits;
+impl_pack_bits_data_type_traits!(ComplexInt8DataType, 8, signed, 2);
+impl_pack_bits_data_type_traits!(ComplexInt16DataType, 16, signed, 2);
+impl_pack_bits_data_type_traits!(ComplexInt32DataType, 32, signed, 2);
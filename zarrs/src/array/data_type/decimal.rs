@@ -0,0 +1,379 @@
+//! The `zarrs.decimal` data type.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+use zarrs_data_type::codec_traits::bytes::{BytesCodecEndiannessMissingError, BytesDataTypeTraits};
+use zarrs_data_type::codec_traits::cast_value::{
+    CastValueDataTypeTraits, CastValueError, CastValueOutOfRangeMode, CastValueRoundingMode,
+    CastValueScalar, cast_scalar_to_decimal, format_decimal, parse_decimal,
+};
+use zarrs_data_type::{
+    DataType, DataTypeFillValueError, DataTypeFillValueMetadataError, DataTypeTraits, FillValue,
+};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, Endianness, FillValueMetadata};
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use zarrs_metadata_ext::data_type::decimal::DecimalDataTypeConfigurationV1;
+
+/// The maximum precision of a decimal data type.
+const MAX_PRECISION: u32 = 38;
+
+/// The maximum scale of a decimal data type, matching the maximum scale of a [`rust_decimal::Decimal`](https://docs.rs/rust_decimal).
+const MAX_SCALE: u32 = 28;
+
+/// The `zarrs.decimal` data type.
+///
+/// Represents exact decimal numbers with up to `precision` significant digits, of which `scale` are after the decimal point.
+/// Elements are stored as an unscaled integer mantissa (`value * 10^scale`) in the smallest of [`i32`], [`i64`], or [`i128`] that holds `precision` digits.
+///
+/// Fill values are decimal strings (e.g. `"-12.50"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalDataType {
+    precision: NonZeroU32,
+    scale: u32,
+}
+
+zarrs_plugin::impl_extension_aliases!(DecimalDataType, v3: "zarrs.decimal");
+
+impl zarrs_data_type::DataTypeTraitsV3 for DecimalDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        let config: DecimalDataTypeConfigurationV1 = metadata.to_typed_configuration()?;
+        Ok(Arc::new(Self::new(config.precision, config.scale)?).into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<DecimalDataType>()
+}
+
+impl DecimalDataType {
+    /// Create a new decimal data type with `precision` significant digits, of which `scale` are after the decimal point.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if `precision` exceeds 38, `scale` exceeds 28, or `scale` exceeds `precision`.
+    pub fn new(precision: NonZeroU32, scale: u32) -> Result<Self, PluginCreateError> {
+        if precision.get() > MAX_PRECISION {
+            return Err(PluginCreateError::Other(format!(
+                "decimal precision must not exceed {MAX_PRECISION}, got {precision}"
+            )));
+        }
+        if scale > MAX_SCALE {
+            return Err(PluginCreateError::Other(format!(
+                "decimal scale must not exceed {MAX_SCALE}, got {scale}"
+            )));
+        }
+        if scale > precision.get() {
+            return Err(PluginCreateError::Other(format!(
+                "decimal scale {scale} must not exceed the precision {precision}"
+            )));
+        }
+        Ok(Self { precision, scale })
+    }
+
+    /// Returns the maximum number of significant decimal digits.
+    #[must_use]
+    pub const fn precision(&self) -> NonZeroU32 {
+        self.precision
+    }
+
+    /// Returns the number of decimal digits after the decimal point.
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns the size in bytes of the integer holding the mantissa of an element.
+    #[must_use]
+    pub const fn element_size(&self) -> usize {
+        match self.precision.get() {
+            0..=9 => 4,
+            10..=18 => 8,
+            _ => 16,
+        }
+    }
+
+    /// Read the mantissa of an element from native-endian bytes.
+    pub(crate) fn read_mantissa(self, bytes: &[u8]) -> Option<i128> {
+        if bytes.len() != self.element_size() {
+            return None;
+        }
+        Some(match bytes.len() {
+            4 => i128::from(i32::from_ne_bytes(bytes.try_into().ok()?)),
+            8 => i128::from(i64::from_ne_bytes(bytes.try_into().ok()?)),
+            _ => i128::from_ne_bytes(bytes.try_into().ok()?),
+        })
+    }
+
+    /// Write the mantissa of an element that is within the precision of the data type as native-endian bytes.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn write_mantissa(self, mantissa: i128, output: &mut Vec<u8>) {
+        match self.element_size() {
+            4 => output.extend_from_slice(&(mantissa as i32).to_ne_bytes()),
+            8 => output.extend_from_slice(&(mantissa as i64).to_ne_bytes()),
+            _ => output.extend_from_slice(&mantissa.to_ne_bytes()),
+        }
+    }
+}
+
+impl DataTypeTraits for DecimalDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::from(DecimalDataTypeConfigurationV1 {
+            precision: self.precision,
+            scale: self.scale,
+        })
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Fixed(self.element_size())
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        _version: ZarrVersion,
+    ) -> Result<FillValue, DataTypeFillValueMetadataError> {
+        let mantissa = match fill_value_metadata {
+            FillValueMetadata::String(string) => {
+                parse_decimal(string, self.precision.get(), self.scale)
+            }
+            FillValueMetadata::Number(number) if number.is_i64() || number.is_u64() => {
+                parse_decimal(&number.to_string(), self.precision.get(), self.scale)
+            }
+            FillValueMetadata::Null
+            | FillValueMetadata::Bool(_)
+            | FillValueMetadata::Number(_)
+            | FillValueMetadata::Array(_)
+            | FillValueMetadata::Object(_) => None,
+        }
+        .ok_or(DataTypeFillValueMetadataError)?;
+        let mut bytes = Vec::with_capacity(self.element_size());
+        self.write_mantissa(mantissa, &mut bytes);
+        Ok(FillValue::new(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadata, DataTypeFillValueError> {
+        let mantissa = self
+            .read_mantissa(fill_value.as_ne_bytes())
+            .ok_or(DataTypeFillValueError)?;
+        Ok(FillValueMetadata::from(format_decimal(
+            mantissa, self.scale,
+        )))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn compatible_element_types(&self) -> &'static [TypeId] {
+        // The unscaled mantissa
+        const I32: [TypeId; 1] = [TypeId::of::<i32>()];
+        const I64: [TypeId; 1] = [TypeId::of::<i64>()];
+        const I128: [TypeId; 1] = [TypeId::of::<i128>()];
+        match self.element_size() {
+            4 => &I32,
+            8 => &I64,
+            _ => &I128,
+        }
+    }
+}
+
+impl BytesDataTypeTraits for DecimalDataType {
+    fn encode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        let endianness = endianness.ok_or(BytesCodecEndiannessMissingError)?;
+        if endianness == Endianness::native() {
+            Ok(bytes)
+        } else {
+            let mut bytes = bytes.into_owned();
+            for element in bytes.chunks_exact_mut(self.element_size()) {
+                element.reverse();
+            }
+            Ok(Cow::Owned(bytes))
+        }
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        self.encode(bytes, endianness)
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    DecimalDataType,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypePlugin,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypeTraits
+);
+
+impl CastValueDataTypeTraits for DecimalDataType {
+    fn cast_value_is_integral(&self) -> bool {
+        false
+    }
+
+    fn cast_value_read(&self, bytes: &[u8]) -> Result<CastValueScalar, CastValueError> {
+        let mantissa = self
+            .read_mantissa(bytes)
+            .ok_or(CastValueError::InvalidElementBytes)?;
+        Ok(CastValueScalar::Decimal {
+            mantissa,
+            scale: self.scale,
+        })
+    }
+
+    fn cast_value_write(
+        &self,
+        value: CastValueScalar,
+        rounding: CastValueRoundingMode,
+        out_of_range: Option<CastValueOutOfRangeMode>,
+        output: &mut Vec<u8>,
+    ) -> Result<(), CastValueError> {
+        let mantissa = cast_scalar_to_decimal(
+            value,
+            self.precision.get(),
+            self.scale,
+            rounding,
+            out_of_range,
+        )?;
+        self.write_mantissa(mantissa, output);
+        Ok(())
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    DecimalDataType,
+    zarrs_data_type::codec_traits::cast_value::CastValueDataTypePlugin,
+    zarrs_data_type::codec_traits::cast_value::CastValueDataTypeTraits
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zarrs_plugin::ExtensionName;
+
+    fn decimal(precision: u32, scale: u32) -> DataType {
+        DataType::new(DecimalDataType::new(NonZeroU32::new(precision).unwrap(), scale).unwrap())
+    }
+
+    #[test]
+    fn from_metadata() {
+        let json = r#"{"name":"zarrs.decimal","configuration":{"precision":12,"scale":3}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        let decimal = data_type.downcast_ref::<DecimalDataType>().unwrap();
+        assert_eq!(decimal.precision().get(), 12);
+        assert_eq!(decimal.scale(), 3);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+        assert_eq!(
+            data_type.name(ZarrVersion::V3).as_deref(),
+            Some("zarrs.decimal")
+        );
+        assert_eq!(
+            Some(&data_type.configuration(ZarrVersion::V3)),
+            metadata.configuration()
+        );
+
+        let json = r#"{"name":"zarrs.decimal","configuration":{"precision":39,"scale":0}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+        let json = r#"{"name":"zarrs.decimal","configuration":{"precision":4,"scale":5}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+        assert!(DecimalDataType::new(NonZeroU32::new(38).unwrap(), 28).is_ok());
+        assert_eq!(
+            DecimalDataType::new(NonZeroU32::new(38).unwrap(), 29)
+                .unwrap_err()
+                .to_string(),
+            "decimal scale must not exceed 28, got 29"
+        );
+    }
+
+    #[test]
+    fn element_size() {
+        assert_eq!(decimal(9, 2).size(), DataTypeSize::Fixed(4));
+        assert_eq!(decimal(18, 2).size(), DataTypeSize::Fixed(8));
+        assert_eq!(decimal(38, 2).size(), DataTypeSize::Fixed(16));
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(decimal(10, 2), decimal(10, 2));
+        assert_ne!(decimal(10, 2), decimal(10, 3));
+        assert_ne!(decimal(10, 2), decimal(11, 2));
+    }
+
+    #[test]
+    fn fill_value() {
+        let data_type = decimal(6, 2);
+        let fill_value = data_type
+            .fill_value(&FillValueMetadata::from("-1234.5"), ZarrVersion::V3)
+            .unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), (-123_450_i32).to_ne_bytes());
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            FillValueMetadata::from("-1234.50")
+        );
+
+        let fill_value = data_type
+            .fill_value(&FillValueMetadata::from(7), ZarrVersion::V3)
+            .unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), 700_i32.to_ne_bytes());
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            FillValueMetadata::from("7.00")
+        );
+
+        let fill_value = data_type
+            .fill_value(&FillValueMetadata::from("0.05"), ZarrVersion::V3)
+            .unwrap();
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            FillValueMetadata::from("0.05")
+        );
+
+        // Too many fractional digits, too many digits, not a decimal
+        for fill_value in ["0.001", "10000", "1.2.3", "abc", ""] {
+            assert!(
+                data_type
+                    .fill_value(&FillValueMetadata::from(fill_value), ZarrVersion::V3)
+                    .is_err()
+            );
+        }
+        assert!(
+            data_type
+                .fill_value(&FillValueMetadata::from(0.5), ZarrVersion::V3)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn fill_value_i128() {
+        let data_type = decimal(38, 10);
+        let fill_value = data_type
+            .fill_value(
+                &FillValueMetadata::from("-9999999999999999999999999999.9999999999"),
+                ZarrVersion::V3,
+            )
+            .unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            (-(10_i128.pow(38) - 1)).to_ne_bytes()
+        );
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            FillValueMetadata::from("-9999999999999999999999999999.9999999999")
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! The `zarrs.json` data type.
+
+use zarrs_metadata::FillValueMetadata;
+
+use super::macros::register_data_type_plugin;
+
+/// The `zarrs.json` data type (experimental).
+///
+/// Each element is an arbitrary JSON value, held as its compact JSON serialisation.
+/// The fill value is any JSON value.
+///
+/// This is intended for Zarr V2 object (`|O`) arrays with non-string elements encoded with the [`numcodecs.json2`](crate::array::codec::array_to_bytes::json2) or [`numcodecs.msgpack2`](crate::array::codec::array_to_bytes::msgpack2) codecs.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonDataType;
+register_data_type_plugin!(JsonDataType);
+zarrs_plugin::impl_extension_aliases!(JsonDataType, v3: "zarrs.json");
+
+impl zarrs_data_type::DataTypeTraits for JsonDataType {
+    fn configuration(&self, _version: zarrs_plugin::ZarrVersion) -> zarrs_metadata::Configuration {
+        zarrs_metadata::Configuration::default()
+    }
+
+    fn size(&self) -> zarrs_metadata::DataTypeSize {
+        zarrs_metadata::DataTypeSize::Variable
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        _version: zarrs_plugin::ZarrVersion,
+    ) -> Result<zarrs_data_type::FillValue, zarrs_data_type::DataTypeFillValueMetadataError> {
+        let mut value = serde_json::to_value(fill_value_metadata)
+            .map_err(|_| zarrs_data_type::DataTypeFillValueMetadataError)?;
+        sort_json_object_keys(&mut value);
+        let bytes = serde_json::to_vec(&value)
+            .map_err(|_| zarrs_data_type::DataTypeFillValueMetadataError)?;
+        Ok(zarrs_data_type::FillValue::from(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &zarrs_data_type::FillValue,
+    ) -> Result<FillValueMetadata, zarrs_data_type::DataTypeFillValueError> {
+        serde_json::from_slice(fill_value.as_ne_bytes())
+            .map_err(|_| zarrs_data_type::DataTypeFillValueError)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn compatible_element_types(&self) -> &'static [std::any::TypeId] {
+        const TYPES: [std::any::TypeId; 1] = [std::any::TypeId::of::<serde_json::Value>()];
+        &TYPES
+    }
+}
+
+/// Recursively sort the keys of JSON objects.
+pub(crate) fn sort_json_object_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(values) => values.iter_mut().for_each(sort_json_object_keys),
+        serde_json::Value::Object(map) => {
+            map.values_mut().for_each(sort_json_object_keys);
+            map.sort_keys();
+        }
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => {}
+    }
+}
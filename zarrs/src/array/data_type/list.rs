@@ -0,0 +1,256 @@
+//! The `zarrs.list` data type.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zarrs_codec::CodecError;
+use zarrs_data_type::codec_traits::bytes::BytesDataTypeExt;
+
+use zarrs_data_type::{
+    DataType, DataTypeFillValueError, DataTypeFillValueMetadataError, DataTypeTraits, FillValue,
+};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, Endianness, FillValueMetadata};
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use zarrs_metadata_ext::data_type::list::ListDataTypeConfigurationV1;
+
+use super::structured::data_type_metadata_v3;
+
+/// The `zarrs.list` data type.
+///
+/// A variable-length list of items of a fixed size data type, such as ragged per-cell measurements.
+/// The items of each element are held contiguously in native-endian order.
+/// The data type is compatible with the `vlen-array` (`numcodecs.vlen-array`) codec, which stores items in the byte order of its `dtype`, and the `zarrs.vlen` codec, which stores items as little-endian.
+///
+/// [`List<T>`](crate::array::List) elements are supported where `T` is an element type of the item data type.
+/// Lists of `u8` items also support the [`Vec<u8>`] and [`&[u8]`](slice) byte string elements.
+///
+/// Fill values are an array of item fill values, which is typically empty (`[]`).
+/// A `null` Zarr V2 fill value is an empty list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListDataType {
+    data_type: DataType,
+}
+
+zarrs_plugin::impl_extension_aliases!(ListDataType, v3: "zarrs.list");
+
+impl zarrs_data_type::DataTypeTraitsV3 for ListDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        let config: ListDataTypeConfigurationV1 = metadata.to_typed_configuration()?;
+        let data_type = DataType::from_metadata(&config.data_type)?;
+        Ok(Arc::new(Self::new(data_type)?).into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<ListDataType>()
+}
+
+impl ListDataType {
+    /// Create a new list data type with items of `data_type`.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if `data_type` is not fixed size, is optional, or does not support the `bytes` codec.
+    pub fn new(data_type: DataType) -> Result<Self, PluginCreateError> {
+        if data_type.fixed_size().is_none()
+            || data_type.is_optional()
+            || data_type.codec_bytes().is_err()
+        {
+            return Err(PluginCreateError::Other(format!(
+                "list data type has unsupported item data type {data_type}"
+            )));
+        }
+        Ok(Self { data_type })
+    }
+
+    /// Returns the data type of the list items.
+    #[must_use]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the size of a list item in bytes.
+    #[must_use]
+    pub fn item_size(&self) -> usize {
+        self.data_type.fixed_size().unwrap_or_default()
+    }
+}
+
+/// Convert the native-endian items of `zarrs.list` elements to `endianness`.
+///
+/// The bytes of other variable-length data types are returned unchanged.
+pub(crate) fn list_items_encode<'a>(
+    data_type: &DataType,
+    bytes: Cow<'a, [u8]>,
+    endianness: Endianness,
+) -> Result<Cow<'a, [u8]>, CodecError> {
+    match data_type.downcast_ref::<ListDataType>() {
+        Some(list) if !endianness.is_native() => Ok(list
+            .data_type
+            .codec_bytes()?
+            .encode(bytes, Some(endianness))?),
+        _ => Ok(bytes),
+    }
+}
+
+/// Convert the items of `zarrs.list` elements in `endianness` to native-endian.
+///
+/// The bytes of other variable-length data types are returned unchanged.
+pub(crate) fn list_items_decode<'a>(
+    data_type: &DataType,
+    bytes: Cow<'a, [u8]>,
+    endianness: Endianness,
+) -> Result<Cow<'a, [u8]>, CodecError> {
+    match data_type.downcast_ref::<ListDataType>() {
+        Some(list) if !endianness.is_native() => Ok(list
+            .data_type
+            .codec_bytes()?
+            .decode(bytes, Some(endianness))?),
+        _ => Ok(bytes),
+    }
+}
+
+impl DataTypeTraits for ListDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::from(ListDataTypeConfigurationV1 {
+            data_type: data_type_metadata_v3(&self.data_type),
+        })
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Variable
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        version: ZarrVersion,
+    ) -> Result<FillValue, DataTypeFillValueMetadataError> {
+        if matches!(version, ZarrVersion::V2) && fill_value_metadata.is_null() {
+            return Ok(FillValue::new(vec![]));
+        }
+        let items = fill_value_metadata
+            .as_array()
+            .ok_or(DataTypeFillValueMetadataError)?;
+        let mut bytes = Vec::with_capacity(items.len() * self.item_size());
+        for item in items {
+            let fill_value = self.data_type.fill_value(item, version)?;
+            bytes.extend_from_slice(fill_value.as_ne_bytes());
+        }
+        Ok(FillValue::new(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadata, DataTypeFillValueError> {
+        let bytes = fill_value.as_ne_bytes();
+        let item_size = self.item_size();
+        if item_size == 0 || !bytes.len().is_multiple_of(item_size) {
+            return Err(DataTypeFillValueError);
+        }
+        let items = bytes
+            .chunks_exact(item_size)
+            .map(|item| self.data_type.metadata_fill_value(&FillValue::from(item)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(FillValueMetadata::Array(items))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn compatible_element_types(&self) -> &'static [TypeId] {
+        // Lists of `u8` items are supported by the byte string element types
+        const U8: [TypeId; 2] = [TypeId::of::<Vec<u8>>(), TypeId::of::<&[u8]>()];
+        if self
+            .data_type
+            .compatible_element_types()
+            .contains(&TypeId::of::<u8>())
+        {
+            &U8
+        } else {
+            &[]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_metadata() {
+        let json = r#"{"name":"zarrs.list","configuration":{"data_type":"int32"}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        let list = data_type.downcast_ref::<ListDataType>().unwrap();
+        assert_eq!(list.data_type(), &crate::array::data_type::int32());
+        assert_eq!(list.item_size(), 4);
+        assert_eq!(data_type.size(), DataTypeSize::Variable);
+        assert_eq!(
+            Some(&data_type.configuration(ZarrVersion::V3)),
+            metadata.configuration()
+        );
+
+        let json = r#"{"name":"zarrs.list","configuration":{"data_type":{"name":"numpy.datetime64","configuration":{"unit":"s","scale_factor":1}}}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(
+            Some(&data_type.configuration(ZarrVersion::V3)),
+            metadata.configuration()
+        );
+
+        for json in [
+            r#"{"name":"zarrs.list","configuration":{"data_type":"string"}}"#,
+            r#"{"name":"zarrs.list","configuration":{"data_type":{"name":"zarrs.optional","configuration":{"name":"int32","configuration":{}}}}}"#,
+            r#"{"name":"zarrs.list"}"#,
+        ] {
+            let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+            assert!(DataType::from_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn fill_value() {
+        let data_type = crate::array::data_type::list(crate::array::data_type::uint16()).unwrap();
+        let empty = FillValueMetadata::Array(vec![]);
+        let fill_value = data_type.fill_value(&empty, ZarrVersion::V3).unwrap();
+        assert!(fill_value.as_ne_bytes().is_empty());
+        assert_eq!(data_type.metadata_fill_value(&fill_value).unwrap(), empty);
+
+        let items = FillValueMetadata::Array(vec![1.into(), 2.into()]);
+        let fill_value = data_type.fill_value(&items, ZarrVersion::V3).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            [1u16.to_ne_bytes(), 2u16.to_ne_bytes()].concat()
+        );
+        assert_eq!(data_type.metadata_fill_value(&fill_value).unwrap(), items);
+
+        let fill_value = data_type
+            .fill_value(&FillValueMetadata::Null, ZarrVersion::V2)
+            .unwrap();
+        assert!(fill_value.as_ne_bytes().is_empty());
+        assert!(
+            data_type
+                .fill_value(&FillValueMetadata::Null, ZarrVersion::V3)
+                .is_err()
+        );
+        assert!(
+            data_type
+                .fill_value(
+                    &FillValueMetadata::Array(vec![(-1).into()]),
+                    ZarrVersion::V3
+                )
+                .is_err()
+        );
+        assert!(
+            data_type
+                .metadata_fill_value(&FillValue::new(vec![0; 3]))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! The `null_terminated_bytes` data type.
+//!
+//! See <https://github.com/zarr-developers/zarr-extensions/tree/main/data-types/null_terminated_bytes>.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use zarrs_data_type::DataType;
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, FillValueMetadata};
+use zarrs_plugin::{PluginCreateError, Regex, ZarrVersion};
+
+use zarrs_metadata_ext::data_type::null_terminated_bytes::NullTerminatedBytesDataTypeConfigurationV1;
+
+/// The `null_terminated_bytes` data type.
+///
+/// Represents fixed-length byte strings padded with trailing null bytes to fill `length_bytes`.
+/// This corresponds to the `NumPy` `bytes_` (`|S*`) data type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullTerminatedBytesDataType {
+    /// Total size of each element in bytes.
+    length_bytes: NonZeroU64,
+    /// Total size of each element in bytes, as a `usize`.
+    size: usize,
+}
+
+// NullTerminatedBytesDataType uses instance-specific V2 names based on length_bytes.
+
+impl zarrs_data_type::DataTypeTraitsV3 for NullTerminatedBytesDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        let config: NullTerminatedBytesDataTypeConfigurationV1 =
+            metadata.to_typed_configuration()?;
+        Ok(Arc::new(NullTerminatedBytesDataType::new(config.length_bytes)?).into())
+    }
+}
+
+impl zarrs_data_type::DataTypeTraitsV2 for NullTerminatedBytesDataType {
+    fn create(
+        metadata: &zarrs_metadata::v2::DataTypeMetadataV2,
+    ) -> Result<DataType, PluginCreateError> {
+        let name = match metadata {
+            zarrs_metadata::v2::DataTypeMetadataV2::Simple(name) => name.as_str(),
+            zarrs_metadata::v2::DataTypeMetadataV2::Structured(_) => {
+                return Err(PluginCreateError::Other(
+                    "null_terminated_bytes does not support structured types".into(),
+                ));
+            }
+        };
+
+        // Parse |S{N} (NumPy fixed bytes dtypes), where N is the number of bytes
+        let length_bytes = name
+            .strip_prefix("|S")
+            .and_then(|n_str| n_str.parse::<u64>().ok())
+            .and_then(NonZeroU64::new)
+            .ok_or_else(|| PluginCreateError::NameInvalid {
+                name: name.to_string(),
+            })?;
+
+        Ok(Arc::new(NullTerminatedBytesDataType::new(length_bytes)?).into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<NullTerminatedBytesDataType>()
+}
+
+// Register V2 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV2::new::<NullTerminatedBytesDataType>()
+}
+
+impl zarrs_plugin::ExtensionAliases<zarrs_plugin::ZarrVersion3> for NullTerminatedBytesDataType {
+    fn aliases() -> std::sync::RwLockReadGuard<'static, zarrs_plugin::ExtensionAliasesConfig> {
+        NULLTERMINATEDBYTESDATATYPE_ALIASES_V3.read().unwrap()
+    }
+
+    fn aliases_mut() -> std::sync::RwLockWriteGuard<'static, zarrs_plugin::ExtensionAliasesConfig> {
+        NULLTERMINATEDBYTESDATATYPE_ALIASES_V3.write().unwrap()
+    }
+}
+
+impl zarrs_plugin::ExtensionAliases<zarrs_plugin::ZarrVersion2> for NullTerminatedBytesDataType {
+    fn aliases() -> std::sync::RwLockReadGuard<'static, zarrs_plugin::ExtensionAliasesConfig> {
+        NULLTERMINATEDBYTESDATATYPE_ALIASES_V2.read().unwrap()
+    }
+
+    fn aliases_mut() -> std::sync::RwLockWriteGuard<'static, zarrs_plugin::ExtensionAliasesConfig> {
+        NULLTERMINATEDBYTESDATATYPE_ALIASES_V2.write().unwrap()
+    }
+}
+
+static NULLTERMINATEDBYTESDATATYPE_ALIASES_V3: std::sync::LazyLock<
+    std::sync::RwLock<zarrs_plugin::ExtensionAliasesConfig>,
+> = std::sync::LazyLock::new(|| {
+    std::sync::RwLock::new(zarrs_plugin::ExtensionAliasesConfig::new(
+        "null_terminated_bytes",
+        vec![],
+        vec![],
+    ))
+});
+
+static NULLTERMINATEDBYTESDATATYPE_ALIASES_V2: std::sync::LazyLock<
+    std::sync::RwLock<zarrs_plugin::ExtensionAliasesConfig>,
+> = std::sync::LazyLock::new(|| {
+    std::sync::RwLock::new(zarrs_plugin::ExtensionAliasesConfig::new(
+        "", // V2 name is instance-specific (|S12 etc)
+        vec![],
+        vec![Regex::new(r"^\|S\d+$").unwrap()],
+    ))
+});
+
+impl zarrs_plugin::ExtensionName for NullTerminatedBytesDataType {
+    fn name(&self, version: ZarrVersion) -> Option<Cow<'static, str>> {
+        Some(match version {
+            ZarrVersion::V3 => Cow::Borrowed("null_terminated_bytes"),
+            ZarrVersion::V2 => Cow::Owned(format!("|S{}", self.length_bytes)),
+        })
+    }
+}
+
+impl NullTerminatedBytesDataType {
+    /// Create a new `NullTerminatedBytesDataType` with the given size in bytes.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `length_bytes` exceeds [`usize::MAX`].
+    pub fn new(length_bytes: NonZeroU64) -> Result<Self, PluginCreateError> {
+        let size = usize::try_from(length_bytes.get()).map_err(|_| {
+            PluginCreateError::Other(format!(
+                "length_bytes {length_bytes} exceeds the maximum supported size"
+            ))
+        })?;
+        Ok(Self { length_bytes, size })
+    }
+
+    /// Returns the size of each element in bytes.
+    #[must_use]
+    pub const fn length_bytes(&self) -> NonZeroU64 {
+        self.length_bytes
+    }
+}
+
+impl zarrs_data_type::DataTypeTraits for NullTerminatedBytesDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::from(NullTerminatedBytesDataTypeConfigurationV1 {
+            length_bytes: self.length_bytes,
+        })
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Fixed(self.size)
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        version: ZarrVersion,
+    ) -> Result<zarrs_data_type::FillValue, zarrs_data_type::DataTypeFillValueMetadataError> {
+        let length_bytes = self.size;
+        let mut bytes = match fill_value_metadata {
+            // Fill value is a base64-encoded string
+            FillValueMetadata::String(s) => BASE64_STANDARD
+                .decode(s)
+                .map_err(|_| zarrs_data_type::DataTypeFillValueMetadataError)?,
+            // Zarr V2 arrays may have a null or 0 fill value, which maps to an empty byte string
+            FillValueMetadata::Null if version == ZarrVersion::V2 => vec![],
+            FillValueMetadata::Number(n) if version == ZarrVersion::V2 && n.as_u64() == Some(0) => {
+                vec![]
+            }
+            FillValueMetadata::Null
+            | FillValueMetadata::Bool(_)
+            | FillValueMetadata::Number(_)
+            | FillValueMetadata::Array(_)
+            | FillValueMetadata::Object(_) => {
+                return Err(zarrs_data_type::DataTypeFillValueMetadataError);
+            }
+        };
+        if bytes.len() > length_bytes {
+            return Err(zarrs_data_type::DataTypeFillValueMetadataError);
+        }
+        bytes.resize(length_bytes, 0);
+        Ok(zarrs_data_type::FillValue::new(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &zarrs_data_type::FillValue,
+    ) -> Result<FillValueMetadata, zarrs_data_type::DataTypeFillValueError> {
+        let bytes = fill_value.as_ne_bytes();
+        if bytes.len() != self.size {
+            return Err(zarrs_data_type::DataTypeFillValueError);
+        }
+        // Trim trailing null padding
+        let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        Ok(FillValueMetadata::from(
+            BASE64_STANDARD.encode(&bytes[..len]),
+        ))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn compatible_element_types(&self) -> &'static [TypeId] {
+        // [u8; N] and &[u8; N] require an exact length match, checked at runtime
+        const TYPES: [TypeId; 4] = [
+            TypeId::of::<&[u8]>(),
+            TypeId::of::<Vec<u8>>(),
+            TypeId::of::<&str>(),
+            TypeId::of::<String>(),
+        ];
+        &TYPES
+    }
+}
+
+zarrs_data_type::codec_traits::impl_bytes_data_type_traits!(NullTerminatedBytesDataType, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zarrs_metadata::v2::DataTypeMetadataV2;
+    use zarrs_plugin::ExtensionName;
+
+    fn data_type(length_bytes: u64) -> DataType {
+        DataType::new(
+            NullTerminatedBytesDataType::new(NonZeroU64::new(length_bytes).unwrap()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn from_metadata_v3() {
+        let json = r#"{"name":"null_terminated_bytes","configuration":{"length_bytes":16}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert!(data_type.is::<NullTerminatedBytesDataType>());
+        assert_eq!(data_type.fixed_size(), Some(16));
+        assert_eq!(
+            data_type.name_v3().unwrap().as_ref(),
+            "null_terminated_bytes"
+        );
+        assert_eq!(
+            data_type.configuration_v3(),
+            serde_json::from_str::<Configuration>(r#"{"length_bytes":16}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_metadata_v3_invalid() {
+        for json in [
+            r#"{"name":"null_terminated_bytes","configuration":{}}"#,
+            r#"{"name":"null_terminated_bytes","configuration":{"length_bytes":0}}"#,
+        ] {
+            let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+            assert!(DataType::from_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn from_metadata_v2() {
+        let metadata = DataTypeMetadataV2::Simple("|S12".to_string());
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert!(data_type.is::<NullTerminatedBytesDataType>());
+        assert_eq!(data_type.fixed_size(), Some(12));
+        assert_eq!(data_type.name(ZarrVersion::V2).unwrap().as_ref(), "|S12");
+
+        for name in ["|S0", "|S", "<S4", "|U4"] {
+            let metadata = DataTypeMetadataV2::Simple(name.to_string());
+            assert!(
+                DataType::from_metadata(&metadata)
+                    .map_or(true, |data_type| !data_type
+                        .is::<NullTerminatedBytesDataType>())
+            );
+        }
+    }
+
+    #[test]
+    fn fill_value() {
+        let dt = data_type(4);
+        let metadata = FillValueMetadata::from(BASE64_STANDARD.encode(b"ab"));
+        let fill_value = dt.fill_value_v3(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), b"ab\0\0");
+        assert_eq!(dt.metadata_fill_value(&fill_value).unwrap(), metadata);
+
+        // Interior nulls are preserved
+        let metadata = FillValueMetadata::from(BASE64_STANDARD.encode(b"a\0b"));
+        let fill_value = dt.fill_value_v3(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), b"a\0b\0");
+        assert_eq!(dt.metadata_fill_value(&fill_value).unwrap(), metadata);
+
+        // Empty
+        let metadata = FillValueMetadata::from("");
+        let fill_value = dt.fill_value_v3(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0u8; 4]);
+        assert_eq!(dt.metadata_fill_value(&fill_value).unwrap(), metadata);
+    }
+
+    #[test]
+    fn fill_value_invalid() {
+        let dt = data_type(2);
+        for metadata in [
+            FillValueMetadata::from(BASE64_STANDARD.encode(b"abc")),
+            FillValueMetadata::from("not base64!"),
+            FillValueMetadata::from(0),
+            FillValueMetadata::Null,
+        ] {
+            assert!(dt.fill_value_v3(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn fill_value_v2() {
+        let dt = data_type(2);
+        for metadata in [FillValueMetadata::from(0), FillValueMetadata::Null] {
+            let fill_value = dt.fill_value_v2(&metadata).unwrap();
+            assert_eq!(fill_value.as_ne_bytes(), [0u8; 2]);
+        }
+        assert!(dt.fill_value_v2(&FillValueMetadata::from(1)).is_err());
+    }
+}
@@ -0,0 +1,517 @@
+//! The `int*` and `uint*` arbitrary bit width integer data types.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::{Arc, LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use zarrs_data_type::codec_traits::bytes::{BytesCodecEndiannessMissingError, BytesDataTypeTraits};
+use zarrs_data_type::codec_traits::cast_value::{
+    CastValueDataTypeTraits, CastValueError, CastValueIntStored, CastValueOutOfRangeMode,
+    CastValueRepr, CastValueRoundingMode, CastValueScalar, cast_scalar_to_signed_integer,
+    cast_scalar_to_unsigned_integer,
+};
+use zarrs_data_type::codec_traits::packbits::PackBitsDataTypeTraits;
+use zarrs_data_type::{
+    DataType, DataTypeFillValueError, DataTypeFillValueMetadataError, DataTypeTraits, FillValue,
+};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, Endianness, FillValueMetadata};
+use zarrs_plugin::{
+    ExtensionAliases, ExtensionAliasesConfig, PluginCreateError, Regex, ZarrVersion, ZarrVersion2,
+    ZarrVersion3,
+};
+
+/// Bit widths with a dedicated integer data type (e.g. `int4`, `uint8`).
+const DEDICATED_BITS: [u32; 6] = [2, 4, 8, 16, 32, 64];
+
+/// An `int*` or `uint*` integer data type with an arbitrary bit width from 1 to 64.
+///
+/// Elements are stored in memory as the smallest primitive integer that holds the bit width (e.g. `u16` for `uint12`).
+/// Signed elements are sign extended.
+/// Use the `packbits` codec to pack elements to their bit width when encoded.
+///
+/// Bit widths with a dedicated data type (2, 4, 8, 16, 32, 64) are not supported by this data type.
+/// The [`int_bits`](super::int_bits) and [`uint_bits`](super::uint_bits) functions create a data type for any bit width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedIntDataType {
+    bits: u32,
+    signed: bool,
+}
+
+impl zarrs_data_type::DataTypeTraitsV3 for PackedIntDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        metadata.to_typed_configuration::<zarrs_metadata::EmptyConfiguration>()?;
+        let name = metadata.name();
+        let (bits, signed) = if let Some(bits) = name.strip_prefix("uint") {
+            (bits, false)
+        } else if let Some(bits) = name.strip_prefix("int") {
+            (bits, true)
+        } else {
+            return Err(PluginCreateError::NameInvalid {
+                name: name.to_string(),
+            });
+        };
+        let bits = bits
+            .parse::<u32>()
+            .map_err(|_| PluginCreateError::NameInvalid {
+                name: name.to_string(),
+            })?;
+        Ok(Arc::new(Self::new(bits, signed)?).into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<PackedIntDataType>()
+}
+
+// Manual implementations instead of impl_extension_aliases! because PackedIntDataType
+// needs instance-specific names based on the bit width (e.g., "int12", "uint12")
+impl ExtensionAliases<ZarrVersion3> for PackedIntDataType {
+    fn aliases() -> RwLockReadGuard<'static, ExtensionAliasesConfig> {
+        PACKEDINTDATATYPE_ALIASES_V3.read().unwrap()
+    }
+
+    fn aliases_mut() -> RwLockWriteGuard<'static, ExtensionAliasesConfig> {
+        PACKEDINTDATATYPE_ALIASES_V3.write().unwrap()
+    }
+}
+
+impl ExtensionAliases<ZarrVersion2> for PackedIntDataType {
+    fn aliases() -> RwLockReadGuard<'static, ExtensionAliasesConfig> {
+        PACKEDINTDATATYPE_ALIASES_V2.read().unwrap()
+    }
+
+    fn aliases_mut() -> RwLockWriteGuard<'static, ExtensionAliasesConfig> {
+        PACKEDINTDATATYPE_ALIASES_V2.write().unwrap()
+    }
+
+    fn matches_name(_name: &str) -> bool {
+        false
+    }
+}
+
+static PACKEDINTDATATYPE_ALIASES_V3: LazyLock<RwLock<ExtensionAliasesConfig>> =
+    LazyLock::new(|| {
+        // Match int1..int64 and uint1..uint64, excluding the bit widths with a dedicated data type
+        let bits = (1..=64u32)
+            .filter(|bits| !DEDICATED_BITS.contains(bits))
+            .map(|bits| bits.to_string())
+            .collect::<Vec<_>>()
+            .join("|");
+        RwLock::new(ExtensionAliasesConfig::new(
+            "", // V3 name is instance-specific (int12, uint12 etc)
+            vec![],
+            vec![Regex::new(&format!("^u?int({bits})$")).unwrap()],
+        ))
+    });
+
+static PACKEDINTDATATYPE_ALIASES_V2: LazyLock<RwLock<ExtensionAliasesConfig>> =
+    LazyLock::new(|| RwLock::new(ExtensionAliasesConfig::new("", vec![], vec![])));
+
+impl zarrs_plugin::ExtensionName for PackedIntDataType {
+    fn name(&self, version: ZarrVersion) -> Option<Cow<'static, str>> {
+        match version {
+            ZarrVersion::V3 => Some(Cow::Owned(if self.signed {
+                format!("int{}", self.bits)
+            } else {
+                format!("uint{}", self.bits)
+            })),
+            ZarrVersion::V2 => None,
+        }
+    }
+}
+
+impl PackedIntDataType {
+    /// Create a new `int*` (`signed`) or `uint*` data type with a bit width of `bits`.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if `bits` is not in the range 1 to 64 or has a dedicated data type.
+    pub fn new(bits: u32, signed: bool) -> Result<Self, PluginCreateError> {
+        if !(1..=64).contains(&bits) {
+            return Err(PluginCreateError::Other(format!(
+                "integer bit width must be between 1 and 64, got {bits}"
+            )));
+        }
+        if DEDICATED_BITS.contains(&bits) {
+            return Err(PluginCreateError::Other(format!(
+                "integer bit width {bits} has a dedicated data type"
+            )));
+        }
+        Ok(Self { bits, signed })
+    }
+
+    /// Returns the bit width.
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns true if the data type is signed.
+    #[must_use]
+    pub const fn signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Returns the size in bytes of the primitive integer holding an element.
+    #[must_use]
+    pub const fn element_size(&self) -> usize {
+        self.bits.next_power_of_two().div_ceil(8) as usize
+    }
+
+    /// The range of values of a signed data type.
+    fn signed_range(self) -> (i64, i64) {
+        let max = i64::MAX >> (64 - self.bits);
+        (-max - 1, max)
+    }
+
+    /// The maximum value of an unsigned data type.
+    fn unsigned_max(self) -> u64 {
+        u64::MAX >> (64 - self.bits)
+    }
+
+    /// Read a sign extended signed element.
+    fn read_signed(bytes: &[u8]) -> Option<i64> {
+        Some(match bytes.len() {
+            1 => i64::from(i8::from_ne_bytes(bytes.try_into().ok()?)),
+            2 => i64::from(i16::from_ne_bytes(bytes.try_into().ok()?)),
+            4 => i64::from(i32::from_ne_bytes(bytes.try_into().ok()?)),
+            8 => i64::from_ne_bytes(bytes.try_into().ok()?),
+            _ => return None,
+        })
+    }
+
+    /// Read an unsigned element.
+    fn read_unsigned(bytes: &[u8]) -> Option<u64> {
+        Some(match bytes.len() {
+            1 => u64::from(u8::from_ne_bytes(bytes.try_into().ok()?)),
+            2 => u64::from(u16::from_ne_bytes(bytes.try_into().ok()?)),
+            4 => u64::from(u32::from_ne_bytes(bytes.try_into().ok()?)),
+            8 => u64::from_ne_bytes(bytes.try_into().ok()?),
+            _ => return None,
+        })
+    }
+
+    /// Write a signed element that is within the range of the data type.
+    #[expect(clippy::cast_possible_truncation)]
+    fn write_signed(self, value: i64, output: &mut Vec<u8>) {
+        match self.element_size() {
+            1 => output.extend_from_slice(&(value as i8).to_ne_bytes()),
+            2 => output.extend_from_slice(&(value as i16).to_ne_bytes()),
+            4 => output.extend_from_slice(&(value as i32).to_ne_bytes()),
+            _ => output.extend_from_slice(&value.to_ne_bytes()),
+        }
+    }
+
+    /// Write an unsigned element that is within the range of the data type.
+    #[expect(clippy::cast_possible_truncation)]
+    fn write_unsigned(self, value: u64, output: &mut Vec<u8>) {
+        match self.element_size() {
+            1 => output.extend_from_slice(&(value as u8).to_ne_bytes()),
+            2 => output.extend_from_slice(&(value as u16).to_ne_bytes()),
+            4 => output.extend_from_slice(&(value as u32).to_ne_bytes()),
+            _ => output.extend_from_slice(&value.to_ne_bytes()),
+        }
+    }
+}
+
+impl DataTypeTraits for PackedIntDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::default()
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Fixed(self.element_size())
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        _version: ZarrVersion,
+    ) -> Result<FillValue, DataTypeFillValueMetadataError> {
+        let mut bytes = Vec::with_capacity(self.element_size());
+        if self.signed {
+            let int = fill_value_metadata
+                .as_i64()
+                .ok_or(DataTypeFillValueMetadataError)?;
+            let (min, max) = self.signed_range();
+            if !(min..=max).contains(&int) {
+                return Err(DataTypeFillValueMetadataError);
+            }
+            self.write_signed(int, &mut bytes);
+        } else {
+            let int = fill_value_metadata
+                .as_u64()
+                .ok_or(DataTypeFillValueMetadataError)?;
+            if int > self.unsigned_max() {
+                return Err(DataTypeFillValueMetadataError);
+            }
+            self.write_unsigned(int, &mut bytes);
+        }
+        Ok(FillValue::new(bytes))
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadata, DataTypeFillValueError> {
+        let bytes = fill_value.as_ne_bytes();
+        if bytes.len() != self.element_size() {
+            return Err(DataTypeFillValueError);
+        }
+        if self.signed {
+            Self::read_signed(bytes)
+                .map(FillValueMetadata::from)
+                .ok_or(DataTypeFillValueError)
+        } else {
+            Self::read_unsigned(bytes)
+                .map(FillValueMetadata::from)
+                .ok_or(DataTypeFillValueError)
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn eq(&self, other: &dyn DataTypeTraits) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn compatible_element_types(&self) -> &'static [TypeId] {
+        const I8: [TypeId; 1] = [TypeId::of::<i8>()];
+        const I16: [TypeId; 1] = [TypeId::of::<i16>()];
+        const I32: [TypeId; 1] = [TypeId::of::<i32>()];
+        const I64: [TypeId; 1] = [TypeId::of::<i64>()];
+        const U8: [TypeId; 1] = [TypeId::of::<u8>()];
+        const U16: [TypeId; 1] = [TypeId::of::<u16>()];
+        const U32: [TypeId; 1] = [TypeId::of::<u32>()];
+        const U64: [TypeId; 1] = [TypeId::of::<u64>()];
+        match (self.signed, self.element_size()) {
+            (true, 1) => &I8,
+            (true, 2) => &I16,
+            (true, 4) => &I32,
+            (true, _) => &I64,
+            (false, 1) => &U8,
+            (false, 2) => &U16,
+            (false, 4) => &U32,
+            (false, _) => &U64,
+        }
+    }
+}
+
+impl BytesDataTypeTraits for PackedIntDataType {
+    fn encode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        let element_size = self.element_size();
+        if element_size == 1 {
+            return Ok(bytes);
+        }
+        let endianness = endianness.ok_or(BytesCodecEndiannessMissingError)?;
+        if endianness == Endianness::native() {
+            Ok(bytes)
+        } else {
+            let mut bytes = bytes.into_owned();
+            for element in bytes.chunks_exact_mut(element_size) {
+                element.reverse();
+            }
+            Ok(Cow::Owned(bytes))
+        }
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        self.encode(bytes, endianness)
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    PackedIntDataType,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypePlugin,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypeTraits
+);
+
+impl PackBitsDataTypeTraits for PackedIntDataType {
+    fn component_size_bits(&self) -> u64 {
+        u64::from(self.bits)
+    }
+
+    fn num_components(&self) -> u64 {
+        1
+    }
+
+    fn sign_extension(&self) -> bool {
+        self.signed
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    PackedIntDataType,
+    zarrs_data_type::codec_traits::packbits::PackBitsDataTypePlugin,
+    zarrs_data_type::codec_traits::packbits::PackBitsDataTypeTraits
+);
+
+impl CastValueDataTypeTraits for PackedIntDataType {
+    fn cast_value_is_integral(&self) -> bool {
+        true
+    }
+
+    fn cast_value_repr(&self) -> Option<CastValueRepr> {
+        let stored = match (self.signed, self.element_size()) {
+            (true, 1) => CastValueIntStored::I8,
+            (true, 2) => CastValueIntStored::I16,
+            (true, 4) => CastValueIntStored::I32,
+            (true, _) => CastValueIntStored::I64,
+            (false, 1) => CastValueIntStored::U8,
+            (false, 2) => CastValueIntStored::U16,
+            (false, 4) => CastValueIntStored::U32,
+            (false, _) => CastValueIntStored::U64,
+        };
+        Some(CastValueRepr::Int {
+            bits: self.bits,
+            stored,
+        })
+    }
+
+    fn cast_value_read(&self, bytes: &[u8]) -> Result<CastValueScalar, CastValueError> {
+        if bytes.len() != self.element_size() {
+            return Err(CastValueError::InvalidElementBytes);
+        }
+        if self.signed {
+            Self::read_signed(bytes).map(|value| CastValueScalar::Signed(i128::from(value)))
+        } else {
+            Self::read_unsigned(bytes).map(|value| CastValueScalar::Unsigned(u128::from(value)))
+        }
+        .ok_or(CastValueError::InvalidElementBytes)
+    }
+
+    fn cast_value_write(
+        &self,
+        value: CastValueScalar,
+        rounding: CastValueRoundingMode,
+        out_of_range: Option<CastValueOutOfRangeMode>,
+        output: &mut Vec<u8>,
+    ) -> Result<(), CastValueError> {
+        if self.signed {
+            let value = cast_scalar_to_signed_integer(value, self.bits, rounding, out_of_range)?;
+            let value = i64::try_from(value).map_err(|_| CastValueError::NotRepresentable)?;
+            self.write_signed(value, output);
+        } else {
+            let value = cast_scalar_to_unsigned_integer(value, self.bits, rounding, out_of_range)?;
+            let value = u64::try_from(value).map_err(|_| CastValueError::NotRepresentable)?;
+            self.write_unsigned(value, output);
+        }
+        Ok(())
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    PackedIntDataType,
+    zarrs_data_type::codec_traits::cast_value::CastValueDataTypePlugin,
+    zarrs_data_type::codec_traits::cast_value::CastValueDataTypeTraits
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zarrs_plugin::ExtensionName;
+
+    #[test]
+    fn from_metadata() {
+        for (name, signed, bits, size) in [
+            ("int1", true, 1, 1),
+            ("uint3", false, 3, 1),
+            ("int12", true, 12, 2),
+            ("uint12", false, 12, 2),
+            ("int24", true, 24, 4),
+            ("uint33", false, 33, 8),
+            ("int63", true, 63, 8),
+        ] {
+            let metadata = MetadataV3::new(name);
+            let data_type = DataType::from_metadata(&metadata).unwrap();
+            let packed = data_type.downcast_ref::<PackedIntDataType>().unwrap();
+            assert_eq!(packed.bits(), bits);
+            assert_eq!(packed.signed(), signed);
+            assert_eq!(data_type.fixed_size(), Some(size));
+            assert_eq!(data_type.name(ZarrVersion::V3).unwrap(), name);
+            assert!(data_type.name(ZarrVersion::V2).is_none());
+        }
+
+        // Dedicated data types are not matched
+        let data_type = DataType::from_metadata(&MetadataV3::new("uint4")).unwrap();
+        assert!(data_type.is::<crate::array::data_type::UInt4DataType>());
+
+        for name in ["int0", "uint65", "int012", "int1x"] {
+            assert!(DataType::from_metadata(&MetadataV3::new(name)).is_err());
+        }
+        assert!(PackedIntDataType::new(8, true).is_err());
+    }
+
+    #[test]
+    fn equality() {
+        let int12 = DataType::new(PackedIntDataType::new(12, true).unwrap());
+        let uint12 = DataType::new(PackedIntDataType::new(12, false).unwrap());
+        let int13 = DataType::new(PackedIntDataType::new(13, true).unwrap());
+        assert_eq!(
+            int12,
+            DataType::new(PackedIntDataType::new(12, true).unwrap())
+        );
+        assert_ne!(int12, uint12);
+        assert_ne!(int12, int13);
+    }
+
+    #[test]
+    fn fill_value() {
+        let int12 = DataType::new(PackedIntDataType::new(12, true).unwrap());
+        for value in [-2048i16, -1, 0, 2047] {
+            let metadata = FillValueMetadata::from(value);
+            let fill_value = int12.fill_value_v3(&metadata).unwrap();
+            assert_eq!(fill_value.as_ne_bytes(), value.to_ne_bytes());
+            assert_eq!(int12.metadata_fill_value(&fill_value).unwrap(), metadata);
+        }
+        for value in [-2049, 2048] {
+            assert!(
+                int12
+                    .fill_value_v3(&FillValueMetadata::from(value))
+                    .is_err()
+            );
+        }
+
+        let uint1 = DataType::new(PackedIntDataType::new(1, false).unwrap());
+        assert_eq!(
+            uint1
+                .fill_value_v3(&FillValueMetadata::from(1))
+                .unwrap()
+                .as_ne_bytes(),
+            [1]
+        );
+        assert!(uint1.fill_value_v3(&FillValueMetadata::from(2)).is_err());
+        assert!(uint1.fill_value_v3(&FillValueMetadata::from(-1)).is_err());
+
+        let int63 = DataType::new(PackedIntDataType::new(63, true).unwrap());
+        assert!(
+            int63
+                .fill_value_v3(&FillValueMetadata::from(i64::MAX >> 1))
+                .is_ok()
+        );
+        assert!(
+            int63
+                .fill_value_v3(&FillValueMetadata::from(i64::MAX))
+                .is_err()
+        );
+        assert!(
+            int63
+                .fill_value_v3(&FillValueMetadata::from(i64::MIN))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,819 @@
+//! The `struct` data type.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use zarrs_codec::ArrayBytes;
+use zarrs_data_type::codec_traits::bytes::{
+    BytesCodecEndiannessMissingError, BytesDataTypeExt, BytesDataTypeTraits,
+};
+use zarrs_data_type::{
+    DataType, DataTypeFillValueError, DataTypeFillValueMetadataError, DataTypeTraits, FillValue,
+};
+use zarrs_metadata::v2::{
+    DataTypeMetadataV2, DataTypeMetadataV2Structured, data_type_metadata_v2_to_endianness,
+};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::{Configuration, DataTypeSize, Endianness, FillValueMetadata};
+use zarrs_metadata_ext::data_type::structured::{
+    StructDataTypeConfigurationV1, StructDataTypeFieldV1,
+};
+use zarrs_plugin::{PluginCreateError, ZarrVersion};
+
+use crate::array::ArrayError;
+
+/// A field of a [`StructDataType`].
+#[derive(Debug, Clone)]
+pub struct StructField {
+    name: String,
+    data_type: DataType,
+    shape: Vec<u64>,
+    /// The byte order of the field in Zarr V2 structured data type metadata, if known.
+    endianness_v2: Option<Endianness>,
+}
+
+impl PartialEq for StructField {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.data_type == other.data_type && self.shape == other.shape
+    }
+}
+
+impl StructField {
+    /// Create a new scalar field.
+    #[must_use]
+    pub fn new(name: impl Into<String>, data_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            shape: Vec::new(),
+            endianness_v2: None,
+        }
+    }
+
+    /// Create a new subarray field with elements of `data_type` and the given `shape`.
+    #[must_use]
+    pub fn new_subarray(name: impl Into<String>, data_type: DataType, shape: Vec<u64>) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            shape,
+            endianness_v2: None,
+        }
+    }
+
+    /// Return the field name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the data type of the field elements.
+    #[must_use]
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Return the subarray shape of the field.
+    ///
+    /// The shape is empty for a scalar field.
+    #[must_use]
+    pub fn shape(&self) -> &[u64] {
+        &self.shape
+    }
+
+    /// Return the number of elements in the field (1 for a scalar field).
+    ///
+    /// Returns [`None`] if the number of elements exceeds [`u64::MAX`].
+    #[must_use]
+    pub fn num_elements(&self) -> Option<u64> {
+        self.shape
+            .iter()
+            .try_fold(1u64, |num_elements, &dim| num_elements.checked_mul(dim))
+    }
+
+    fn element_size(&self) -> usize {
+        self.data_type
+            .fixed_size()
+            .expect("struct fields are fixed size")
+    }
+
+    /// Return the size of the field in bytes.
+    ///
+    /// Returns [`None`] if the data type of the field is not fixed size or the size of the field exceeds [`usize::MAX`].
+    #[must_use]
+    pub fn size(&self) -> Option<usize> {
+        let num_elements = usize::try_from(self.num_elements()?).ok()?;
+        self.data_type.fixed_size()?.checked_mul(num_elements)
+    }
+}
+
+/// The `struct` data type.
+///
+/// Each element is a packed record of named, fixed size fields.
+/// A field may be a scalar, a subarray, or a nested `struct`.
+/// The in-memory representation of each field matches its data type, and there is no padding between fields.
+///
+/// This data type corresponds to Zarr V2 structured `dtype`s (e.g. `[["x", "<u8"], ["y", "<f4", [2]]]`).
+///
+/// The fill value is an object mapping each field name to the fill value of the field, where a subarray field takes an array of the fill values of its elements.
+/// A base64 encoded string of the bytes of an element is also accepted, as written by `zarr-python` for Zarr V2 arrays.
+/// Each field is decoded with its byte order in the Zarr V2 data type metadata (e.g. `[["a", ">u2"]]`), or as little-endian if unknown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDataType {
+    fields: Vec<StructField>,
+    offsets: Vec<usize>,
+    field_sizes: Vec<usize>,
+    size: usize,
+}
+
+zarrs_plugin::impl_extension_aliases!(StructDataType,
+  v3: "struct", ["structured"],
+  v2: "structured_v2", []
+);
+
+impl zarrs_data_type::DataTypeTraitsV3 for StructDataType {
+    fn create(metadata: &MetadataV3) -> Result<DataType, PluginCreateError> {
+        let configuration: StructDataTypeConfigurationV1 = metadata.to_typed_configuration()?;
+        let fields = configuration
+            .fields
+            .into_iter()
+            .map(|field| {
+                let data_type = DataType::from_metadata(&field.data_type)?;
+                Ok(StructField::new_subarray(
+                    field.name,
+                    data_type,
+                    field.shape.unwrap_or_default(),
+                ))
+            })
+            .collect::<Result<Vec<_>, PluginCreateError>>()?;
+        Ok(Arc::new(StructDataType::new(fields)?).into())
+    }
+}
+
+impl zarrs_data_type::DataTypeTraitsV2 for StructDataType {
+    fn create(metadata: &DataTypeMetadataV2) -> Result<DataType, PluginCreateError> {
+        let DataTypeMetadataV2::Structured(fields) = metadata else {
+            return Err(PluginCreateError::Other(
+                "struct data type requires a structured V2 data type".to_string(),
+            ));
+        };
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let data_type = DataType::from_metadata(field.datatype())?;
+                let endianness_v2 = match field.datatype() {
+                    DataTypeMetadataV2::Simple(_) => {
+                        data_type_metadata_v2_to_endianness(field.datatype())
+                            .map_err(|err| PluginCreateError::Other(err.to_string()))?
+                    }
+                    DataTypeMetadataV2::Structured(_) => None,
+                };
+                Ok(StructField {
+                    endianness_v2,
+                    ..StructField::new_subarray(
+                        field.fieldname(),
+                        data_type,
+                        field.shape().map(<[u64]>::to_vec).unwrap_or_default(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, PluginCreateError>>()?;
+        Ok(Arc::new(StructDataType::new(fields)?).into())
+    }
+}
+
+// Register V3 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV3::new::<StructDataType>()
+}
+
+// Register V2 plugin.
+inventory::submit! {
+    zarrs_data_type::DataTypePluginV2::new::<StructDataType>()
+}
+
+impl StructDataType {
+    /// Create a new `struct` data type from its `fields`.
+    ///
+    /// # Errors
+    /// Returns a [`PluginCreateError`] if
+    ///  - there are no fields,
+    ///  - a field name is repeated,
+    ///  - a field data type is not fixed size or does not support the `bytes` codec, or
+    ///  - the size of the struct exceeds [`usize::MAX`].
+    pub fn new(fields: Vec<StructField>) -> Result<Self, PluginCreateError> {
+        if fields.is_empty() {
+            return Err(PluginCreateError::Other(
+                "struct data type must have at least one field".to_string(),
+            ));
+        }
+        let mut names = HashSet::with_capacity(fields.len());
+        let mut offsets = Vec::with_capacity(fields.len());
+        let mut field_sizes = Vec::with_capacity(fields.len());
+        let mut size = 0usize;
+        for field in &fields {
+            if !names.insert(field.name.as_str()) {
+                return Err(PluginCreateError::Other(format!(
+                    "struct data type field name {} is repeated",
+                    field.name
+                )));
+            }
+            if field.data_type.fixed_size().is_none()
+                || field.data_type.is_optional()
+                || field.data_type.codec_bytes().is_err()
+            {
+                return Err(PluginCreateError::Other(format!(
+                    "struct data type field {} has unsupported data type {}",
+                    field.name, field.data_type
+                )));
+            }
+            let too_large = || {
+                PluginCreateError::Other(format!(
+                    "struct data type field {} is too large",
+                    field.name
+                ))
+            };
+            let field_size = field.size().ok_or_else(too_large)?;
+            offsets.push(size);
+            field_sizes.push(field_size);
+            size = size.checked_add(field_size).ok_or_else(too_large)?;
+        }
+        Ok(Self {
+            fields,
+            offsets,
+            field_sizes,
+            size,
+        })
+    }
+
+    /// Return the fields.
+    #[must_use]
+    pub fn fields(&self) -> &[StructField] {
+        &self.fields
+    }
+
+    /// Return the field with `name`, if it exists.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&StructField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Return the byte offset of the field with `name` within an element, if it exists.
+    #[must_use]
+    pub fn field_offset(&self, name: &str) -> Option<usize> {
+        self.field_index(name).map(|index| self.offsets[index])
+    }
+
+    fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+
+    /// Return the fill value of the field with `name` from the fill value of the struct.
+    ///
+    /// The fill value of a subarray field is that of its elements if they are all equal, otherwise [`None`] is returned.
+    #[must_use]
+    pub fn field_fill_value(&self, fill_value: &FillValue, name: &str) -> Option<FillValue> {
+        let bytes = fill_value.as_ne_bytes();
+        if bytes.len() != self.size {
+            return None;
+        }
+        let index = self.field_index(name)?;
+        let field = &self.fields[index];
+        let offset = self.offsets[index];
+        let field_bytes = &bytes[offset..offset + self.field_sizes[index]];
+        let mut elements = field_bytes.chunks_exact(field.element_size());
+        let first = elements.next()?;
+        elements
+            .all(|element| element == first)
+            .then(|| FillValue::from(first))
+    }
+
+    /// Extract the bytes of the field with `name` from the [`ArrayBytes`] of a struct array.
+    ///
+    /// The elements of a subarray field are in C order following each struct element.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if the field does not exist or `bytes` are not a whole number of struct elements.
+    pub fn field_array_bytes(
+        &self,
+        bytes: &ArrayBytes<'_>,
+        name: &str,
+    ) -> Result<ArrayBytes<'static>, ArrayError> {
+        let Some(index) = self.field_index(name) else {
+            return Err(ArrayError::Other(format!(
+                "struct data type has no field {name}"
+            )));
+        };
+        let offset = self.offsets[index];
+        let ArrayBytes::Fixed(bytes) = bytes else {
+            return Err(ArrayError::Other(
+                "struct array bytes must be fixed length".to_string(),
+            ));
+        };
+        if !bytes.len().is_multiple_of(self.size) {
+            return Err(ArrayError::Other(format!(
+                "struct array bytes length {} is not a multiple of the element size {}",
+                bytes.len(),
+                self.size
+            )));
+        }
+        let field_size = self.field_sizes[index];
+        let field_bytes = bytes
+            .chunks_exact(self.size)
+            .flat_map(|element| &element[offset..offset + field_size])
+            .copied()
+            .collect::<Vec<u8>>();
+        Ok(ArrayBytes::new_flen(field_bytes))
+    }
+
+    /// Return the Zarr V2 structured data type metadata.
+    ///
+    /// Returns [`None`] if a field data type has no Zarr V2 representation.
+    #[must_use]
+    pub fn metadata_v2(&self) -> Option<DataTypeMetadataV2> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let datatype = if let Some(struct_data_type) =
+                    field.data_type.downcast_ref::<StructDataType>()
+                {
+                    struct_data_type.metadata_v2()?
+                } else {
+                    DataTypeMetadataV2::Simple(field.data_type.name_v2()?.into_owned())
+                };
+                let shape = (!field.shape.is_empty()).then(|| field.shape.clone());
+                Some(DataTypeMetadataV2Structured::new(
+                    field.name.clone(),
+                    datatype,
+                    shape,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(DataTypeMetadataV2::Structured)
+    }
+
+    /// Decode the bytes of elements with the byte order of each field in the Zarr V2 data type metadata.
+    fn decode_v2(&self, bytes: &[u8]) -> Result<Vec<u8>, BytesCodecEndiannessMissingError> {
+        let bytes = self.map_fields(bytes, |field, field_bytes| {
+            if let Some(struct_data_type) = field.data_type.downcast_ref::<StructDataType>() {
+                struct_data_type.decode_v2(&field_bytes)
+            } else {
+                Ok(field
+                    .data_type
+                    .codec_bytes()
+                    .expect("struct fields support the bytes codec")
+                    .decode(
+                        field_bytes,
+                        Some(field.endianness_v2.unwrap_or(Endianness::Little)),
+                    )?
+                    .into_owned())
+            }
+        })?;
+        Ok(bytes.into_owned())
+    }
+
+    /// Apply `f` to the bytes of each field across all elements of `bytes`.
+    fn map_fields<'a>(
+        &self,
+        bytes: &[u8],
+        f: impl Fn(&StructField, Cow<'_, [u8]>) -> Result<Vec<u8>, BytesCodecEndiannessMissingError>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        let num_elements = bytes.len() / self.size;
+        let mut bytes_out = vec![0; bytes.len()];
+        for ((field, &offset), &field_size) in
+            self.fields.iter().zip(&self.offsets).zip(&self.field_sizes)
+        {
+            let field_bytes = bytes
+                .chunks_exact(self.size)
+                .flat_map(|element| &element[offset..offset + field_size])
+                .copied()
+                .collect::<Vec<u8>>();
+            let field_bytes = f(field, Cow::Owned(field_bytes))?;
+            debug_assert_eq!(field_bytes.len(), num_elements * field_size);
+            for (element_out, field_bytes) in bytes_out
+                .chunks_exact_mut(self.size)
+                .zip(field_bytes.chunks_exact(field_size))
+            {
+                element_out[offset..offset + field_size].copy_from_slice(field_bytes);
+            }
+        }
+        Ok(Cow::Owned(bytes_out))
+    }
+}
+
+/// Create the V3 metadata of a data type.
+pub(super) fn data_type_metadata_v3(data_type: &DataType) -> MetadataV3 {
+    let name = data_type
+        .name_v3()
+        .map_or_else(String::new, Cow::into_owned);
+    let configuration = data_type.configuration_v3();
+    if configuration.is_empty() {
+        MetadataV3::new(name)
+    } else {
+        MetadataV3::new_with_configuration(name, configuration)
+    }
+}
+
+impl DataTypeTraits for StructDataType {
+    fn configuration(&self, _version: ZarrVersion) -> Configuration {
+        Configuration::from(StructDataTypeConfigurationV1 {
+            fields: self
+                .fields
+                .iter()
+                .map(|field| StructDataTypeFieldV1 {
+                    name: field.name.clone(),
+                    data_type: data_type_metadata_v3(&field.data_type),
+                    shape: (!field.shape.is_empty()).then(|| field.shape.clone()),
+                })
+                .collect(),
+        })
+    }
+
+    fn size(&self) -> DataTypeSize {
+        DataTypeSize::Fixed(self.size)
+    }
+
+    fn fill_value(
+        &self,
+        fill_value_metadata: &FillValueMetadata,
+        version: ZarrVersion,
+    ) -> Result<FillValue, DataTypeFillValueMetadataError> {
+        use base64::Engine;
+        use base64::prelude::BASE64_STANDARD;
+
+        // V2: null or 0 fill value means all zero bytes
+        if matches!(version, ZarrVersion::V2)
+            && (fill_value_metadata.is_null() || fill_value_metadata.as_u64() == Some(0))
+        {
+            return Ok(FillValue::new(vec![0; self.size]));
+        }
+
+        if let Some(string) = fill_value_metadata.as_str() {
+            let bytes = BASE64_STANDARD
+                .decode(string)
+                .map_err(|_| DataTypeFillValueMetadataError)?;
+            if bytes.len() != self.size {
+                return Err(DataTypeFillValueMetadataError);
+            }
+            let bytes = self
+                .decode_v2(&bytes)
+                .map_err(|_| DataTypeFillValueMetadataError)?;
+            return Ok(FillValue::new(bytes));
+        }
+
+        let object: HashMap<String, FillValueMetadata> = fill_value_metadata
+            .as_object()
+            .ok_or(DataTypeFillValueMetadataError)?;
+        if object.len() != self.fields.len() {
+            return Err(DataTypeFillValueMetadataError);
+        }
+        let mut bytes = Vec::with_capacity(self.size);
+        for (field, &field_size) in self.fields.iter().zip(&self.field_sizes) {
+            let field_metadata = object
+                .get(&field.name)
+                .ok_or(DataTypeFillValueMetadataError)?;
+            if field.shape.is_empty() {
+                let fill_value = field.data_type.fill_value(field_metadata, version)?;
+                bytes.extend_from_slice(fill_value.as_ne_bytes());
+            } else {
+                let elements = field_metadata
+                    .as_array()
+                    .ok_or(DataTypeFillValueMetadataError)?;
+                if elements.len() * field.element_size() != field_size {
+                    return Err(DataTypeFillValueMetadataError);
+                }
+                for element in elements {
+                    let fill_value = field.data_type.fill_value(element, version)?;
+                    bytes.extend_from_slice(fill_value.as_ne_bytes());
+                }
+            }
+        }
+        if bytes.len() == self.size {
+            Ok(FillValue::new(bytes))
+        } else {
+            Err(DataTypeFillValueMetadataError)
+        }
+    }
+
+    fn metadata_fill_value(
+        &self,
+        fill_value: &FillValue,
+    ) -> Result<FillValueMetadata, DataTypeFillValueError> {
+        let bytes = fill_value.as_ne_bytes();
+        if bytes.len() != self.size {
+            return Err(DataTypeFillValueError);
+        }
+        let mut object = HashMap::with_capacity(self.fields.len());
+        for ((field, &offset), &field_size) in
+            self.fields.iter().zip(&self.offsets).zip(&self.field_sizes)
+        {
+            let field_bytes = &bytes[offset..offset + field_size];
+            let mut elements = field_bytes
+                .chunks_exact(field.element_size())
+                .map(|element| {
+                    field
+                        .data_type
+                        .metadata_fill_value(&FillValue::from(element))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let field_metadata = if field.shape.is_empty() {
+                elements.remove(0)
+            } else {
+                FillValueMetadata::Array(elements)
+            };
+            object.insert(field.name.clone(), field_metadata);
+        }
+        Ok(FillValueMetadata::Object(object))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl BytesDataTypeTraits for StructDataType {
+    fn encode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        self.map_fields(&bytes, |field, field_bytes| {
+            Ok(field
+                .data_type
+                .codec_bytes()
+                .expect("struct fields support the bytes codec")
+                .encode(field_bytes, endianness)?
+                .into_owned())
+        })
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: Cow<'a, [u8]>,
+        endianness: Option<Endianness>,
+    ) -> Result<Cow<'a, [u8]>, BytesCodecEndiannessMissingError> {
+        self.map_fields(&bytes, |field, field_bytes| {
+            Ok(field
+                .data_type
+                .codec_bytes()
+                .expect("struct fields support the bytes codec")
+                .decode(field_bytes, endianness)?
+                .into_owned())
+        })
+    }
+}
+
+zarrs_data_type::register_data_type_extension_codec!(
+    StructDataType,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypePlugin,
+    zarrs_data_type::codec_traits::bytes::BytesDataTypeTraits
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::data_type;
+
+    fn point_data_type() -> DataType {
+        data_type::structured(vec![
+            StructField::new("x", data_type::uint16()),
+            StructField::new_subarray("y", data_type::float32(), vec![2]),
+            StructField::new("z", data_type::bool()),
+        ])
+        .unwrap()
+    }
+
+    fn point_element(x: u16, y: [f32; 2], z: bool) -> Vec<u8> {
+        let mut bytes = x.to_ne_bytes().to_vec();
+        bytes.extend_from_slice(&y[0].to_ne_bytes());
+        bytes.extend_from_slice(&y[1].to_ne_bytes());
+        bytes.push(u8::from(z));
+        bytes
+    }
+
+    #[test]
+    fn struct_metadata_v3() {
+        let json = r#"{"name":"struct","configuration":{"fields":[{"name":"x","data_type":"uint16"},{"name":"y","data_type":"float32","shape":[2]},{"name":"z","data_type":"bool"}]}}"#;
+        let metadata: MetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(data_type, point_data_type());
+        assert_eq!(data_type.fixed_size(), Some(11));
+        assert_eq!(
+            serde_json::to_string(&data_type_metadata_v3(&data_type)).unwrap(),
+            json
+        );
+
+        let struct_data_type = data_type.downcast_ref::<StructDataType>().unwrap();
+        assert_eq!(struct_data_type.fields().len(), 3);
+        assert_eq!(struct_data_type.field("y").unwrap().shape(), &[2]);
+        assert_eq!(struct_data_type.field_offset("z"), Some(10));
+        assert!(struct_data_type.field("w").is_none());
+    }
+
+    #[test]
+    fn struct_metadata_v2() {
+        let json = r#"[["x","<u2"],["y","<f4",[2]],["inner",[["a","|i1"],["b","<i8"]]]]"#;
+        let metadata: DataTypeMetadataV2 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(data_type.fixed_size(), Some(2 + 8 + 9));
+        let struct_data_type = data_type.downcast_ref::<StructDataType>().unwrap();
+        let inner = struct_data_type.field("inner").unwrap().data_type();
+        assert!(inner.is::<StructDataType>());
+        assert_eq!(
+            serde_json::to_string(&struct_data_type.metadata_v2().unwrap()).unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn struct_invalid() {
+        assert!(StructDataType::new(vec![]).is_err());
+        assert!(
+            StructDataType::new(vec![
+                StructField::new("x", data_type::uint8()),
+                StructField::new("x", data_type::uint8()),
+            ])
+            .is_err()
+        );
+        assert!(StructDataType::new(vec![StructField::new("s", data_type::string())]).is_err());
+        assert!(
+            StructDataType::new(vec![StructField::new(
+                "o",
+                data_type::optional(data_type::uint8())
+            )])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn struct_fill_value() {
+        let data_type = point_data_type();
+        let metadata: FillValueMetadata =
+            serde_json::from_str(r#"{"x":7,"y":[1.5,"NaN"],"z":true}"#).unwrap();
+        let fill_value = data_type.fill_value_v3(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            point_element(7, [1.5, f32::NAN], true)
+        );
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value).unwrap(),
+            metadata
+        );
+
+        let struct_data_type = data_type.downcast_ref::<StructDataType>().unwrap();
+        assert_eq!(
+            struct_data_type.field_fill_value(&fill_value, "x"),
+            Some(FillValue::from(7u16))
+        );
+        assert_eq!(struct_data_type.field_fill_value(&fill_value, "y"), None);
+
+        // Missing, unknown, and mistyped fields
+        for json in [
+            r#"{"x":7,"y":[1.5,2.5]}"#,
+            r#"{"x":7,"y":[1.5,2.5],"z":true,"w":1}"#,
+            r#"{"x":7,"y":1.5,"z":true}"#,
+            r#"{"x":7,"y":[1.5],"z":true}"#,
+            r#"{"x":-1,"y":[1.5,2.5],"z":true}"#,
+        ] {
+            let metadata: FillValueMetadata = serde_json::from_str(json).unwrap();
+            assert!(data_type.fill_value_v3(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn struct_fill_value_base64() {
+        use base64::Engine;
+        use base64::prelude::BASE64_STANDARD;
+
+        let data_type = point_data_type();
+        let mut bytes = 7u16.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&1.5f32.to_le_bytes());
+        bytes.extend_from_slice(&2.5f32.to_le_bytes());
+        bytes.push(1);
+        let metadata = FillValueMetadata::from(BASE64_STANDARD.encode(bytes));
+        let fill_value = data_type.fill_value_v2(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), point_element(7, [1.5, 2.5], true));
+
+        let fill_value = data_type.fill_value_v2(&FillValueMetadata::Null).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0; 11]);
+        assert!(data_type.fill_value_v3(&FillValueMetadata::Null).is_err());
+    }
+
+    #[test]
+    fn struct_fill_value_base64_big_endian() {
+        use base64::Engine;
+        use base64::prelude::BASE64_STANDARD;
+
+        let dtype: DataTypeMetadataV2 =
+            serde_json::from_str(r#"[["a",">u2"],["b",[["c",">f4",[2]]]],["d","|u1"]]"#).unwrap();
+        let data_type = DataType::from_metadata(&dtype).unwrap();
+        let mut bytes = 258u16.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&1.5f32.to_be_bytes());
+        bytes.extend_from_slice(&(-2.0f32).to_be_bytes());
+        bytes.push(3);
+        let fill_value_metadata = FillValueMetadata::from(BASE64_STANDARD.encode(bytes));
+
+        let mut expected = 258u16.to_ne_bytes().to_vec();
+        expected.extend_from_slice(&1.5f32.to_ne_bytes());
+        expected.extend_from_slice(&(-2.0f32).to_ne_bytes());
+        expected.push(3);
+        let fill_value = data_type.fill_value_v2(&fill_value_metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), expected);
+
+        // The byte order is also respected when converting the metadata to Zarr V3
+        let metadata_v2: zarrs_metadata::v2::ArrayMetadataV2 =
+            serde_json::from_value(serde_json::json!({
+                "zarr_format": 2,
+                "shape": [4],
+                "chunks": [4],
+                "dtype": dtype,
+                "compressor": null,
+                "fill_value": fill_value_metadata,
+                "order": "C",
+                "filters": null,
+            }))
+            .unwrap();
+        let metadata_v3 = crate::convert::array_metadata_v2_to_v3(&metadata_v2).unwrap();
+        assert_eq!(
+            serde_json::to_value(&metadata_v3.fill_value).unwrap(),
+            serde_json::json!({"a": 258, "b": {"c": [1.5, -2.0]}, "d": 3})
+        );
+    }
+
+    #[test]
+    fn struct_too_large() {
+        assert_eq!(
+            StructField::new_subarray("x", data_type::uint8(), vec![u64::MAX, 2]).num_elements(),
+            None
+        );
+        assert!(
+            StructDataType::new(vec![StructField::new_subarray(
+                "x",
+                data_type::uint64(),
+                vec![u64::MAX]
+            )])
+            .is_err()
+        );
+        let half = u64::try_from(usize::MAX / 2 + 1).unwrap();
+        assert!(
+            StructDataType::new(vec![
+                StructField::new_subarray("x", data_type::uint8(), vec![half]),
+                StructField::new_subarray("y", data_type::uint8(), vec![half]),
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn struct_bytes_codec() {
+        let data_type = point_data_type();
+        let struct_data_type = data_type.downcast_ref::<StructDataType>().unwrap();
+        let mut bytes = point_element(1, [2.0, 3.0], false);
+        bytes.extend(point_element(4, [5.0, 6.0], true));
+
+        let mut expected = 1u16.to_be_bytes().to_vec();
+        expected.extend_from_slice(&2.0f32.to_be_bytes());
+        expected.extend_from_slice(&3.0f32.to_be_bytes());
+        expected.push(0);
+        expected.extend_from_slice(&4u16.to_be_bytes());
+        expected.extend_from_slice(&5.0f32.to_be_bytes());
+        expected.extend_from_slice(&6.0f32.to_be_bytes());
+        expected.push(1);
+
+        let encoded = struct_data_type
+            .encode(Cow::Borrowed(&bytes), Some(Endianness::Big))
+            .unwrap();
+        assert_eq!(encoded.as_ref(), expected);
+        let decoded = struct_data_type
+            .decode(encoded, Some(Endianness::Big))
+            .unwrap();
+        assert_eq!(decoded.as_ref(), bytes);
+        assert!(
+            struct_data_type
+                .encode(Cow::Borrowed(&bytes), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn struct_field_array_bytes() {
+        let data_type = point_data_type();
+        let struct_data_type = data_type.downcast_ref::<StructDataType>().unwrap();
+        let mut bytes = point_element(1, [2.0, 3.0], false);
+        bytes.extend(point_element(4, [5.0, 6.0], true));
+        let bytes = ArrayBytes::new_flen(bytes);
+
+        let x = struct_data_type.field_array_bytes(&bytes, "x").unwrap();
+        assert_eq!(
+            x.into_fixed().unwrap().as_ref(),
+            [1u16, 4].map(u16::to_ne_bytes).as_flattened()
+        );
+        let y = struct_data_type.field_array_bytes(&bytes, "y").unwrap();
+        assert_eq!(
+            y.into_fixed().unwrap().as_ref(),
+            [2.0f32, 3.0, 5.0, 6.0].map(f32::to_ne_bytes).as_flattened()
+        );
+        assert!(struct_data_type.field_array_bytes(&bytes, "w").is_err());
+    }
+}
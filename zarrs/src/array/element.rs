@@ -2,15 +2,29 @@ use super::{ArrayBytes, DataType};
 
 mod bool;
 mod bytes;
+mod categorical;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
 mod error;
 mod fixed_length_utf32;
+mod json;
+mod list;
+mod null_terminated_bytes;
 mod numpy;
 mod optional;
 mod pod;
 mod raw_bits;
 mod string;
+mod struct_field;
 
 pub use error::ElementError;
+pub use list::List;
+pub use struct_field::ElementStructField;
+#[doc(hidden)]
+pub use struct_field::{
+    struct_element_from_array_bytes, struct_element_to_array_bytes,
+    struct_element_validate_data_type,
+};
 
 /// A trait representing an array element type.
 pub trait Element: Sized + Clone {
@@ -4,6 +4,9 @@ use itertools::Itertools;
 
 use crate::array::{ArrayBytes, ArrayBytesOffsets, DataType};
 
+use super::null_terminated_bytes::{
+    decode_null_terminated_bytes, encode_null_terminated_bytes, null_terminated_bytes_length,
+};
 use super::{Element, ElementError, ElementOwned};
 
 use ElementError::IncompatibleElementType as IET;
@@ -25,6 +28,9 @@ macro_rules! impl_element_bytes {
                 elements: &'a [Self],
             ) -> Result<ArrayBytes<'a>, ElementError> {
                 Self::validate_data_type(data_type)?;
+                if let Some(length_bytes) = null_terminated_bytes_length(data_type) {
+                    return encode_null_terminated_bytes(elements, length_bytes);
+                }
 
                 // Calculate offsets
                 let mut len: usize = 0;
@@ -68,6 +74,9 @@ impl ElementOwned for Vec<u8> {
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ElementError> {
         Self::validate_data_type(data_type)?;
+        if let Some(length_bytes) = null_terminated_bytes_length(data_type) {
+            return decode_null_terminated_bytes(bytes, length_bytes);
+        }
         let (bytes, offsets) = bytes.into_variable()?.into_parts();
         let mut elements = Vec::with_capacity(offsets.len().saturating_sub(1));
         for (curr, next) in offsets.iter().tuple_windows() {
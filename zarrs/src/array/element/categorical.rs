@@ -0,0 +1,101 @@
+//! Helpers for string elements of the `zarrs.categorical` data type.
+//!
+//! Strings are encoded to the code of their category, and codes are decoded to their category.
+
+use crate::array::{ArrayBytes, DataType, data_type};
+
+use super::ElementError;
+
+/// Returns the categorical data type if `data_type` is a `zarrs.categorical` data type.
+pub(super) fn categorical(data_type: &DataType) -> Option<&data_type::CategoricalDataType> {
+    data_type.downcast_ref::<data_type::CategoricalDataType>()
+}
+
+/// Encode strings to category codes.
+///
+/// Returns [`ElementError::InvalidElementValue`] if an element is not in the category table.
+pub(super) fn encode_categorical<T: AsRef<str>>(
+    elements: &[T],
+    categorical: &data_type::CategoricalDataType,
+) -> Result<ArrayBytes<'static>, ElementError> {
+    let mut bytes = Vec::with_capacity(elements.len() * categorical.code_data_type().size());
+    for element in elements {
+        let code = categorical
+            .code(element.as_ref())
+            .ok_or(ElementError::InvalidElementValue)?;
+        categorical.write_code(code, &mut bytes);
+    }
+    Ok(ArrayBytes::new_flen(bytes))
+}
+
+/// Decode category codes to strings.
+///
+/// Returns [`ElementError::InvalidElementValue`] if a code is not in the category table.
+pub(super) fn decode_categorical(
+    bytes: ArrayBytes<'_>,
+    categorical: &data_type::CategoricalDataType,
+) -> Result<Vec<String>, ElementError> {
+    let bytes = bytes.into_fixed()?;
+    let code_size = categorical.code_data_type().size();
+    if !bytes.len().is_multiple_of(code_size) {
+        return Err(ElementError::Other(
+            "byte length is not a multiple of element size".into(),
+        ));
+    }
+    bytes
+        .chunks_exact(code_size)
+        .map(|code| {
+            categorical
+                .read_code(code)
+                .and_then(|code| categorical.category(code))
+                .map(ToString::to_string)
+                .ok_or(ElementError::InvalidElementValue)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Element, ElementOwned};
+
+    use super::*;
+
+    fn make_data_type(categories: &[&str]) -> DataType {
+        data_type::categorical(categories.iter().map(ToString::to_string).collect()).unwrap()
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let data_type = make_data_type(&["red", "green", "blue"]);
+        let elements = ["blue", "red", "red", "green"];
+        let bytes = <&str>::to_array_bytes(&data_type, &elements).unwrap();
+        assert_eq!(bytes.clone().into_fixed().unwrap().as_ref(), [2, 0, 0, 1]);
+        let decoded = String::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(decoded, elements);
+    }
+
+    #[test]
+    fn codes() {
+        let data_type = make_data_type(&["a", "b"]);
+        let bytes = u8::to_array_bytes(&data_type, &[1, 0]).unwrap();
+        assert_eq!(
+            String::from_array_bytes(&data_type, bytes).unwrap(),
+            ["b", "a"]
+        );
+        assert!(u16::validate_data_type(&data_type).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        let data_type = make_data_type(&["a", "b"]);
+        assert!(matches!(
+            String::into_array_bytes(&data_type, vec!["c".to_string()]),
+            Err(ElementError::InvalidElementValue)
+        ));
+        let bytes = u8::to_array_bytes(&data_type, &[2]).unwrap();
+        assert!(matches!(
+            String::from_array_bytes(&data_type, bytes),
+            Err(ElementError::InvalidElementValue)
+        ));
+    }
+}
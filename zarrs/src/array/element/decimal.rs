@@ -0,0 +1,137 @@
+//! Element implementations for the `zarrs.decimal` data type.
+
+use rust_decimal::Decimal;
+
+use crate::array::data_type::DecimalDataType;
+use crate::array::{ArrayBytes, DataType};
+
+use super::{Element, ElementError, ElementOwned};
+
+/// Get the decimal data type.
+fn get_decimal(data_type: &DataType) -> Result<DecimalDataType, ElementError> {
+    data_type
+        .downcast_ref::<DecimalDataType>()
+        .copied()
+        .ok_or(ElementError::IncompatibleElementType)
+}
+
+/// Convert a [`Decimal`] to the mantissa of a decimal data type element.
+///
+/// Returns [`ElementError::InvalidElementValue`] if the value has more fractional digits than the scale or exceeds the precision.
+fn decimal_to_mantissa(data_type: DecimalDataType, value: &Decimal) -> Result<i128, ElementError> {
+    let mantissa = value.mantissa();
+    let scale = data_type.scale();
+    let mantissa = if scale >= value.scale() {
+        10_i128
+            .checked_pow(scale - value.scale())
+            .and_then(|factor| mantissa.checked_mul(factor))
+    } else {
+        let divisor = 10_i128.pow(value.scale() - scale);
+        (mantissa % divisor == 0).then_some(mantissa / divisor)
+    };
+    mantissa
+        .filter(|mantissa| mantissa.unsigned_abs() < 10_u128.pow(data_type.precision().get()))
+        .ok_or(ElementError::InvalidElementValue)
+}
+
+impl Element for Decimal {
+    fn validate_data_type(data_type: &DataType) -> Result<(), ElementError> {
+        get_decimal(data_type).map(|_| ())
+    }
+
+    fn to_array_bytes<'a>(
+        data_type: &DataType,
+        elements: &'a [Self],
+    ) -> Result<ArrayBytes<'a>, ElementError> {
+        let decimal = get_decimal(data_type)?;
+        let mut bytes = Vec::with_capacity(elements.len() * decimal.element_size());
+        for element in elements {
+            decimal.write_mantissa(decimal_to_mantissa(decimal, element)?, &mut bytes);
+        }
+        Ok(bytes.into())
+    }
+
+    fn into_array_bytes(
+        data_type: &DataType,
+        elements: Vec<Self>,
+    ) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok(Self::to_array_bytes(data_type, &elements)?.into_owned())
+    }
+}
+
+impl ElementOwned for Decimal {
+    fn from_array_bytes(
+        data_type: &DataType,
+        bytes: ArrayBytes<'_>,
+    ) -> Result<Vec<Self>, ElementError> {
+        let decimal = get_decimal(data_type)?;
+        let bytes = bytes.into_fixed()?;
+        let element_size = decimal.element_size();
+        if !bytes.len().is_multiple_of(element_size) {
+            return Err(ElementError::Other(
+                "byte length is not a multiple of element size".into(),
+            ));
+        }
+        bytes
+            .chunks_exact(element_size)
+            .map(|element| {
+                let mantissa = decimal
+                    .read_mantissa(element)
+                    .ok_or(ElementError::InvalidElementValue)?;
+                Self::try_from_i128_with_scale(mantissa, decimal.scale())
+                    .map_err(|err| ElementError::Other(err.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+    use std::str::FromStr;
+
+    use crate::array::data_type;
+
+    use super::*;
+
+    fn decimal(precision: u32, scale: u32) -> DataType {
+        data_type::decimal(NonZeroU32::new(precision).unwrap(), scale).unwrap()
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        for data_type in [decimal(6, 2), decimal(18, 2), decimal(28, 2)] {
+            let elements = ["1234.5", "-0.01", "0", "9999.99"]
+                .map(|value| Decimal::from_str(value).unwrap())
+                .to_vec();
+            let bytes = Decimal::to_array_bytes(&data_type, &elements).unwrap();
+            let decoded = Decimal::from_array_bytes(&data_type, bytes).unwrap();
+            assert_eq!(decoded, elements);
+            assert_eq!(decoded[0].to_string(), "1234.50");
+        }
+    }
+
+    #[test]
+    fn decimal_mantissa() {
+        let data_type = decimal(6, 2);
+        let elements = [Decimal::from_str("-12.5").unwrap()];
+        let bytes = Decimal::to_array_bytes(&data_type, &elements).unwrap();
+        assert_eq!(
+            i32::from_array_bytes(&data_type, bytes).unwrap(),
+            vec![-1250]
+        );
+        assert!(i64::validate_data_type(&data_type).is_err());
+    }
+
+    #[test]
+    fn decimal_invalid() {
+        let data_type = decimal(6, 2);
+        for value in ["0.001", "10000", "-10000"] {
+            assert!(matches!(
+                Decimal::to_array_bytes(&data_type, &[Decimal::from_str(value).unwrap()]),
+                Err(ElementError::InvalidElementValue)
+            ));
+        }
+        assert!(Decimal::validate_data_type(&data_type::int32()).is_err());
+    }
+}
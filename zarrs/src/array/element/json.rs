@@ -0,0 +1,69 @@
+use std::any::TypeId;
+
+use itertools::Itertools;
+
+use crate::array::{ArrayBytes, ArrayBytesOffsets, DataType};
+
+use super::{Element, ElementError, ElementOwned};
+
+use ElementError::IncompatibleElementType as IET;
+
+impl Element for serde_json::Value {
+    fn validate_data_type(data_type: &DataType) -> Result<(), ElementError> {
+        data_type
+            .compatible_element_types()
+            .contains(&TypeId::of::<Self>())
+            .then_some(())
+            .ok_or(IET)
+    }
+
+    fn to_array_bytes<'a>(
+        data_type: &DataType,
+        elements: &'a [Self],
+    ) -> Result<ArrayBytes<'a>, ElementError> {
+        Self::validate_data_type(data_type)?;
+
+        let mut bytes = Vec::new();
+        let mut offsets = Vec::with_capacity(elements.len() + 1);
+        for element in elements {
+            offsets.push(bytes.len());
+            serde_json::to_writer(&mut bytes, element)
+                .map_err(|_| ElementError::InvalidElementValue)?;
+        }
+        offsets.push(bytes.len());
+        let offsets = unsafe {
+            // SAFETY: The offsets are monotonically increasing.
+            ArrayBytesOffsets::new_unchecked(offsets)
+        };
+        let array_bytes = unsafe {
+            // SAFETY: The last offset is the length of the bytes.
+            ArrayBytes::new_vlen_unchecked(bytes, offsets)
+        };
+        Ok(array_bytes)
+    }
+
+    fn into_array_bytes(
+        data_type: &DataType,
+        elements: Vec<Self>,
+    ) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok(Self::to_array_bytes(data_type, &elements)?.into_owned())
+    }
+}
+
+impl ElementOwned for serde_json::Value {
+    fn from_array_bytes(
+        data_type: &DataType,
+        bytes: ArrayBytes<'_>,
+    ) -> Result<Vec<Self>, ElementError> {
+        Self::validate_data_type(data_type)?;
+        let (bytes, offsets) = bytes.into_variable()?.into_parts();
+        offsets
+            .iter()
+            .tuple_windows()
+            .map(|(&curr, &next)| {
+                serde_json::from_slice(&bytes[curr..next])
+                    .map_err(|_| ElementError::InvalidElementValue)
+            })
+            .collect()
+    }
+}
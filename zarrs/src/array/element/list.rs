@@ -0,0 +1,202 @@
+//! List elements of the `zarrs.list` data type.
+//!
+//! The items of each list are concatenated and the list boundaries are recorded as byte offsets.
+
+use itertools::Itertools;
+
+use crate::array::{
+    ArrayBytes, ArrayBytesOffsets, DataType, convert_from_bytes_slice, data_type,
+    transmute_to_bytes,
+};
+
+use super::{Element, ElementError, ElementOwned};
+
+use ElementError::IncompatibleElementType as IET;
+
+/// A list element of the `zarrs.list` data type.
+///
+/// This is a newtype around [`Vec<T>`] rather than an [`Element`] implementation on [`Vec<T>`] itself.
+/// Otherwise, integer literals in nested byte strings (e.g. `Some(vec![100, 101])` for [`Vec<u8>`] elements) could no longer be inferred.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T> List<T> {
+    /// Return the list items.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for List<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for List<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self(items)
+    }
+}
+
+impl<T> From<List<T>> for Vec<T> {
+    fn from(list: List<T>) -> Self {
+        list.0
+    }
+}
+
+/// Validate that `data_type` is a `zarrs.list` data type with items compatible with `T`.
+fn validate_list<T: Element>(data_type: &DataType) -> Result<(), ElementError> {
+    let list = data_type
+        .downcast_ref::<data_type::ListDataType>()
+        .ok_or(IET)?;
+    T::validate_data_type(list.data_type())
+}
+
+/// Encode lists of items to variable-length bytes.
+fn encode_list<T: bytemuck::NoUninit>(elements: &[List<T>]) -> ArrayBytes<'static> {
+    let mut len: usize = 0;
+    let mut offsets = Vec::with_capacity(elements.len() + 1);
+    for element in elements {
+        offsets.push(len);
+        len = len.checked_add(size_of_val(element.as_slice())).unwrap();
+    }
+    offsets.push(len);
+    let offsets = unsafe {
+        // SAFETY: The offsets are monotonically increasing.
+        ArrayBytesOffsets::new_unchecked(offsets)
+    };
+
+    let mut bytes = Vec::with_capacity(len);
+    for element in elements {
+        bytes.extend_from_slice(transmute_to_bytes(element.as_slice()));
+    }
+    unsafe {
+        // SAFETY: The last offset is the length of the bytes.
+        ArrayBytes::new_vlen_unchecked(bytes, offsets)
+    }
+}
+
+/// Decode variable-length bytes to lists of items.
+fn decode_list<T: bytemuck::Pod>(bytes: ArrayBytes<'_>) -> Result<Vec<List<T>>, ElementError> {
+    let (bytes, offsets) = bytes.into_variable()?.into_parts();
+    let mut elements = Vec::with_capacity(offsets.len().saturating_sub(1));
+    for (curr, next) in offsets.iter().tuple_windows() {
+        let items = &bytes[*curr..*next];
+        if !items.len().is_multiple_of(size_of::<T>()) {
+            return Err(ElementError::Other(
+                "list byte length is not a multiple of the item size".into(),
+            ));
+        }
+        elements.push(List(convert_from_bytes_slice::<T>(items)));
+    }
+    Ok(elements)
+}
+
+macro_rules! impl_element_list {
+    ($item_type:ty) => {
+        impl Element for List<$item_type> {
+            fn validate_data_type(data_type: &DataType) -> Result<(), ElementError> {
+                validate_list::<$item_type>(data_type)
+            }
+
+            fn to_array_bytes<'a>(
+                data_type: &DataType,
+                elements: &'a [Self],
+            ) -> Result<ArrayBytes<'a>, ElementError> {
+                Self::validate_data_type(data_type)?;
+                Ok(encode_list(elements))
+            }
+
+            fn into_array_bytes(
+                data_type: &DataType,
+                elements: Vec<Self>,
+            ) -> Result<ArrayBytes<'static>, ElementError> {
+                Self::validate_data_type(data_type)?;
+                Ok(encode_list(&elements))
+            }
+        }
+
+        impl ElementOwned for List<$item_type> {
+            fn from_array_bytes(
+                data_type: &DataType,
+                bytes: ArrayBytes<'_>,
+            ) -> Result<Vec<Self>, ElementError> {
+                Self::validate_data_type(data_type)?;
+                decode_list(bytes)
+            }
+        }
+    };
+}
+
+impl_element_list!(u8);
+impl_element_list!(i8);
+impl_element_list!(i16);
+impl_element_list!(i32);
+impl_element_list!(i64);
+impl_element_list!(u16);
+impl_element_list!(u32);
+impl_element_list!(u64);
+impl_element_list!(half::f16);
+impl_element_list!(half::bf16);
+impl_element_list!(f32);
+impl_element_list!(f64);
+impl_element_list!(num::complex::Complex32);
+impl_element_list!(num::complex::Complex64);
+
+#[cfg(test)]
+mod tests {
+    use super::List;
+    use crate::array::{Element, ElementOwned, data_type};
+
+    #[test]
+    fn list_elements() {
+        let data_type = data_type::list(data_type::int32()).unwrap();
+        let elements: Vec<List<i32>> = vec![List(vec![1, 2, 3]), List(vec![]), List(vec![-4])];
+        let bytes = List::<i32>::to_array_bytes(&data_type, &elements).unwrap();
+        let (_, offsets) = bytes.clone().into_variable().unwrap().into_parts();
+        assert_eq!(*offsets, [0, 12, 12, 16]);
+        assert_eq!(
+            List::<i32>::from_array_bytes(&data_type, bytes).unwrap(),
+            elements
+        );
+
+        let bytes = List::<i32>::into_array_bytes(&data_type, elements.clone()).unwrap();
+        assert_eq!(
+            List::<i32>::from_array_bytes(&data_type, bytes).unwrap(),
+            elements
+        );
+
+        assert!(List::<i64>::to_array_bytes(&data_type, &[List(vec![1])]).is_err());
+        assert!(List::<i32>::to_array_bytes(&data_type::int32(), &[List(vec![1])]).is_err());
+        assert!(List::<i32>::to_array_bytes(&data_type::string(), &[List(vec![1])]).is_err());
+    }
+
+    #[test]
+    fn list_elements_u8() {
+        let data_type = data_type::list(data_type::uint8()).unwrap();
+        let elements: Vec<Vec<u8>> = vec![vec![1, 2], vec![3]];
+        let bytes = Vec::<u8>::to_array_bytes(&data_type, &elements).unwrap();
+        assert_eq!(
+            Vec::<u8>::from_array_bytes(&data_type, bytes.clone()).unwrap(),
+            elements
+        );
+        assert_eq!(
+            List::<u8>::from_array_bytes(&data_type, bytes).unwrap(),
+            [List(vec![1, 2]), List(vec![3])]
+        );
+        assert!(
+            List::<u16>::to_array_bytes(&data_type::list(data_type::uint8()).unwrap(), &[])
+                .is_err()
+        );
+    }
+}
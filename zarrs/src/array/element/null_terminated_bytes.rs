@@ -0,0 +1,118 @@
+//! Helpers for elements of the `null_terminated_bytes` data type.
+//!
+//! Variable length byte and string elements are null-padded to the fixed length of the data type.
+//! Trailing null bytes are trimmed on decoding, interior null bytes are preserved.
+
+use crate::array::{ArrayBytes, DataType, data_type};
+
+use super::ElementError;
+
+/// Returns the element length in bytes if `data_type` is a `null_terminated_bytes` data type.
+pub(super) fn null_terminated_bytes_length(data_type: &DataType) -> Option<usize> {
+    if data_type.is::<data_type::NullTerminatedBytesDataType>() {
+        data_type.fixed_size()
+    } else {
+        None
+    }
+}
+
+/// Encode byte strings into null-padded fixed-length bytes.
+///
+/// Returns [`ElementError::InvalidElementValue`] if an element is longer than `length_bytes`.
+pub(super) fn encode_null_terminated_bytes<T: AsRef<[u8]>>(
+    elements: &[T],
+    length_bytes: usize,
+) -> Result<ArrayBytes<'static>, ElementError> {
+    let mut bytes = vec![0; elements.len() * length_bytes];
+    for (element, bytes) in elements.iter().zip(bytes.chunks_exact_mut(length_bytes)) {
+        let element = element.as_ref();
+        if element.len() > length_bytes {
+            return Err(ElementError::InvalidElementValue);
+        }
+        bytes[..element.len()].copy_from_slice(element);
+    }
+    Ok(ArrayBytes::new_flen(bytes))
+}
+
+/// Decode null-padded fixed-length bytes into byte strings with trailing null bytes trimmed.
+pub(super) fn decode_null_terminated_bytes(
+    bytes: ArrayBytes<'_>,
+    length_bytes: usize,
+) -> Result<Vec<Vec<u8>>, ElementError> {
+    let bytes = bytes.into_fixed()?;
+    if !bytes.len().is_multiple_of(length_bytes) {
+        return Err(ElementError::Other(
+            "byte length is not a multiple of element size".into(),
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(length_bytes)
+        .map(|element| {
+            let len = element.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+            element[..len].to_vec()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use crate::array::{Element, ElementOwned};
+
+    use super::*;
+
+    fn make_data_type(length_bytes: u64) -> DataType {
+        data_type::null_terminated_bytes(NonZeroU64::new(length_bytes).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn vec_u8_round_trip() {
+        let data_type = make_data_type(4);
+        let elements: Vec<Vec<u8>> = vec![b"".to_vec(), b"ab".to_vec(), b"a\0b".to_vec()];
+        let bytes = Vec::<u8>::to_array_bytes(&data_type, &elements).unwrap();
+        assert_eq!(
+            bytes.clone().into_fixed().unwrap().as_ref(),
+            b"\0\0\0\0ab\0\0a\0b\0"
+        );
+        let decoded = Vec::<u8>::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(decoded, elements);
+    }
+
+    #[test]
+    fn u8_array_round_trip() {
+        let data_type = make_data_type(3);
+        let elements = [*b"ab\0", *b"abc"];
+        let bytes = <[u8; 3]>::to_array_bytes(&data_type, &elements).unwrap();
+        let decoded = <[u8; 3]>::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(decoded, elements);
+        assert!(<[u8; 4]>::validate_data_type(&data_type).is_err());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let data_type = make_data_type(8);
+        let elements = ["abc", "", "zarr🦀"];
+        let bytes = <&str>::to_array_bytes(&data_type, &elements).unwrap();
+        let decoded = String::from_array_bytes(&data_type, bytes).unwrap();
+        assert_eq!(decoded, elements);
+    }
+
+    #[test]
+    fn string_invalid_utf8() {
+        let data_type = make_data_type(2);
+        let elements: Vec<&[u8]> = vec![&[0xff, 0xfe]];
+        let bytes = <&[u8]>::to_array_bytes(&data_type, &elements).unwrap();
+        assert!(matches!(
+            String::from_array_bytes(&data_type, bytes),
+            Err(ElementError::InvalidElementValue)
+        ));
+    }
+
+    #[test]
+    fn overlong_rejected() {
+        let data_type = make_data_type(2);
+        assert!(Vec::<u8>::into_array_bytes(&data_type, vec![b"abc".to_vec()]).is_err());
+        assert!(<&str>::to_array_bytes(&data_type, &["abc"]).is_err());
+    }
+}
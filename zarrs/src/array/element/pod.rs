@@ -56,6 +56,7 @@ impl_element_pod!(i8);
 impl_element_pod!(i16);
 impl_element_pod!(i32);
 impl_element_pod!(i64);
+impl_element_pod!(i128);
 impl_element_pod!(u8);
 impl_element_pod!(u16);
 impl_element_pod!(u32);
@@ -68,6 +69,9 @@ impl_element_pod!(num::complex::Complex<half::bf16>);
 impl_element_pod!(num::complex::Complex<half::f16>);
 impl_element_pod!(num::complex::Complex32);
 impl_element_pod!(num::complex::Complex64);
+impl_element_pod!(num::complex::Complex<i8>);
+impl_element_pod!(num::complex::Complex<i16>);
+impl_element_pod!(num::complex::Complex<i32>);
 
 #[cfg(feature = "float8")]
 impl_element_pod!(float8::F8E4M3);
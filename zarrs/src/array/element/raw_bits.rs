@@ -9,8 +9,11 @@ use ElementError::IncompatibleElementType as IET;
 
 impl<const N: usize> Element for &[u8; N] {
     fn validate_data_type(data_type: &DataType) -> Result<(), ElementError> {
-        // RawBits and fixed size equal to N
-        if data_type.is::<data_type::RawBitsDataType>() && data_type.fixed_size() == Some(N) {
+        // RawBits or NullTerminatedBytes and fixed size equal to N
+        if (data_type.is::<data_type::RawBitsDataType>()
+            || data_type.is::<data_type::NullTerminatedBytesDataType>())
+            && data_type.fixed_size() == Some(N)
+        {
             Ok(())
         } else {
             Err(IET)
@@ -38,8 +41,11 @@ impl<const N: usize> Element for &[u8; N] {
 
 impl<const N: usize> Element for [u8; N] {
     fn validate_data_type(data_type: &DataType) -> Result<(), ElementError> {
-        // RawBits and fixed size equal to N
-        if data_type.is::<data_type::RawBitsDataType>() && data_type.fixed_size() == Some(N) {
+        // RawBits or NullTerminatedBytes and fixed size equal to N
+        if (data_type.is::<data_type::RawBitsDataType>()
+            || data_type.is::<data_type::NullTerminatedBytesDataType>())
+            && data_type.fixed_size() == Some(N)
+        {
             Ok(())
         } else {
             Err(IET)
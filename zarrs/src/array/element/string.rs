@@ -4,6 +4,10 @@ use itertools::Itertools;
 
 use crate::array::{ArrayBytes, ArrayBytesOffsets, DataType};
 
+use super::categorical::{categorical, decode_categorical, encode_categorical};
+use super::null_terminated_bytes::{
+    decode_null_terminated_bytes, encode_null_terminated_bytes, null_terminated_bytes_length,
+};
 use super::{Element, ElementError, ElementOwned};
 
 use ElementError::IncompatibleElementType as IET;
@@ -25,6 +29,12 @@ macro_rules! impl_element_string {
                 elements: &'a [Self],
             ) -> Result<ArrayBytes<'a>, ElementError> {
                 Self::validate_data_type(data_type)?;
+                if let Some(length_bytes) = null_terminated_bytes_length(data_type) {
+                    return encode_null_terminated_bytes(elements, length_bytes);
+                }
+                if let Some(categorical) = categorical(data_type) {
+                    return encode_categorical(elements, categorical);
+                }
 
                 // Calculate offsets
                 let mut len: usize = 0;
@@ -70,6 +80,17 @@ impl ElementOwned for String {
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ElementError> {
         Self::validate_data_type(data_type)?;
+        if let Some(length_bytes) = null_terminated_bytes_length(data_type) {
+            return decode_null_terminated_bytes(bytes, length_bytes)?
+                .into_iter()
+                .map(|element| {
+                    Self::from_utf8(element).map_err(|_| ElementError::InvalidElementValue)
+                })
+                .collect();
+        }
+        if let Some(categorical) = categorical(data_type) {
+            return decode_categorical(bytes, categorical);
+        }
         let (bytes, offsets) = bytes.into_variable()?.into_parts();
         let mut elements = Vec::with_capacity(offsets.len().saturating_sub(1));
         for (curr, next) in offsets.iter().tuple_windows() {
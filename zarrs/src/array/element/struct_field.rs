@@ -0,0 +1,167 @@
+//! The [`ElementStructField`] trait for fields of struct elements.
+
+use num::complex::Complex;
+
+use crate::array::{ArrayBytes, DataType, data_type};
+
+use super::ElementError;
+
+/// A fixed size element type that can be a field of a `struct` element.
+///
+/// This is implemented for numeric, `bool`, complex and fixed size array types.
+/// It is also implemented for structs with `#[derive(Element)]`, which additionally implements [`Element`](super::Element) and [`ElementOwned`](super::ElementOwned) for use with the `struct` data type.
+///
+/// The bytes of a field are its native-endian in-memory representation without padding.
+/// Endianness conversion for the `bytes` codec is handled by the data type.
+pub trait ElementStructField: Sized {
+    /// The size of the field in bytes.
+    const SIZE: usize;
+
+    /// Return the data type of the elements of the field.
+    fn data_type() -> DataType;
+
+    /// Return the subarray shape of the field.
+    ///
+    /// The shape is empty for a scalar field.
+    #[must_use]
+    fn shape() -> Vec<u64> {
+        Vec::new()
+    }
+
+    /// Write the native-endian bytes of the field to `bytes` with length [`SIZE`](Self::SIZE).
+    fn write_ne_bytes(&self, bytes: &mut [u8]);
+
+    /// Read the field from native-endian `bytes` with length [`SIZE`](Self::SIZE).
+    ///
+    /// # Errors
+    /// Returns an [`ElementError`] if the bytes are not a valid value of the field.
+    fn read_ne_bytes(bytes: &[u8]) -> Result<Self, ElementError>;
+}
+
+macro_rules! impl_element_struct_field_pod {
+    ($type:ty, $data_type:ident) => {
+        impl ElementStructField for $type {
+            const SIZE: usize = size_of::<$type>();
+
+            fn data_type() -> DataType {
+                data_type::$data_type()
+            }
+
+            fn write_ne_bytes(&self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(bytemuck::bytes_of(self));
+            }
+
+            fn read_ne_bytes(bytes: &[u8]) -> Result<Self, ElementError> {
+                Ok(bytemuck::pod_read_unaligned(bytes))
+            }
+        }
+    };
+}
+
+impl_element_struct_field_pod!(i8, int8);
+impl_element_struct_field_pod!(i16, int16);
+impl_element_struct_field_pod!(i32, int32);
+impl_element_struct_field_pod!(i64, int64);
+impl_element_struct_field_pod!(u8, uint8);
+impl_element_struct_field_pod!(u16, uint16);
+impl_element_struct_field_pod!(u32, uint32);
+impl_element_struct_field_pod!(u64, uint64);
+impl_element_struct_field_pod!(half::bf16, bfloat16);
+impl_element_struct_field_pod!(half::f16, float16);
+impl_element_struct_field_pod!(f32, float32);
+impl_element_struct_field_pod!(f64, float64);
+impl_element_struct_field_pod!(Complex<half::bf16>, complex_bfloat16);
+impl_element_struct_field_pod!(Complex<half::f16>, complex_float16);
+impl_element_struct_field_pod!(Complex<f32>, complex64);
+impl_element_struct_field_pod!(Complex<f64>, complex128);
+impl_element_struct_field_pod!(Complex<i8>, complex_int8);
+impl_element_struct_field_pod!(Complex<i16>, complex_int16);
+impl_element_struct_field_pod!(Complex<i32>, complex_int32);
+
+impl ElementStructField for bool {
+    const SIZE: usize = 1;
+
+    fn data_type() -> DataType {
+        data_type::bool()
+    }
+
+    fn write_ne_bytes(&self, bytes: &mut [u8]) {
+        bytes[0] = u8::from(*self);
+    }
+
+    fn read_ne_bytes(bytes: &[u8]) -> Result<Self, ElementError> {
+        match bytes[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ElementError::InvalidElementValue),
+        }
+    }
+}
+
+impl<T: ElementStructField, const N: usize> ElementStructField for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+
+    fn shape() -> Vec<u64> {
+        let mut shape = vec![N as u64];
+        shape.extend(T::shape());
+        shape
+    }
+
+    fn write_ne_bytes(&self, bytes: &mut [u8]) {
+        for (element, bytes) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+            element.write_ne_bytes(bytes);
+        }
+    }
+
+    fn read_ne_bytes(bytes: &[u8]) -> Result<Self, ElementError> {
+        let elements = bytes
+            .chunks_exact(T::SIZE)
+            .map(T::read_ne_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        elements
+            .try_into()
+            .map_err(|_| ElementError::InvalidElementValue)
+    }
+}
+
+/// Validate that `data_type` is the data type of the struct element `T`.
+#[doc(hidden)]
+pub fn struct_element_validate_data_type<T: ElementStructField>(
+    data_type: &DataType,
+) -> Result<(), ElementError> {
+    (T::shape().is_empty() && data_type == &T::data_type())
+        .then_some(())
+        .ok_or(ElementError::IncompatibleElementType)
+}
+
+/// Convert struct elements to [`ArrayBytes`].
+#[doc(hidden)]
+pub fn struct_element_to_array_bytes<T: ElementStructField>(
+    data_type: &DataType,
+    elements: &[T],
+) -> Result<ArrayBytes<'static>, ElementError> {
+    struct_element_validate_data_type::<T>(data_type)?;
+    let mut bytes = vec![0; elements.len() * T::SIZE];
+    for (element, bytes) in elements.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+        element.write_ne_bytes(bytes);
+    }
+    Ok(ArrayBytes::new_flen(bytes))
+}
+
+/// Convert [`ArrayBytes`] to struct elements.
+#[doc(hidden)]
+pub fn struct_element_from_array_bytes<T: ElementStructField>(
+    data_type: &DataType,
+    bytes: ArrayBytes<'_>,
+) -> Result<Vec<T>, ElementError> {
+    struct_element_validate_data_type::<T>(data_type)?;
+    let bytes = bytes.into_fixed()?;
+    if !bytes.len().is_multiple_of(T::SIZE) {
+        return Err(ElementError::InvalidElementValue);
+    }
+    bytes.chunks_exact(T::SIZE).map(T::read_ne_bytes).collect()
+}
@@ -109,6 +109,56 @@ impl<T: ElementOwned, D: ndarray::Dimension> FromArrayBytes for ndarray::Array<T
     }
 }
 
+/// Decode the row-major elements of a matrix with a `shape` of `[nrows, ncols]`.
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+fn matrix_elements<T: ElementOwned>(
+    bytes: ArrayBytes<'static>,
+    shape: &[u64],
+    data_type: &DataType,
+) -> Result<(usize, usize, Vec<T>), ArrayError> {
+    let [nrows, ncols] = crate::array::iter_u64_to_usize(shape.iter())[..] else {
+        return Err(ArrayError::Other(format!(
+            "`shape` {shape:?} is incompatible with a matrix, which must be 2D"
+        )));
+    };
+    let elements: Vec<T> = T::from_array_bytes(data_type, bytes)?;
+    if nrows.checked_mul(ncols) != Some(elements.len()) {
+        return Err(ArrayError::Other(format!(
+            "`shape`: {shape:?} is not compatible with the number of elements: {:?}",
+            elements.len()
+        )));
+    }
+    Ok((nrows, ncols, elements))
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: ElementOwned + nalgebra::Scalar> FromArrayBytes for nalgebra::DMatrix<T> {
+    fn from_array_bytes(
+        bytes: ArrayBytes<'static>,
+        shape: &[u64],
+        data_type: &DataType,
+    ) -> Result<Self, ArrayError> {
+        let (nrows, ncols, elements) = matrix_elements::<T>(bytes, shape, data_type)?;
+        // Array elements are row-major, whereas nalgebra matrices are column-major
+        Ok(Self::from_row_iterator(nrows, ncols, elements))
+    }
+}
+
+#[cfg(feature = "faer")]
+impl<T: ElementOwned + Clone> FromArrayBytes for faer::Mat<T> {
+    fn from_array_bytes(
+        bytes: ArrayBytes<'static>,
+        shape: &[u64],
+        data_type: &DataType,
+    ) -> Result<Self, ArrayError> {
+        let (nrows, ncols, elements) = matrix_elements::<T>(bytes, shape, data_type)?;
+        // Array elements are row-major, whereas faer matrices are column-major
+        Ok(Self::from_fn(nrows, ncols, |i, j| {
+            elements[i * ncols + j].clone()
+        }))
+    }
+}
+
 impl FromArrayBytes for super::Tensor {
     fn from_array_bytes(
         bytes: ArrayBytes<'static>,
@@ -80,6 +80,54 @@ impl<T: Element, D: ndarray::Dimension> IntoArrayBytes<'static> for ndarray::Arr
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl<'a, T: Element + nalgebra::Scalar> IntoArrayBytes<'a> for &'a nalgebra::DMatrix<T> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'a>, ElementError> {
+        if self.nrows() <= 1 || self.ncols() <= 1 {
+            // The column-major elements of a row or column vector are also row-major
+            T::to_array_bytes(data_type, self.as_slice())
+        } else {
+            // Matrix elements are column-major, whereas array elements are row-major
+            let elements: Vec<T> = self.transpose().data.into();
+            T::into_array_bytes(data_type, elements)
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: Element + nalgebra::Scalar> IntoArrayBytes<'static> for nalgebra::DMatrix<T> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
+        let elements: Vec<T> = if self.nrows() <= 1 || self.ncols() <= 1 {
+            self.data.into()
+        } else {
+            self.transpose().data.into()
+        };
+        T::into_array_bytes(data_type, elements)
+    }
+}
+
+#[cfg(feature = "faer")]
+impl<'a, T: Element + Clone> IntoArrayBytes<'a> for &'a faer::Mat<T> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'a>, ElementError> {
+        if self.ncols() == 1 {
+            T::to_array_bytes(data_type, self.col_as_slice(0))
+        } else {
+            // Matrix elements are column-major with padded columns, whereas array elements are row-major
+            let elements: Vec<T> = (0..self.nrows())
+                .flat_map(|i| (0..self.ncols()).map(move |j| self[(i, j)].clone()))
+                .collect();
+            T::into_array_bytes(data_type, elements)
+        }
+    }
+}
+
+#[cfg(feature = "faer")]
+impl<T: Element + Clone> IntoArrayBytes<'static> for faer::Mat<T> {
+    fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
+        Ok((&self).into_array_bytes(data_type)?.into_owned())
+    }
+}
+
 impl IntoArrayBytes<'static> for super::Tensor {
     fn into_array_bytes(self, data_type: &DataType) -> Result<ArrayBytes<'static>, ElementError> {
         let (bytes, tensor_data_type, _) = self.into_parts();
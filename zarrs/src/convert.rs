@@ -14,12 +14,14 @@ use zarrs_plugin::{ExtensionAliasesV2, ExtensionAliasesV3, ExtensionName};
 
 use crate::array::chunk_grid::RegularChunkGrid;
 use crate::array::chunk_key_encoding::V2ChunkKeyEncoding;
-use crate::array::codec::{BytesCodec, VlenArrayCodec, VlenBytesCodec, VlenUtf8Codec};
-use crate::array::data_type;
+use crate::array::codec::{BytesCodec, Json2Codec, VlenArrayCodec, VlenBytesCodec, VlenUtf8Codec};
+use crate::array::{DataType, data_type};
 use zarrs_codec::CodecMetadataOptions;
 use zarrs_metadata_ext::chunk_grid::regular::RegularChunkGridConfiguration;
 use zarrs_metadata_ext::chunk_key_encoding::v2::V2ChunkKeyEncodingConfiguration;
 use zarrs_metadata_ext::codec::bytes::BytesCodecConfigurationV1;
+use zarrs_metadata_ext::codec::vlen_array::VlenArrayCodecConfigurationV1;
+use zarrs_metadata_ext::data_type::list::ListDataTypeConfigurationV1;
 
 #[cfg(feature = "blosc")]
 use crate::{
@@ -29,8 +31,14 @@ use crate::{
     },
 };
 
+#[cfg(feature = "jpeg")]
+use crate::array::codec::JpegCodec;
+#[cfg(feature = "msgpack")]
+use crate::array::codec::MsgPack2Codec;
 #[cfg(feature = "pcodec")]
 use crate::array::codec::PcodecCodec;
+#[cfg(feature = "png")]
+use crate::array::codec::PngCodec;
 
 #[cfg(feature = "transpose")]
 use crate::{
@@ -159,23 +167,47 @@ pub fn codec_metadata_v2_to_v3(
         for filter in filters {
             let id = filter.id();
 
+            if Json2Codec::matches_name_v2(id) {
+                // Object codec (array to bytes)
+                array_to_bytes_count += 1;
+                codecs.push(MetadataV3::new_with_configuration(
+                    Json2Codec::aliases_v3().default_name.clone().to_string(),
+                    filter.configuration().clone(),
+                ));
+                continue;
+            }
+
+            #[cfg(feature = "msgpack")]
+            if MsgPack2Codec::matches_name_v2(id) {
+                // Object codec (array to bytes)
+                array_to_bytes_count += 1;
+                codecs.push(MetadataV3::new_with_configuration(
+                    MsgPack2Codec::aliases_v3().default_name.clone().to_string(),
+                    filter.configuration().clone(),
+                ));
+                continue;
+            }
+
             // Check for vlen codecs (array to bytes)
             if VlenArrayCodec::matches_name_v2(id)
                 || VlenBytesCodec::matches_name_v2(id)
                 || VlenUtf8Codec::matches_name_v2(id)
             {
                 array_to_bytes_count += 1;
-                let name = if VlenArrayCodec::matches_name_v2(id) {
-                    VlenArrayCodec::aliases_v3().default_name.clone()
-                } else if VlenBytesCodec::matches_name_v2(id) {
-                    VlenBytesCodec::aliases_v3().default_name.clone()
+                let vlen_v2_metadata = if VlenArrayCodec::matches_name_v2(id) {
+                    // Retain the item `dtype`
+                    MetadataV3::new_with_configuration(
+                        VlenArrayCodec::aliases_v3().default_name.to_string(),
+                        filter.configuration().clone(),
+                    )
                 } else {
-                    VlenUtf8Codec::aliases_v3().default_name.clone()
+                    let name = if VlenBytesCodec::matches_name_v2(id) {
+                        VlenBytesCodec::aliases_v3().default_name.clone()
+                    } else {
+                        VlenUtf8Codec::aliases_v3().default_name.clone()
+                    };
+                    MetadataV3::new_with_configuration(name.to_string(), serde_json::Map::default())
                 };
-                let vlen_v2_metadata = MetadataV3::new_with_configuration(
-                    name.to_string(),
-                    serde_json::Map::default(),
-                );
                 codecs.push(vlen_v2_metadata);
             } else {
                 // Generic filter - convert to V3
@@ -214,6 +246,22 @@ pub fn codec_metadata_v2_to_v3(
                 compressor.configuration().clone(),
             ));
         }
+        #[cfg(feature = "png")]
+        if PngCodec::matches_name_v2(id) {
+            array_to_bytes_count += 1;
+            codecs.push(MetadataV3::new_with_configuration(
+                PngCodec::aliases_v3().default_name.clone().to_string(),
+                compressor.configuration().clone(),
+            ));
+        }
+        #[cfg(feature = "jpeg")]
+        if JpegCodec::matches_name_v2(id) {
+            array_to_bytes_count += 1;
+            codecs.push(MetadataV3::new_with_configuration(
+                JpegCodec::aliases_v3().default_name.clone().to_string(),
+                compressor.configuration().clone(),
+            ));
+        }
     }
 
     if array_to_bytes_count > 1 {
@@ -296,8 +344,27 @@ pub fn array_metadata_v2_to_v3(
 
     let endianness = data_type_metadata_v2_to_endianness(&array_metadata_v2.dtype)
         .map_err(ArrayMetadataV2ToV3Error::InvalidEndianness)?;
-    let data_type = data_type_metadata_v2_to_v3(&array_metadata_v2.dtype)?;
-    let fill_value = fill_value_metadata_v2_to_v3(&array_metadata_v2.fill_value, &data_type)?;
+    let data_type = if let Some(data_type) = list_data_type_metadata_v2_to_v3(
+        &array_metadata_v2.dtype,
+        array_metadata_v2.filters.as_deref(),
+    )? {
+        data_type
+    } else {
+        data_type_metadata_v2_to_v3(&array_metadata_v2.dtype)?
+    };
+    let fill_value = if let DataTypeMetadataV2::Structured(_) = &array_metadata_v2.dtype {
+        // Decode base64 encoded structured fill values with the byte order of each field
+        let data_type = DataType::from_metadata(&array_metadata_v2.dtype)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()))?;
+        let fill_value = data_type
+            .fill_value_v2(&array_metadata_v2.fill_value)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()))?;
+        data_type
+            .metadata_fill_value(&fill_value)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()))?
+    } else {
+        fill_value_metadata_v2_to_v3(&array_metadata_v2.fill_value, &data_type)?
+    };
 
     let codecs = codec_metadata_v2_to_v3(
         array_metadata_v2.order,
@@ -371,12 +438,66 @@ pub fn data_type_metadata_v2_to_v3(
                 data_type.clone(),
             ))
         }
-        DataTypeMetadataV2::Structured(_) => Err(ArrayMetadataV2ToV3Error::UnsupportedDataType(
-            data_type.clone(),
-        )),
+        DataTypeMetadataV2::Structured(_) => {
+            let dt = DataType::from_metadata(data_type)
+                .map_err(|_| ArrayMetadataV2ToV3Error::UnsupportedDataType(data_type.clone()))?;
+            let v3_name = dt
+                .name_v3()
+                .ok_or_else(|| ArrayMetadataV2ToV3Error::UnsupportedDataType(data_type.clone()))?;
+            Ok(MetadataV3::new_with_configuration(
+                v3_name.to_string(),
+                dt.configuration_v3(),
+            ))
+        }
     }
 }
 
+/// Returns the `zarrs.list` data type metadata of a Zarr V2 object array with a `vlen-array` filter.
+///
+/// The item data type is the `dtype` of the `vlen-array` filter, as written by `numcodecs`.
+/// Returns [`None`] if the array is not an object array with a `vlen-array` filter with a `dtype`.
+///
+/// # Errors
+/// Returns a [`ArrayMetadataV2ToV3Error`] if the item data type is not supported.
+pub(crate) fn list_data_type_metadata_v2_to_v3(
+    data_type: &DataTypeMetadataV2,
+    filters: Option<&[MetadataV2]>,
+) -> Result<Option<MetadataV3>, ArrayMetadataV2ToV3Error> {
+    let DataTypeMetadataV2::Simple(name) = data_type else {
+        return Ok(None);
+    };
+    if !data_type::StringDataType::matches_name_v2(name) {
+        return Ok(None);
+    }
+    let Some(item_dtype) = filters
+        .unwrap_or_default()
+        .iter()
+        .filter(|filter| VlenArrayCodec::matches_name_v2(filter.id()))
+        .find_map(|filter| {
+            filter
+                .to_typed_configuration::<VlenArrayCodecConfigurationV1>()
+                .ok()?
+                .dtype
+        })
+    else {
+        return Ok(None);
+    };
+
+    // The `vlen-array` codec stores the items in the byte order of the `dtype`
+    let item_data_type = DataTypeMetadataV2::Simple(item_dtype);
+    data_type_metadata_v2_to_endianness(&item_data_type)
+        .map_err(ArrayMetadataV2ToV3Error::InvalidEndianness)?;
+
+    Ok(Some(MetadataV3::new_with_serializable_configuration(
+        data_type::ListDataType::aliases_v3()
+            .default_name
+            .to_string(),
+        &ListDataTypeConfigurationV1 {
+            data_type: data_type_metadata_v2_to_v3(&item_data_type)?,
+        },
+    )?))
+}
+
 /// Convert Zarr V2 fill value metadata to Zarr V3.
 ///
 /// # Errors
@@ -396,6 +517,22 @@ pub fn fill_value_metadata_v2_to_v3(
 
     let data_type_name = data_type.name();
 
+    // Structured and fixed-length byte string fill values are converted through the data type
+    // (e.g. base64 encoded bytes to an object, or a `null`/0 fill value to empty bytes)
+    if data_type::StructDataType::matches_name_v3(data_type_name)
+        || data_type::NullTerminatedBytesDataType::matches_name_v3(data_type_name)
+        || data_type::ListDataType::matches_name_v3(data_type_name)
+    {
+        let data_type = DataType::from_metadata(data_type)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()))?;
+        let fill_value = data_type
+            .fill_value_v2(fill_value)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()))?;
+        return data_type
+            .metadata_fill_value(&fill_value)
+            .map_err(|e| ArrayMetadataV2ToV3Error::Other(e.to_string()));
+    }
+
     let is_string = data_type::StringDataType::matches_name_v3(data_type_name);
     let is_bool = data_type::BoolDataType::matches_name_v3(data_type_name);
 
@@ -541,4 +678,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn array_v2_config_imagecodecs_png() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"
+            {
+                "chunks": [256, 256, 3],
+                "compressor": {
+                    "id": "imagecodecs_png",
+                    "level": null
+                },
+                "dtype": "|u1",
+                "fill_value": 0,
+                "filters": null,
+                "order": "C",
+                "shape": [1024, 1024, 3],
+                "zarr_format": 2
+            }"#;
+        let array_metadata_v2: zarrs_metadata::v2::ArrayMetadataV2 = serde_json::from_str(json)?;
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2)?;
+        assert_eq!(array_metadata_v3.codecs.len(), 1);
+        assert_eq!(
+            array_metadata_v3.codecs[0].name(),
+            PngCodec::aliases_v3().default_name.clone()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn array_v2_config_object_json2() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"
+            {
+                "chunks": [10],
+                "compressor": null,
+                "dtype": "|O",
+                "fill_value": 0,
+                "filters": [
+                    {
+                        "id": "json2",
+                        "encoding": "utf-8",
+                        "sort_keys": true
+                    }
+                ],
+                "order": "C",
+                "shape": [100],
+                "zarr_format": 2
+            }"#;
+        let array_metadata_v2: zarrs_metadata::v2::ArrayMetadataV2 = serde_json::from_str(json)?;
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2)?;
+        assert_eq!(array_metadata_v3.codecs.len(), 1);
+        assert_eq!(
+            array_metadata_v3.codecs[0].name(),
+            Json2Codec::aliases_v3().default_name.clone()
+        );
+        assert_eq!(
+            array_metadata_v3.codecs[0].configuration().unwrap()["encoding"],
+            "utf-8"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn array_v2_config_null_terminated_bytes() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"
+            {
+                "chunks": [10],
+                "compressor": null,
+                "dtype": "|S6",
+                "fill_value": "YWJj",
+                "filters": null,
+                "order": "C",
+                "shape": [100],
+                "zarr_format": 2
+            }"#;
+        let array_metadata_v2: zarrs_metadata::v2::ArrayMetadataV2 = serde_json::from_str(json)?;
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2)?;
+        assert_eq!(array_metadata_v3.data_type.name(), "null_terminated_bytes");
+        assert_eq!(
+            array_metadata_v3.data_type.configuration().unwrap()["length_bytes"],
+            6
+        );
+        assert_eq!(
+            array_metadata_v3.fill_value,
+            FillValueMetadata::from("YWJj")
+        );
+
+        let array_metadata_v2 = zarrs_metadata::v2::ArrayMetadataV2 {
+            fill_value: FillValueMetadata::Null,
+            ..array_metadata_v2
+        };
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2)?;
+        assert_eq!(array_metadata_v3.fill_value, FillValueMetadata::from(""));
+        Ok(())
+    }
 }
@@ -198,13 +198,17 @@
 //!  - `async`: an **experimental** asynchronous API for [`stores`](storage), [`Array`](crate::array::Array), and [`Group`](group::Group).
 //!    - The async API is runtime-agnostic. This has some limitations that are detailed in the [`Array`](crate::array::Array) docs.
 //!    - The async API is not as performant as the sync API.
-//!  - Codecs: `adler32`, `bitround`, `bz2`, `fletcher32`, `gdeflate`, `pcodec`, `zfp`, `zlib`.
+//!  - Codecs: `adler32`, `aead`, `bitround`, `bitshuffle`, `brotli`, `bz2`, `crc32`, `digest`, `fletcher32`, `gdeflate`, `jenkins_lookup3`, `jpeg`, `msgpack`, `pcodec`, `png`, `snappy`, `zfp`, `zlib`.
 //!  - `dlpack`: adds convenience methods for [`DLPack`](https://arrow.apache.org/docs/python/dlpack.html) tensor interop to [`Array`](crate::array::Array).
+//!  - `arrow`: adds [`FromArrayBytes`](crate::array::FromArrayBytes)/[`IntoArrayBytes`](crate::array::IntoArrayBytes) implementations for [Apache Arrow](https://arrow.apache.org/) arrays.
+//!  - `nalgebra`/`faer`: adds [`FromArrayBytes`](crate::array::FromArrayBytes)/[`IntoArrayBytes`](crate::array::IntoArrayBytes) implementations for [`nalgebra`](https://docs.rs/nalgebra) and [`faer`](https://docs.rs/faer) matrices, for reading and writing 2D array subsets.
+//!  - `derive`: adds `#[derive(Element)]` for mapping Rust structs to `struct` array elements.
 //!  - Additional [`Element`](crate::array::Element)/[`ElementOwned`](crate::array::ElementOwned) implementations:
 //!    - `float8`: add support for [`float8`] subfloat data types.
 //!    - `microfloat`: add support for [`microfloat`] subfloat data types.
 //!    - `jiff`: add support for [`jiff`] time data types.
 //!    - `chrono`: add support for [`chrono`] time data types.
+//!    - `rust_decimal`: add support for [`rust_decimal`] decimal data types.
 //!
 //! ## `zarrs` Ecosystem
 #![doc = include_str!("../doc/ecosystem.md")]
@@ -646,6 +646,9 @@ fn exercise_array_write_update_ops<A: ArrayUpdateOps>(array: &A) -> TestResult {
     );
 
     let _ = array.compact_chunk(&[0, 0])?;
+    let chunks = ArraySubset::new_with_ranges(&[0..2, 0..2]);
+    array.compact_chunks(&chunks, 0.0)?;
+    assert_eq!(array.fragmentation_report(&chunks)?.wasted_size(), 0);
     array.erase_chunk(&[0, 1])?;
     assert!(
         array
@@ -331,6 +331,15 @@ async fn exercise_async_write_update_ops<A: AsyncArrayUpdateOps>(array: &A) -> T
         array.async_store_encoded_chunk(&[0, 1], encoded).await?;
     }
     let _ = array.async_compact_chunk(&[0, 0]).await?;
+    let chunks = ArraySubset::new_with_ranges(&[0..2, 0..2]);
+    array.async_compact_chunks(&chunks, 0.0).await?;
+    assert_eq!(
+        array
+            .async_fragmentation_report(&chunks)
+            .await?
+            .wasted_size(),
+        0
+    );
     array.async_erase_chunk(&[0, 1]).await?;
     assert!(
         array
@@ -4,7 +4,7 @@ use std::num::NonZeroU64;
 use std::sync::Arc;
 
 use zarrs::array::codec::array_to_bytes::sharding::ShardingCodecBuilder;
-use zarrs::array::{ArrayBuilder, data_type};
+use zarrs::array::{ArrayBuilder, ArraySubset, data_type};
 use zarrs::metadata_ext::codec::sharding::ShardingIndexLocation;
 use zarrs::storage::ReadableStorageTraits;
 use zarrs::storage::storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter;
@@ -431,3 +431,61 @@ fn array_partial_encode_sharding_compact_index_compressed() {
         }
     }
 }
+
+#[test]
+fn array_partial_encode_sharding_fragmentation() -> Result<(), Box<dyn std::error::Error>> {
+    let opt = CodecOptions::default().with_experimental_partial_encoding(true);
+
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let mut builder = ArrayBuilder::new(
+        vec![8, 8], // array shape
+        vec![4, 4], // regular chunk shape (shard)
+        data_type::uint16(),
+        0u16,
+    );
+    builder
+        .array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![NonZeroU64::new(2).unwrap(); 2], &data_type::uint16()) // 2x2 subchunks
+                .index_bytes_to_bytes_codecs(vec![])
+                .build(),
+        ))
+        .bytes_to_bytes_codecs(vec![]);
+    let array = builder.build(store, "/")?.with_codec_options(opt);
+    let all_chunks = ArraySubset::new_with_shape(array.chunk_grid_shape().to_vec());
+
+    // Write shards [0, 0] and [1, 1], each with 4 subchunks of 8 bytes and an index of 64 bytes
+    array.store_chunk(&[0, 0], &[1u16; 16])?;
+    array.store_chunk(&[1, 1], &[1u16; 16])?;
+    let report = array.fragmentation_report(&all_chunks)?;
+    assert_eq!(report.chunks().len(), 2);
+    assert_eq!(report.encoded_size(), 2 * 96);
+    assert_eq!(report.wasted_size(), 0);
+
+    // Overwrite a subchunk of shard [0, 0] twice, appending 16 bytes of replaced subchunks
+    array.store_array_subset(&[0..2, 0..2], &[2u16; 4])?;
+    array.store_array_subset(&[0..2, 0..2], &[3u16; 4])?;
+    let report = array.fragmentation_report(&all_chunks)?;
+    assert_eq!(report.encoded_size(), 2 * 96 + 16);
+    assert_eq!(report.wasted_size(), 16);
+    let chunk = &report.chunks()[0];
+    assert_eq!(chunk.chunk_indices(), &[0, 0]);
+    assert_eq!(chunk.wasted_size(), 16);
+    assert!((chunk.wasted_ratio() - 16.0 / 112.0).abs() < f64::EPSILON);
+
+    // Chunks below the threshold are not compacted
+    assert!(array.compact_chunks(&all_chunks, 0.5)?.chunks().is_empty());
+    assert_eq!(array.fragmentation_report(&all_chunks)?.wasted_size(), 16);
+
+    let compacted = array.compact_chunks(&all_chunks, 0.1)?;
+    assert_eq!(compacted.chunks(), std::slice::from_ref(chunk));
+    let report = array.fragmentation_report(&all_chunks)?;
+    assert_eq!(report.encoded_size(), 2 * 96);
+    assert_eq!(report.wasted_size(), 0);
+
+    let mut expected = vec![1u16; 16];
+    for i in [0, 1, 4, 5] {
+        expected[i] = 3;
+    }
+    assert_eq!(array.retrieve_chunk::<Vec<u16>>(&[0, 0])?, expected);
+    Ok(())
+}
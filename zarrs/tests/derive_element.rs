@@ -0,0 +1,150 @@
+#![allow(missing_docs)]
+#![cfg(feature = "derive")]
+
+use std::sync::Arc;
+
+use zarrs::array::codec::BytesCodec;
+use zarrs::array::data_type::{self, StructField};
+use zarrs::array::{ArrayBuilder, Element, ElementOwned, ElementStructField, FillValue};
+use zarrs::metadata::Endianness;
+use zarrs::storage::ReadableStorageTraits;
+use zarrs::storage::store::MemoryStore;
+
+#[derive(Element, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Element, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Sample {
+    #[zarrs(rename = "sample_id")]
+    id: u16,
+    position: Position,
+    path: [Position; 2],
+    matrix: [[i16; 2]; 3],
+    flags: [bool; 2],
+}
+
+fn samples() -> Vec<Sample> {
+    (0..4u8)
+        .map(|i| {
+            let f = f32::from(i);
+            let n = i16::from(i);
+            Sample {
+                id: 0x0100 + u16::from(i),
+                position: Position { x: f, y: -f },
+                path: [Position { x: f + 0.5, y: f }, Position { x: f, y: f + 0.5 }],
+                matrix: [[n, -n], [n + 1, -n - 1], [n + 2, -n - 2]],
+                flags: [i % 2 == 0, i % 2 == 1],
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn derive_element_data_type() {
+    let position = data_type::structured(vec![
+        StructField::new("x", data_type::float32()),
+        StructField::new("y", data_type::float32()),
+    ])
+    .unwrap();
+    assert_eq!(Position::data_type(), position);
+    assert_eq!(Position::SIZE, 8);
+
+    let sample = data_type::structured(vec![
+        StructField::new("sample_id", data_type::uint16()),
+        StructField::new("position", position.clone()),
+        StructField::new_subarray("path", position, vec![2]),
+        StructField::new_subarray("matrix", data_type::int16(), vec![3, 2]),
+        StructField::new_subarray("flags", data_type::bool(), vec![2]),
+    ])
+    .unwrap();
+    assert_eq!(Sample::data_type(), sample);
+    assert_eq!(Sample::SIZE, 2 + 8 + 16 + 12 + 2);
+
+    assert!(Sample::validate_data_type(&sample).is_ok());
+    assert!(Sample::validate_data_type(&Position::data_type()).is_err());
+}
+
+#[test]
+fn derive_element_bytes() {
+    let samples = samples();
+    let data_type = Sample::data_type();
+    let bytes = Sample::to_array_bytes(&data_type, &samples).unwrap();
+    let fixed = bytes.clone().into_fixed().unwrap();
+    assert_eq!(fixed.len(), samples.len() * Sample::SIZE);
+    // Fields are packed in declaration order without padding
+    assert_eq!(&fixed[..2], &0x0100u16.to_ne_bytes());
+    assert_eq!(&fixed[2..6], &0.0f32.to_ne_bytes());
+    assert_eq!(&fixed[Sample::SIZE - 2..Sample::SIZE], &[1, 0]);
+    assert_eq!(
+        Sample::from_array_bytes(&data_type, bytes).unwrap(),
+        samples
+    );
+
+    // Invalid bool values are rejected
+    let mut invalid = fixed.into_owned();
+    invalid[Sample::SIZE - 1] = 2;
+    assert!(
+        Sample::from_array_bytes(&data_type, zarrs::array::ArrayBytes::new_flen(invalid)).is_err()
+    );
+}
+
+fn derive_element_round_trip(endianness: Endianness) {
+    let store = Arc::new(MemoryStore::default());
+    let data_type = Sample::data_type();
+    let fill_value = FillValue::new(
+        Sample::to_array_bytes(&data_type, &[samples()[0]])
+            .unwrap()
+            .into_fixed()
+            .unwrap()
+            .into_owned(),
+    );
+    let array = ArrayBuilder::new(vec![8], vec![4], data_type, fill_value)
+        .array_to_bytes_codec(Arc::new(BytesCodec::new(Some(endianness))))
+        .build(store.clone(), "/array")
+        .unwrap();
+    array.store_metadata().unwrap();
+
+    let samples = samples();
+    array.store_chunk(&[1], &samples).unwrap();
+
+    // Each field is encoded in the requested endianness
+    let encoded = store.get(&array.chunk_key(&[1])).unwrap().unwrap();
+    assert_eq!(encoded.len(), samples.len() * Sample::SIZE);
+    let (id, x, matrix) = match endianness {
+        Endianness::Big => (
+            0x0100u16.to_be_bytes(),
+            0.5f32.to_be_bytes(),
+            (-1i16).to_be_bytes(),
+        ),
+        Endianness::Little => (
+            0x0100u16.to_le_bytes(),
+            0.5f32.to_le_bytes(),
+            (-1i16).to_le_bytes(),
+        ),
+    };
+    assert_eq!(&encoded[..2], &id);
+    // path[0].x of the first sample
+    assert_eq!(&encoded[10..14], &x);
+    // matrix[1][1] of the first sample
+    assert_eq!(&encoded[26 + 3 * 2..26 + 4 * 2], &matrix);
+
+    let array = zarrs::array::Array::open(store, "/array").unwrap();
+    let retrieved: Vec<Sample> = array.retrieve_array_subset(&array.subset_all()).unwrap();
+    assert_eq!(&retrieved[4..], &samples);
+    assert_eq!(&retrieved[..4], &[samples[0]; 4]);
+}
+
+#[test]
+fn derive_element_round_trip_big_endian() {
+    derive_element_round_trip(Endianness::Big);
+}
+
+#[test]
+fn derive_element_round_trip_little_endian() {
+    derive_element_round_trip(Endianness::Little);
+}
@@ -69,6 +69,13 @@ pub trait BytesPartialDecoderTraits: Any + MaybeSend + MaybeSync {
     /// If this returns `true`, the decoder can efficiently handle partial decoding operations.
     /// If this returns `false`, partial decoding will fall back to a full decode operation.
     fn supports_partial_decode(&self) -> bool;
+
+    /// Returns the store key of the value if this decoder reads it directly from a store.
+    ///
+    /// Intended for caches of data derived from stored values, such as shard indexes.
+    fn store_key(&self) -> Option<&StoreKey> {
+        None
+    }
 }
 
 /// Partial bytes encoder traits.
@@ -260,6 +267,10 @@ impl<TStorage: ReadableStorageTraits + 'static> BytesPartialDecoderTraits for (T
     fn supports_partial_decode(&self) -> bool {
         self.0.supports_get_partial()
     }
+
+    fn store_key(&self) -> Option<&StoreKey> {
+        Some(&self.1)
+    }
 }
 
 impl<Tstorage: ReadableWritableStorageTraits + 'static> BytesPartialEncoderTraits
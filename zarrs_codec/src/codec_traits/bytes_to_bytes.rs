@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::{
     ArrayBytesRaw, BytesPartialDecoderTraits, BytesPartialEncoderTraits, BytesRepresentation,
     BytesToBytesCodecPartialDefault, CodecCreateError, CodecError, CodecOptions,
-    CodecSpecificOptions, CodecTraits, RecommendedConcurrency,
+    CodecSpecificOptions, CodecTraits, DataType, RecommendedConcurrency,
 };
 #[cfg(feature = "async")]
 use crate::{AsyncBytesPartialDecoderTraits, AsyncBytesPartialEncoderTraits};
@@ -30,6 +30,21 @@ pub trait BytesToBytesCodecTraits: CodecTraits + core::fmt::Debug {
         Ok(self.into_dyn())
     }
 
+    /// Return a version of this codec with defaults derived from the data type of its codec chain.
+    ///
+    /// `data_type` is the data type decoded by the array-to-bytes codec of the codec chain.
+    /// The default implementation returns the codec unchanged.
+    ///
+    /// # Errors
+    /// Returns a [`CodecCreateError`] if the `data_type` is not supported by this codec.
+    #[expect(unused_variables)]
+    fn with_data_type(
+        self: Arc<Self>,
+        data_type: &DataType,
+    ) -> Result<Arc<dyn BytesToBytesCodecTraits>, CodecCreateError> {
+        Ok(self.into_dyn())
+    }
+
     /// Return the maximum internal concurrency supported for the requested decoded representation.
     ///
     /// # Errors
@@ -633,6 +633,14 @@ pub enum CodecError {
     /// An embedded checksum does not match the decoded value.
     #[error("the checksum is invalid")]
     InvalidChecksum,
+    /// An embedded cryptographic digest does not match the decoded value.
+    #[error("the digest is invalid")]
+    InvalidDigest,
+    /// Authenticated decryption failed, the key is incorrect or the encoded value has been modified.
+    #[error(
+        "authenticated decryption failed, the key is incorrect or the encoded value has been modified"
+    )]
+    AuthenticationFailed,
     /// A store error.
     #[error(transparent)]
     StorageError(#[from] StorageError),
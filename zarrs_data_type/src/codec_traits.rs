@@ -10,6 +10,7 @@
 //!
 //! - [`impl_bitround_codec`] - Implement [`bitround::BitroundDataTypeTraits`]
 //! - [`impl_bytes_data_type_traits`] - Implement [`bytes::BytesDataTypeTraits`]
+//! - [`impl_cast_value_data_type_traits_complex`] - Implement [`cast_value::CastValueDataTypeTraits`] for complex numbers
 //! - [`impl_cast_value_data_type_traits_float`] - Implement [`cast_value::CastValueDataTypeTraits`] for floats
 //! - [`impl_cast_value_data_type_traits_signed_integer`] - Implement [`cast_value::CastValueDataTypeTraits`] for signed integers
 //! - [`impl_cast_value_data_type_traits_unsigned_integer`] - Implement [`cast_value::CastValueDataTypeTraits`] for unsigned integers
@@ -29,7 +30,8 @@ pub mod zfp;
 pub use bitround::impl_bitround_codec;
 pub use bytes::impl_bytes_data_type_traits;
 pub use cast_value::{
-    impl_cast_value_data_type_traits_float, impl_cast_value_data_type_traits_signed_integer,
+    impl_cast_value_data_type_traits_complex, impl_cast_value_data_type_traits_float,
+    impl_cast_value_data_type_traits_signed_integer,
     impl_cast_value_data_type_traits_unsigned_integer,
 };
 pub use fixedscaleoffset::impl_fixed_scale_offset_data_type_traits;
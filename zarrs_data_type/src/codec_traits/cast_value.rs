@@ -3,6 +3,8 @@
 mod kernels;
 
 use half::{bf16, f16};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use thiserror::Error;
 
 pub use kernels::{
@@ -44,6 +46,13 @@ pub enum CastValueScalar {
     Unsigned(u128),
     /// A floating point value.
     Float(CastValueFloatScalar),
+    /// A decimal value of `mantissa * 10^-scale`.
+    Decimal {
+        /// The unscaled integer value.
+        mantissa: i128,
+        /// The number of fractional decimal digits.
+        scale: u32,
+    },
 }
 
 /// A floating point scalar value.
@@ -116,6 +125,9 @@ pub enum CastValueError {
     /// Wrapping was requested for a non-integral target data type.
     #[error("cast_value wrap mode is only valid for integral target data types")]
     InvalidWrapTarget,
+    /// A complex value was cast to or from a non-complex data type.
+    #[error("cast_value can only cast complex values to complex data types")]
+    Complex,
 }
 
 /// Traits for a data type supporting the `cast_value` codec.
@@ -284,6 +296,14 @@ fn scalar_to_rounded_integer(
         CastValueScalar::Unsigned(value) => i128::try_from(value)
             .map(RoundedScalar::Int)
             .map_err(|_| CastValueError::NotRepresentable),
+        CastValueScalar::Decimal { mantissa, scale } => {
+            let value = DecimalParts::from_signed(mantissa, -i64::from(scale));
+            value
+                .to_scale(0, Some(rounding))
+                .and_then(|magnitude| value.signed(magnitude))
+                .map(RoundedScalar::Int)
+                .ok_or(CastValueError::NotRepresentable)
+        }
         CastValueScalar::Float(value) => {
             if value.is_nan() || value.is_infinite() {
                 return Err(CastValueError::NotRepresentable);
@@ -319,9 +339,189 @@ fn scalar_to_f64(value: CastValueScalar, rounding: CastValueRoundingMode) -> f64
         }
         CastValueScalar::Unsigned(value) => integer_magnitude_to_f64(value, false, rounding),
         CastValueScalar::Float(value) => value.to_f64(),
+        CastValueScalar::Decimal { mantissa, scale } => decimal_to_f64(mantissa, scale),
     }
 }
 
+/// Convert a decimal to an `f64`.
+fn decimal_to_f64(mantissa: i128, mut scale: u32) -> f64 {
+    let mut mantissa = mantissa;
+    loop {
+        if let Ok(decimal) = Decimal::try_from_i128_with_scale(mantissa, scale) {
+            return decimal.to_f64().unwrap_or(f64::NAN);
+        }
+        if scale == 0 {
+            #[expect(clippy::cast_precision_loss)]
+            return mantissa as f64;
+        }
+        // Drop fractional digits beyond the 96-bit mantissa of a `Decimal`, they are below the precision of an `f64`
+        mantissa /= 10;
+        scale -= 1;
+    }
+}
+
+/// A decimal number of `coefficient * 10^exponent`.
+#[derive(Clone, Copy)]
+struct DecimalParts {
+    negative: bool,
+    coefficient: u128,
+    exponent: i64,
+}
+
+impl DecimalParts {
+    fn from_signed(value: i128, exponent: i64) -> Self {
+        Self {
+            negative: value < 0,
+            coefficient: value.unsigned_abs(),
+            exponent,
+        }
+    }
+
+    /// Parse a decimal string such as `-12.50` or `1.25e-3`.
+    ///
+    /// Returns `None` if the string is invalid or its significant digits overflow `u128`.
+    fn parse(value: &str) -> Option<Self> {
+        let (negative, value) = if let Some(value) = value.strip_prefix('-') {
+            (true, value)
+        } else {
+            (false, value.strip_prefix('+').unwrap_or(value))
+        };
+        let (digits, mut exponent) = match value.split_once(['e', 'E']) {
+            Some((digits, exponent)) => (digits, exponent.parse::<i64>().ok()?),
+            None => (value, 0),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut coefficient: u128 = 0;
+        for (digit, is_fraction) in integer
+            .bytes()
+            .map(|digit| (digit, false))
+            .chain(fraction.bytes().map(|digit| (digit, true)))
+        {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            let digit = u128::from(digit - b'0');
+            if let Some(next) = coefficient
+                .checked_mul(10)
+                .and_then(|coefficient| coefficient.checked_add(digit))
+            {
+                coefficient = next;
+                exponent -= i64::from(is_fraction);
+            } else if digit == 0 {
+                // Drop zeros beyond the precision of the coefficient
+                exponent += i64::from(!is_fraction);
+            } else {
+                return None;
+            }
+        }
+        Some(Self {
+            negative,
+            coefficient,
+            exponent,
+        })
+    }
+
+    /// Convert the binary value of a finite float to a decimal with up to 28 fractional digits.
+    ///
+    /// Magnitudes beyond the 96-bit mantissa of a `Decimal` are integers, and saturate beyond `i128`.
+    fn from_float(value: CastValueFloatScalar) -> Option<Self> {
+        let value = value.to_f64();
+        if let Some(decimal) = Decimal::from_f64_retain(value) {
+            Some(Self::from_signed(
+                decimal.mantissa(),
+                -i64::from(decimal.scale()),
+            ))
+        } else if value.is_finite() {
+            #[expect(clippy::cast_possible_truncation)]
+            Some(Self::from_signed(value as i128, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Rescale to a magnitude in units of `10^-scale`.
+    ///
+    /// Returns `None` if the magnitude overflows `u128`, or if `rounding` is `None` and the value is not exactly representable.
+    fn to_scale(self, scale: u32, rounding: Option<CastValueRoundingMode>) -> Option<u128> {
+        if self.coefficient == 0 {
+            return Some(0);
+        }
+        let shift = self.exponent + i64::from(scale);
+        if shift >= 0 {
+            let factor = 10_u128.checked_pow(u32::try_from(shift).ok()?)?;
+            return self.coefficient.checked_mul(factor);
+        }
+        let divisor = u32::try_from(-shift)
+            .ok()
+            .and_then(|shift| 10_u128.checked_pow(shift));
+        let (quotient, remainder) = divisor.map_or((0, self.coefficient), |divisor| {
+            (self.coefficient / divisor, self.coefficient % divisor)
+        });
+        if remainder == 0 {
+            return Some(quotient);
+        }
+        // A divisor beyond u128 is more than twice any remainder
+        let half = divisor.map_or(std::cmp::Ordering::Less, |divisor| {
+            remainder.cmp(&(divisor - remainder))
+        });
+        let increment = match rounding? {
+            CastValueRoundingMode::NearestEven => {
+                half.is_gt() || (half.is_eq() && quotient % 2 == 1)
+            }
+            CastValueRoundingMode::TowardsZero => false,
+            CastValueRoundingMode::TowardsPositive => !self.negative,
+            CastValueRoundingMode::TowardsNegative => self.negative,
+            CastValueRoundingMode::NearestAway => half.is_ge(),
+        };
+        Some(quotient + u128::from(increment))
+    }
+
+    /// Apply the sign to a magnitude.
+    fn signed(self, magnitude: u128) -> Option<i128> {
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+/// The maximum decimal mantissa magnitude with `precision` significant digits.
+fn decimal_max(precision: u32) -> u128 {
+    debug_assert!((1..=38).contains(&precision));
+    10_u128.pow(precision) - 1
+}
+
+/// Parse a decimal string (e.g. `-12.50` or `1.25e-3`) to the mantissa of a decimal with `precision` significant digits and `scale` fractional digits.
+///
+/// Returns `None` if the string is invalid, has more significant fractional digits than `scale`, or exceeds `precision`.
+#[must_use]
+pub fn parse_decimal(value: &str, precision: u32, scale: u32) -> Option<i128> {
+    let value = DecimalParts::parse(value)?;
+    let magnitude = value.to_scale(scale, None)?;
+    if magnitude > decimal_max(precision) {
+        return None;
+    }
+    value.signed(magnitude)
+}
+
+/// Format the mantissa of a decimal with `scale` fractional digits as a decimal string (e.g. `-12.50`).
+#[must_use]
+pub fn format_decimal(mantissa: i128, scale: u32) -> String {
+    let sign = if mantissa < 0 { "-" } else { "" };
+    let digits = mantissa.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let scale = scale as usize;
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integer}.{fraction}")
+}
+
 /// Round an integer magnitude directly to a binary float precision, returning
 /// the exact target value represented as `f64`.
 fn integer_magnitude_to_float_precision(
@@ -752,10 +952,54 @@ pub fn cast_scalar_to_float_quantity_with_precision(
         CastValueScalar::Unsigned(value) => {
             integer_magnitude_to_float_precision(value, false, mantissa_digits, rounding)
         }
-        CastValueScalar::Float(_) => quantity,
+        CastValueScalar::Float(_) | CastValueScalar::Decimal { .. } => quantity,
     })
 }
 
+/// Cast a scalar to the mantissa of a decimal with `precision` significant digits and `scale` fractional digits.
+///
+/// Floating point values are cast from their binary value, to 28 fractional digits.
+///
+/// # Errors
+/// Returns an error if the scalar is not representable.
+pub fn cast_scalar_to_decimal(
+    value: CastValueScalar,
+    precision: u32,
+    scale: u32,
+    rounding: CastValueRoundingMode,
+    out_of_range: Option<CastValueOutOfRangeMode>,
+) -> Result<i128, CastValueError> {
+    let max = decimal_max(precision);
+    let value = match value {
+        CastValueScalar::Signed(value) => DecimalParts::from_signed(value, 0),
+        CastValueScalar::Unsigned(value) => DecimalParts {
+            negative: false,
+            coefficient: value,
+            exponent: 0,
+        },
+        CastValueScalar::Decimal { mantissa, scale } => {
+            DecimalParts::from_signed(mantissa, -i64::from(scale))
+        }
+        CastValueScalar::Float(value) => {
+            if value.is_nan() || value.is_infinite() {
+                return Err(CastValueError::NotRepresentable);
+            }
+            DecimalParts::from_float(value).ok_or(CastValueError::NotRepresentable)?
+        }
+    };
+    let magnitude = match value.to_scale(scale, Some(rounding)) {
+        Some(magnitude) if magnitude <= max => magnitude,
+        Some(_) | None => match out_of_range {
+            Some(CastValueOutOfRangeMode::Clamp) => max,
+            Some(CastValueOutOfRangeMode::Wrap) => return Err(CastValueError::InvalidWrapTarget),
+            None => return Err(CastValueError::NotRepresentable),
+        },
+    };
+    value
+        .signed(magnitude)
+        .ok_or(CastValueError::NotRepresentable)
+}
+
 /// The target-range handling of [`cast_scalar_to_float_quantity`] for a value
 /// already exactly rounded to `f64`.
 fn float_quantity_from_f64(
@@ -1241,7 +1485,85 @@ macro_rules! _impl_cast_value_data_type_traits_float {
     };
 }
 
+/// Macro to implement `cast_value` for complex data types with components of `$component`.
+///
+/// Complex values are cast component-wise by the bulk cast kernels, so they can only be cast to other complex data types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _impl_cast_value_data_type_traits_complex {
+    ($marker:ty, $component:ident) => {
+        impl $crate::codec_traits::cast_value::CastValueDataTypeTraits for $marker {
+            fn cast_value_is_integral(&self) -> bool {
+                $crate::_impl_cast_value_data_type_traits_complex!(@integral $component)
+            }
+
+            fn cast_value_repr(
+                &self,
+            ) -> Option<$crate::codec_traits::cast_value::CastValueRepr> {
+                Some($crate::codec_traits::cast_value::CastValueRepr::Complex(
+                    &$crate::_impl_cast_value_data_type_traits_complex!(@repr $component),
+                ))
+            }
+
+            fn cast_value_read(
+                &self,
+                _bytes: &[u8],
+            ) -> Result<
+                $crate::codec_traits::cast_value::CastValueScalar,
+                $crate::codec_traits::cast_value::CastValueError,
+            > {
+                Err($crate::codec_traits::cast_value::CastValueError::Complex)
+            }
+
+            fn cast_value_write(
+                &self,
+                _value: $crate::codec_traits::cast_value::CastValueScalar,
+                _rounding: $crate::codec_traits::cast_value::CastValueRoundingMode,
+                _out_of_range: Option<$crate::codec_traits::cast_value::CastValueOutOfRangeMode>,
+                _output: &mut Vec<u8>,
+            ) -> Result<(), $crate::codec_traits::cast_value::CastValueError> {
+                Err($crate::codec_traits::cast_value::CastValueError::Complex)
+            }
+        }
+        $crate::register_data_type_extension_codec!(
+            $marker,
+            $crate::codec_traits::cast_value::CastValueDataTypePlugin,
+            $crate::codec_traits::cast_value::CastValueDataTypeTraits
+        );
+    };
+    (@integral i8) => { true };
+    (@integral i16) => { true };
+    (@integral i32) => { true };
+    (@integral f16) => { false };
+    (@integral bf16) => { false };
+    (@integral f32) => { false };
+    (@integral f64) => { false };
+    (@repr i8) => {
+        $crate::codec_traits::cast_value::CastValueRepr::Int {
+            bits: 8,
+            stored: $crate::codec_traits::cast_value::CastValueIntStored::I8,
+        }
+    };
+    (@repr i16) => {
+        $crate::codec_traits::cast_value::CastValueRepr::Int {
+            bits: 16,
+            stored: $crate::codec_traits::cast_value::CastValueIntStored::I16,
+        }
+    };
+    (@repr i32) => {
+        $crate::codec_traits::cast_value::CastValueRepr::Int {
+            bits: 32,
+            stored: $crate::codec_traits::cast_value::CastValueIntStored::I32,
+        }
+    };
+    (@repr f16) => { $crate::codec_traits::cast_value::CastValueRepr::F16 };
+    (@repr bf16) => { $crate::codec_traits::cast_value::CastValueRepr::BF16 };
+    (@repr f32) => { $crate::codec_traits::cast_value::CastValueRepr::F32 };
+    (@repr f64) => { $crate::codec_traits::cast_value::CastValueRepr::F64 };
+}
+
 pub use crate::{
+    _impl_cast_value_data_type_traits_complex as impl_cast_value_data_type_traits_complex,
     _impl_cast_value_data_type_traits_float as impl_cast_value_data_type_traits_float,
     _impl_cast_value_data_type_traits_signed_integer as impl_cast_value_data_type_traits_signed_integer,
     _impl_cast_value_data_type_traits_unsigned_integer as impl_cast_value_data_type_traits_unsigned_integer,
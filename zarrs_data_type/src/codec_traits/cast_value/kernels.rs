@@ -89,6 +89,10 @@ pub enum CastValueRepr {
     F32,
     /// IEEE 754 binary64.
     F64,
+    /// A complex number with interleaved real and imaginary components of the given representation.
+    ///
+    /// Complex numbers are only castable to other complex numbers.
+    Complex(&'static CastValueRepr),
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -643,6 +647,7 @@ fn select_from_int<S: KernelInt>(target: CastValueRepr) -> Option<CastKernelFn>
         CastValueRepr::BF16 => kernel_int_to_float::<S, bf16> as CastKernelFn,
         CastValueRepr::F32 => kernel_int_to_float::<S, f32> as CastKernelFn,
         CastValueRepr::F64 => kernel_int_to_float::<S, f64> as CastKernelFn,
+        CastValueRepr::Complex(_) => return None,
     })
 }
 
@@ -660,6 +665,7 @@ fn select_from_float<S: KernelFloatSource + KernelToF64>(
         CastValueRepr::BF16 => kernel_to_float::<S, bf16> as CastKernelFn,
         CastValueRepr::F32 => kernel_to_float::<S, f32> as CastKernelFn,
         CastValueRepr::F64 => kernel_to_float::<S, f64> as CastKernelFn,
+        CastValueRepr::Complex(_) => return None,
     })
 }
 
@@ -675,6 +681,19 @@ pub fn select_cast_kernel(
     out_of_range: Option<CastValueOutOfRangeMode>,
 ) -> Option<CastValueKernel> {
     let kernel = match source {
+        CastValueRepr::Complex(source) => {
+            // Complex numbers are cast component-wise
+            return match target {
+                CastValueRepr::Complex(target) => {
+                    select_cast_kernel(*source, *target, rounding, out_of_range)
+                }
+                CastValueRepr::Int { .. }
+                | CastValueRepr::F16
+                | CastValueRepr::BF16
+                | CastValueRepr::F32
+                | CastValueRepr::F64 => None,
+            };
+        }
         CastValueRepr::Int { bits, stored } => {
             if !valid_int_bits(bits, stored) {
                 return None;
@@ -762,6 +781,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn kernel_selection_complex() {
+        const COMPLEX_INT16: CastValueRepr = CastValueRepr::Complex(&CastValueRepr::Int {
+            bits: 16,
+            stored: CastValueIntStored::I16,
+        });
+        const COMPLEX_F32: CastValueRepr = CastValueRepr::Complex(&CastValueRepr::F32);
+        let kernel = select_cast_kernel(
+            COMPLEX_INT16,
+            COMPLEX_F32,
+            CastValueRoundingMode::NearestEven,
+            None,
+        )
+        .unwrap();
+        let source: Vec<u8> = [1i16, -2, 3, -4]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+        let mut output = Vec::new();
+        kernel.cast(&source, &mut output).unwrap();
+        let expected: Vec<u8> = [1f32, -2., 3., -4.]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+        assert_eq!(output, expected);
+
+        for (source, target) in [
+            (COMPLEX_INT16, CastValueRepr::F32),
+            (CastValueRepr::F32, COMPLEX_F32),
+            (INT64, COMPLEX_INT16),
+        ] {
+            assert!(
+                select_cast_kernel(source, target, CastValueRoundingMode::NearestEven, None)
+                    .is_none(),
+                "{source:?} -> {target:?}"
+            );
+        }
+    }
+
     #[test]
     fn kernel_int_to_int_wrap() {
         // int16 -> int8 wrap, matching the spec examples
@@ -0,0 +1,279 @@
+//! Derive macros for the [`zarrs`](https://docs.rs/zarrs/latest/zarrs/) crate.
+//!
+//! These macros are re-exported by `zarrs` with the `derive` feature and should be used through `zarrs`.
+//!
+//! ## Licence
+//! `zarrs_derive` is licensed under either of
+//! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_derive/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
+//! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_derive/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
+//!
+//! Unless you explicitly state otherwise, any contribution intentionally submitted for inclusion in the work by you, as defined in the Apache-2.0 license, shall be dual licensed as above, without any additional terms or conditions.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, Ident, LitStr, Path, Type, parse_macro_input};
+
+/// Derive `Element`, `ElementOwned` and `ElementStructField` for a struct with named fields.
+///
+/// The struct is mapped to the `struct` data type, returned by `ElementStructField::data_type()`.
+/// Each field must implement `ElementStructField`, which includes:
+/// - numeric types, `bool`, and complex numbers,
+/// - fixed size arrays (e.g. `[f32; 3]`), which are mapped to subarray fields, and
+/// - other structs with `#[derive(Element)]`, which are mapped to nested `struct` fields.
+///
+/// Elements are packed without padding, so the struct does not need to be `#[repr(C)]` or `#[repr(packed)]`.
+/// Endianness conversion for the `bytes` codec is handled by the `struct` data type.
+///
+/// # Attributes
+/// - `#[zarrs(rename = "name")]` on a field sets the name of the field in the data type.
+///   Field names must be unique.
+/// - `#[zarrs(crate = "path")]` on the struct sets the path to the `zarrs` crate (default `::zarrs`).
+///
+/// Enums, tuple structs, and generic structs are not supported.
+///
+/// # Example
+/// ```
+/// use zarrs::array::{Element, ElementStructField, data_type};
+///
+/// #[derive(Element, Clone, Copy)]
+/// #[repr(C)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+///     #[zarrs(rename = "tag")]
+///     label: [u8; 4],
+/// }
+///
+/// let data_type = Point::data_type();
+/// assert_eq!(
+///     data_type,
+///     data_type::structured(vec![
+///         data_type::StructField::new("x", data_type::float64()),
+///         data_type::StructField::new("y", data_type::float64()),
+///         data_type::StructField::new_subarray("tag", data_type::uint8(), vec![4]),
+///     ])
+///     .unwrap()
+/// );
+/// assert_eq!(Point::SIZE, 20);
+/// ```
+#[proc_macro_derive(Element, attributes(zarrs))]
+pub fn derive_element(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_element_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A parsed field of a struct with `#[derive(Element)]`.
+struct ElementField {
+    ident: Ident,
+    name: String,
+    name_span: Span,
+    ty: Type,
+}
+
+fn derive_element_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(Element)] does not support generic structs",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[derive(Element)] only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "#[derive(Element)] only supports structs with named fields",
+        ));
+    };
+    if fields.named.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "#[derive(Element)] requires at least one field",
+        ));
+    }
+
+    // Parse the container attributes
+    let mut zarrs: Path = syn::parse_quote!(::zarrs);
+    for attr in &input.attrs {
+        if attr.path().is_ident("zarrs") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    zarrs = meta.value()?.parse::<LitStr>()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported zarrs attribute"))
+                }
+            })?;
+        }
+    }
+
+    let fields = fields
+        .named
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    for (i, field) in fields.iter().enumerate() {
+        if fields[..i].iter().any(|other| other.name == field.name) {
+            return Err(syn::Error::new(
+                field.name_span,
+                format!("duplicate struct field name `{}`", field.name),
+            ));
+        }
+    }
+    Ok(generate(&input.ident, &zarrs, &fields))
+}
+
+fn parse_field(field: &Field) -> syn::Result<ElementField> {
+    let field_ident = field.ident.clone().expect("named field");
+    let mut name = field_ident.to_string();
+    let mut name_span = field_ident.span();
+    for attr in &field.attrs {
+        if attr.path().is_ident("zarrs") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let rename = meta.value()?.parse::<LitStr>()?;
+                    name = rename.value();
+                    name_span = rename.span();
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported zarrs field attribute"))
+                }
+            })?;
+        }
+    }
+    Ok(ElementField {
+        ident: field_ident,
+        name,
+        name_span,
+        ty: field.ty.clone(),
+    })
+}
+
+fn generate(ident: &Ident, zarrs: &Path, fields: &[ElementField]) -> TokenStream2 {
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let names = fields.iter().map(|field| &field.name);
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let field_trait = quote!(#zarrs::array::ElementStructField);
+    let data_type_error = format!("{ident} is a valid struct data type");
+
+    quote! {
+        impl #field_trait for #ident {
+            const SIZE: usize = 0 #(+ <#types as #field_trait>::SIZE)*;
+
+            fn data_type() -> #zarrs::array::DataType {
+                static DATA_TYPE: ::std::sync::LazyLock<#zarrs::array::DataType> =
+                    ::std::sync::LazyLock::new(|| {
+                        #zarrs::array::data_type::structured(::std::vec![
+                            #(#zarrs::array::data_type::StructField::new_subarray(
+                                #names,
+                                <#types as #field_trait>::data_type(),
+                                <#types as #field_trait>::shape(),
+                            )),*
+                        ])
+                        .expect(#data_type_error)
+                    });
+                ::std::clone::Clone::clone(&*DATA_TYPE)
+            }
+
+            fn write_ne_bytes(&self, bytes: &mut [u8]) {
+                let mut offset = 0;
+                #(
+                    let size = <#types as #field_trait>::SIZE;
+                    #field_trait::write_ne_bytes(&self.#idents, &mut bytes[offset..offset + size]);
+                    offset += size;
+                )*
+                let _ = offset;
+            }
+
+            fn read_ne_bytes(
+                bytes: &[u8],
+            ) -> ::std::result::Result<Self, #zarrs::array::ElementError> {
+                let mut offset = 0;
+                #(
+                    let size = <#types as #field_trait>::SIZE;
+                    let #idents = <#types as #field_trait>::read_ne_bytes(&bytes[offset..offset + size])?;
+                    offset += size;
+                )*
+                let _ = offset;
+                ::std::result::Result::Ok(Self { #(#idents),* })
+            }
+        }
+
+        impl #zarrs::array::Element for #ident {
+            fn validate_data_type(
+                data_type: &#zarrs::array::DataType,
+            ) -> ::std::result::Result<(), #zarrs::array::ElementError> {
+                #zarrs::array::struct_element_validate_data_type::<Self>(data_type)
+            }
+
+            fn to_array_bytes<'a>(
+                data_type: &#zarrs::array::DataType,
+                elements: &'a [Self],
+            ) -> ::std::result::Result<#zarrs::array::ArrayBytes<'a>, #zarrs::array::ElementError> {
+                #zarrs::array::struct_element_to_array_bytes(data_type, elements)
+            }
+
+            fn into_array_bytes(
+                data_type: &#zarrs::array::DataType,
+                elements: ::std::vec::Vec<Self>,
+            ) -> ::std::result::Result<#zarrs::array::ArrayBytes<'static>, #zarrs::array::ElementError> {
+                #zarrs::array::struct_element_to_array_bytes(data_type, &elements)
+            }
+        }
+
+        impl #zarrs::array::ElementOwned for #ident {
+            fn from_array_bytes(
+                data_type: &#zarrs::array::DataType,
+                bytes: #zarrs::array::ArrayBytes<'_>,
+            ) -> ::std::result::Result<::std::vec::Vec<Self>, #zarrs::array::ElementError> {
+                #zarrs::array::struct_element_from_array_bytes(data_type, bytes)
+            }
+        }
+    }
+}
+
+/// Structs that `#[derive(Element)]` rejects at compile time.
+///
+/// Enums:
+/// ```compile_fail
+/// #[derive(zarrs::array::Element, Clone, Copy)]
+/// enum Shape {
+///     Circle,
+///     Square,
+/// }
+/// ```
+///
+/// Tuple structs:
+/// ```compile_fail
+/// #[derive(zarrs::array::Element, Clone, Copy)]
+/// struct Point(f64, f64);
+/// ```
+///
+/// Generic structs:
+/// ```compile_fail
+/// #[derive(zarrs::array::Element, Clone, Copy)]
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+/// ```
+///
+/// Duplicate field names:
+/// ```compile_fail
+/// #[derive(zarrs::array::Element, Clone, Copy)]
+/// struct Point {
+///     x: f64,
+///     #[zarrs(rename = "x")]
+///     y: f64,
+/// }
+/// ```
+#[cfg(doctest)]
+#[allow(dead_code)]
+struct CompileFailTests;
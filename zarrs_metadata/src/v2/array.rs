@@ -146,6 +146,8 @@ const fn chunk_key_separator_default_zarr_v2() -> ChunkKeySeparator {
 }
 
 /// Zarr V2 structured data type metadata.
+///
+/// Each field is serialised as a `[fieldname, datatype]` or `[fieldname, datatype, shape]` list, where `datatype` may itself be structured.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(
     from = "DataTypeMetadataV2StructuredTuple",
@@ -155,16 +157,50 @@ pub struct DataTypeMetadataV2Structured {
     /// Field name.
     fieldname: String,
     /// Data type.
-    datatype: String,
+    datatype: DataTypeMetadataV2,
     /// Subarray shape.
     shape: Option<Vec<u64>>,
 }
 
+impl DataTypeMetadataV2Structured {
+    /// Create new structured data type field metadata.
+    #[must_use]
+    pub fn new(
+        fieldname: impl Into<String>,
+        datatype: impl Into<DataTypeMetadataV2>,
+        shape: Option<Vec<u64>>,
+    ) -> Self {
+        Self {
+            fieldname: fieldname.into(),
+            datatype: datatype.into(),
+            shape,
+        }
+    }
+
+    /// Return the field name.
+    #[must_use]
+    pub fn fieldname(&self) -> &str {
+        &self.fieldname
+    }
+
+    /// Return the field data type.
+    #[must_use]
+    pub fn datatype(&self) -> &DataTypeMetadataV2 {
+        &self.datatype
+    }
+
+    /// Return the field subarray shape, if the field is a subarray.
+    #[must_use]
+    pub fn shape(&self) -> Option<&[u64]> {
+        self.shape.as_deref()
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct DataTypeMetadataV2StructuredTuple(
     String,
-    String,
-    #[serde(skip_serializing_if = "Option::is_none")] Option<Vec<u64>>,
+    DataTypeMetadataV2,
+    #[serde(default, skip_serializing_if = "Option::is_none")] Option<Vec<u64>>,
 );
 
 impl From<DataTypeMetadataV2StructuredTuple> for DataTypeMetadataV2Structured {
@@ -205,7 +241,7 @@ pub struct DataTypeMetadataV2EndiannessError(DataTypeMetadataV2);
 /// Get the endianness of a Zarr V2 data type.
 ///
 /// # Errors
-/// Returns a [`DataTypeMetadataV2EndiannessError`] if the data type is not supported or the endianness prefix is invalid.
+/// Returns a [`DataTypeMetadataV2EndiannessError`] if the data type is not supported, the endianness prefix is invalid, or the fields of a structured data type have a different endianness.
 pub fn data_type_metadata_v2_to_endianness(
     data_type: &DataTypeMetadataV2,
 ) -> Result<Option<Endianness>, DataTypeMetadataV2EndiannessError> {
@@ -221,8 +257,20 @@ pub fn data_type_metadata_v2_to_endianness(
                 Err(DataTypeMetadataV2EndiannessError(data_type.clone()))
             }
         }
-        DataTypeMetadataV2::Structured(_) => {
-            Err(DataTypeMetadataV2EndiannessError(data_type.clone()))
+        DataTypeMetadataV2::Structured(fields) => {
+            // The fields must share an endianness, ignoring fields where endianness is not applicable
+            let mut endianness = None;
+            for field in fields {
+                if let Some(field_endianness) =
+                    data_type_metadata_v2_to_endianness(field.datatype())?
+                {
+                    if endianness.is_some_and(|endianness| endianness != field_endianness) {
+                        return Err(DataTypeMetadataV2EndiannessError(data_type.clone()));
+                    }
+                    endianness = Some(field_endianness);
+                }
+            }
+            Ok(endianness)
         }
     }
 }
@@ -265,4 +313,38 @@ mod tests {
             r#"{"node_type":"array","zarr_format":2,"shape":[10000,10000],"chunks":[1000,1000],"dtype":"<f8","compressor":null,"fill_value":"NaN","order":"C","filters":null,"dimension_separator":"."}"#
         );
     }
+
+    #[test]
+    fn structured_data_type() {
+        let json = r#"[["x","<u8"],["y","<f4",[2,3]],["z",[["a","|b1"],["b","<i2"]]]]"#;
+        let data_type: DataTypeMetadataV2 = serde_json::from_str(json).unwrap();
+        let DataTypeMetadataV2::Structured(fields) = &data_type else {
+            panic!("expected a structured data type")
+        };
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0].fieldname(), "x");
+        assert_eq!(fields[0].datatype(), &DataTypeMetadataV2::from("<u8"));
+        assert_eq!(fields[0].shape(), None);
+        assert_eq!(fields[1].shape(), Some([2, 3].as_slice()));
+        assert!(matches!(
+            fields[2].datatype(),
+            DataTypeMetadataV2::Structured(_)
+        ));
+        assert_eq!(serde_json::to_string(&data_type).unwrap(), json);
+        assert_eq!(
+            data_type_metadata_v2_to_endianness(&data_type).unwrap(),
+            Some(Endianness::Little)
+        );
+
+        let data_type: DataTypeMetadataV2 =
+            serde_json::from_str(r#"[["x","|u1"],["y","|b1"]]"#).unwrap();
+        assert_eq!(
+            data_type_metadata_v2_to_endianness(&data_type).unwrap(),
+            None
+        );
+
+        let data_type: DataTypeMetadataV2 =
+            serde_json::from_str(r#"[["x","<u8"],["y",">f4"]]"#).unwrap();
+        assert!(data_type_metadata_v2_to_endianness(&data_type).is_err());
+    }
 }
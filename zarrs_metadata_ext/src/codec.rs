@@ -40,14 +40,28 @@ pub use registered::*;
 
 /// `zarrs` codec metadata.
 mod zarrs {
+    /// `aead` codec metadata (`zarrs` experimental).
+    pub mod aead;
+    /// `bitshuffle` codec metadata (`zarrs` experimental).
+    pub mod bitshuffle;
+    /// `brotli` codec metadata (`zarrs` experimental).
+    pub mod brotli;
+    /// `digest` codec metadata (`zarrs` experimental).
+    pub mod digest;
     /// `gdeflate` codec metadata (`zarrs` experimental).
     pub mod gdeflate;
+    /// `rle` codec metadata (`zarrs` experimental).
+    pub mod rle;
+    /// `snappy` codec metadata (`zarrs` experimental).
+    pub mod snappy;
     /// `squeeze` codec metadata (`zarrs` experimental).
     pub mod squeeze;
     /// `vlen` codec metadata (`zarrs` experimental).
     pub mod vlen;
     /// `vlen_v2` codec metadata (`zarrs` experimental).
     pub mod vlen_v2;
+    /// `zstd_dictionary` codec metadata (`zarrs` experimental).
+    pub mod zstd_dictionary;
 }
 pub use zarrs::*;
 
@@ -59,12 +73,24 @@ mod numcodecs {
     pub mod bitround;
     /// `bz2` codec metadata (`numcodecs`).
     pub mod bz2;
+    /// `crc32` codec metadata (`numcodecs`).
+    pub mod crc32;
     /// `fixedscaleoffset` codec metadata (`numcodecs`).
     pub mod fixedscaleoffset;
     /// `fletcher32` codec metadata (`numcodecs`).
     pub mod fletcher32;
+    /// `jenkins_lookup3` codec metadata (`numcodecs`).
+    pub mod jenkins_lookup3;
+    /// `imagecodecs_jpeg` codec metadata (`numcodecs`, `imagecodecs`).
+    pub mod jpeg;
+    /// `json2` codec metadata (`numcodecs`).
+    pub mod json2;
+    /// `msgpack2` codec metadata (`numcodecs`).
+    pub mod msgpack2;
     /// `pcodec` codec metadata (`numcodecs`).
     pub mod pcodec;
+    /// `imagecodecs_png` codec metadata (`numcodecs`, `imagecodecs`).
+    pub mod png;
     /// `shuffle` codec metadata (`numcodecs`).
     pub mod shuffle;
     /// `vlen-array` codec metadata (`numcodecs`).
@@ -0,0 +1,108 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `crc32` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum Crc32CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(Crc32CodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for Crc32CodecConfiguration {}
+
+/// `crc32` codec configuration parameters (version 1.0 draft).
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "numcodecs.crc32",
+///     "configuration": {}
+/// }
+/// ```
+///
+/// ```json
+/// {
+///     "name": "numcodecs.crc32",
+///     "configuration": {
+///         "location": "end"
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Crc32CodecConfigurationV1 {
+    /// The location of the checksum.
+    #[serde(
+        default = "checksum_location_default",
+        skip_serializing_if = "checksum_location_is_default"
+    )]
+    pub location: Crc32CodecConfigurationChecksumLocation,
+}
+
+/// The location of the checksum.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Crc32CodecConfigurationChecksumLocation {
+    #[default]
+    /// The checksum is encoded at the start of the byte sequence.
+    Start,
+    /// The checksum is encoded at the end of the byte sequence.
+    End,
+}
+
+fn checksum_location_default() -> Crc32CodecConfigurationChecksumLocation {
+    Crc32CodecConfigurationChecksumLocation::default()
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn checksum_location_is_default(location: &Crc32CodecConfigurationChecksumLocation) -> bool {
+    *location == Crc32CodecConfigurationChecksumLocation::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use zarrs_metadata::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn codec_crc32_config1() {
+        serde_json::from_str::<Crc32CodecConfiguration>(r"{}").unwrap();
+    }
+
+    #[test]
+    fn codec_crc32_config_location() {
+        let configuration =
+            serde_json::from_str::<Crc32CodecConfiguration>(r#"{"location":"end"}"#).unwrap();
+        assert_eq!(
+            configuration,
+            Crc32CodecConfiguration::V1(Crc32CodecConfigurationV1 {
+                location: Crc32CodecConfigurationChecksumLocation::End
+            })
+        );
+    }
+
+    #[test]
+    fn codec_crc32_config_outer1() {
+        serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "numcodecs.crc32",
+            "configuration": {
+                "location": "end"
+            }
+        }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_crc32_config_invalid() {
+        assert!(
+            serde_json::from_str::<Crc32CodecConfiguration>(r#"{"location":"middle"}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `jenkins_lookup3` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum JenkinsLookup3CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(JenkinsLookup3CodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for JenkinsLookup3CodecConfiguration {}
+
+/// `jenkins_lookup3` codec configuration parameters (version 1.0 draft).
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "numcodecs.jenkins_lookup3",
+///     "configuration": {
+///         "initval": 0,
+///         "prefix": null
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct JenkinsLookup3CodecConfigurationV1 {
+    /// The initial value (seed) of the hash. Defaults to 0.
+    #[serde(default)]
+    pub initval: u32,
+    /// Bytes prepended to the input before evaluating the hash. The prefix is not stored in the encoded output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use zarrs_metadata::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn codec_jenkins_lookup3_config1() {
+        let configuration =
+            serde_json::from_str::<JenkinsLookup3CodecConfiguration>(r"{}").unwrap();
+        assert_eq!(
+            configuration,
+            JenkinsLookup3CodecConfiguration::V1(JenkinsLookup3CodecConfigurationV1::default())
+        );
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_config_numcodecs() {
+        let configuration = serde_json::from_str::<JenkinsLookup3CodecConfiguration>(
+            r#"{"initval": 5, "prefix": null}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            configuration,
+            JenkinsLookup3CodecConfiguration::V1(JenkinsLookup3CodecConfigurationV1 {
+                initval: 5,
+                prefix: None
+            })
+        );
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_config_outer1() {
+        serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "numcodecs.jenkins_lookup3",
+            "configuration": {
+                "initval": 0,
+                "prefix": [1, 2, 3]
+            }
+        }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_config_invalid() {
+        assert!(
+            serde_json::from_str::<JenkinsLookup3CodecConfiguration>(r#"{"initval": -1}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `imagecodecs_jpeg` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum JpegCodecConfiguration {
+    /// Version 1.0.
+    V1(JpegCodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for JpegCodecConfiguration {}
+
+/// `imagecodecs_jpeg` codec configuration parameters (version 1.0).
+///
+/// Only baseline 8-bit lossy JPEG is supported.
+/// Other `imagecodecs` parameters that only influence encoding are preserved but otherwise ignored.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "numcodecs.imagecodecs_jpeg",
+///     "configuration": {
+///         "level": 90
+///     }
+/// }
+/// ```
+///
+/// ### Example (Zarr V2)
+/// ```json
+/// {
+///     "id": "imagecodecs_jpeg",
+///     "level": 90,
+///     "lossless": false,
+///     "bitspersample": null
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct JpegCodecConfigurationV1 {
+    /// The JPEG quality (1-100).
+    ///
+    /// The encoder default (75) is used if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<u8>,
+    /// Whether to use lossless JPEG. Only `false` is supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lossless: Option<bool>,
+    /// The number of bits per sample. Only `8` is supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bitspersample: Option<u8>,
+    /// JPEG tables for abbreviated streams. Not supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tables: Option<serde_json::Value>,
+    /// A JPEG header for abbreviated streams. Not supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<serde_json::Value>,
+    /// The colorspace of the data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colorspace_data: Option<serde_json::Value>,
+    /// The colorspace of the JPEG stream.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colorspace_jpeg: Option<serde_json::Value>,
+    /// The chroma subsampling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subsampling: Option<serde_json::Value>,
+    /// Whether to optimise Huffman tables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimize: Option<bool>,
+    /// The smoothing factor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smoothing: Option<serde_json::Value>,
+    /// The lossless predictor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predictor: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_jpeg_config() {
+        serde_json::from_str::<JpegCodecConfiguration>(r"{}").unwrap();
+        let JpegCodecConfiguration::V1(configuration) = serde_json::from_str::<
+            JpegCodecConfiguration,
+        >(
+            r#"{"level":90,"tables":null,"header":null,"colorspace_data":null,"colorspace_jpeg":null,"subsampling":null,"optimize":null,"smoothing":null,"lossless":null,"predictor":null,"bitspersample":null}"#,
+        )
+        .unwrap();
+        assert_eq!(configuration.level, Some(90));
+    }
+
+    #[test]
+    fn codec_jpeg_config_invalid() {
+        assert!(serde_json::from_str::<JpegCodecConfiguration>(r#"{"quality":9}"#).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `json2` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum Json2CodecConfiguration {
+    /// Version 1.0.
+    V1(Json2CodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for Json2CodecConfiguration {}
+
+/// `json2` codec configuration parameters (version 1.0).
+///
+/// These mirror the parameters of the `numcodecs` `JSON` codec.
+/// `skipkeys`, `check_circular`, `allow_nan`, and `strict` do not influence encoding or decoding in `zarrs`.
+///
+/// ### Example (Zarr V2)
+/// ```json
+/// {
+///     "id": "json2",
+///     "encoding": "utf-8",
+///     "skipkeys": false,
+///     "ensure_ascii": true,
+///     "check_circular": true,
+///     "allow_nan": true,
+///     "indent": null,
+///     "separators": [",", ":"],
+///     "sort_keys": true,
+///     "strict": true
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Json2CodecConfigurationV1 {
+    /// The text encoding. Only `utf-8` is supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// Skip object keys that are not of a basic type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipkeys: Option<bool>,
+    /// Escape non-ASCII characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ensure_ascii: Option<bool>,
+    /// Check for circular references.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_circular: Option<bool>,
+    /// Allow non-finite floating point values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_nan: Option<bool>,
+    /// The indentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indent: Option<serde_json::Value>,
+    /// The item and key separators.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub separators: Option<Vec<String>>,
+    /// Sort the keys of objects. Defaults to `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_keys: Option<bool>,
+    /// Disallow control characters in strings when decoding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_json2_config() {
+        serde_json::from_str::<Json2CodecConfiguration>(r"{}").unwrap();
+        let Json2CodecConfiguration::V1(configuration) =
+            serde_json::from_str::<Json2CodecConfiguration>(
+                r#"{"encoding":"utf-8","skipkeys":false,"ensure_ascii":true,"check_circular":true,"allow_nan":true,"indent":null,"separators":[",",":"],"sort_keys":true,"strict":true}"#,
+            )
+            .unwrap();
+        assert_eq!(configuration.sort_keys, Some(true));
+        assert!(serde_json::from_str::<Json2CodecConfiguration>(r#"{"unknown":1}"#).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `msgpack2` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum MsgPack2CodecConfiguration {
+    /// Version 1.0.
+    V1(MsgPack2CodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for MsgPack2CodecConfiguration {}
+
+/// `msgpack2` codec configuration parameters (version 1.0).
+///
+/// These mirror the parameters of the `numcodecs` `MsgPack` codec.
+///
+/// ### Example (Zarr V2)
+/// ```json
+/// {
+///     "id": "msgpack2",
+///     "use_single_float": false,
+///     "use_bin_type": true,
+///     "raw": false
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct MsgPack2CodecConfigurationV1 {
+    /// Encode floating point values with single precision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_single_float: Option<bool>,
+    /// Use the bin type for bytes (and the str type for strings).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_bin_type: Option<bool>,
+    /// Decode raw (str type) values as bytes rather than strings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_msgpack2_config() {
+        serde_json::from_str::<MsgPack2CodecConfiguration>(r"{}").unwrap();
+        let MsgPack2CodecConfiguration::V1(configuration) =
+            serde_json::from_str::<MsgPack2CodecConfiguration>(
+                r#"{"use_single_float":false,"use_bin_type":true,"raw":false}"#,
+            )
+            .unwrap();
+        assert_eq!(configuration.use_bin_type, Some(true));
+        assert!(serde_json::from_str::<MsgPack2CodecConfiguration>(r#"{"unknown":1}"#).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `imagecodecs_png` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum PngCodecConfiguration {
+    /// Version 1.0.
+    V1(PngCodecConfigurationV1),
+}
+
+impl ConfigurationSerialize for PngCodecConfiguration {}
+
+/// `imagecodecs_png` codec configuration parameters (version 1.0).
+///
+/// The `strategy` and `filter` parameters of `imagecodecs` only influence encoding and are preserved but otherwise ignored.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "numcodecs.imagecodecs_png",
+///     "configuration": {
+///         "level": 6
+///     }
+/// }
+/// ```
+///
+/// ### Example (Zarr V2)
+/// ```json
+/// {
+///     "id": "imagecodecs_png",
+///     "level": null
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct PngCodecConfigurationV1 {
+    /// The deflate compression level (0-9).
+    ///
+    /// The encoder default is used if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<u8>,
+    /// The `zlib` compression strategy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<i64>,
+    /// The PNG row filter flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_png_config() {
+        serde_json::from_str::<PngCodecConfiguration>(r"{}").unwrap();
+        let configuration =
+            serde_json::from_str::<PngCodecConfiguration>(r#"{"level":9}"#).unwrap();
+        assert_eq!(
+            configuration,
+            PngCodecConfiguration::V1(PngCodecConfigurationV1 {
+                level: Some(9),
+                ..Default::default()
+            })
+        );
+        serde_json::from_str::<PngCodecConfiguration>(
+            r#"{"level":null,"strategy":null,"filter":null}"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_png_config_invalid() {
+        assert!(serde_json::from_str::<PngCodecConfiguration>(r#"{"quality":9}"#).is_err());
+    }
+}
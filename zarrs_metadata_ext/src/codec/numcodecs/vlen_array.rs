@@ -17,4 +17,10 @@ impl ConfigurationSerialize for VlenArrayCodecConfiguration {}
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
 #[serde(deny_unknown_fields)]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
-pub struct VlenArrayCodecConfigurationV1 {}
+pub struct VlenArrayCodecConfigurationV1 {
+    /// The `numpy` data type of the array items (e.g. `<i4`).
+    ///
+    /// This is always written by `numcodecs`, but the items are interpreted by the array data type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dtype: Option<String>,
+}
@@ -0,0 +1,118 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `aead` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum AeadCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(AeadCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for AeadCodecConfiguration {}
+
+/// `aead` codec configuration parameters (version 0.0 draft).
+///
+/// The encryption key is not part of the configuration.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "zarrs.aead",
+///     "configuration": {
+///         "algorithm": "aes-256-gcm",
+///         "nonce": "random"
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct AeadCodecConfigurationV0 {
+    /// The AEAD algorithm.
+    pub algorithm: AeadAlgorithm,
+    /// How per-chunk nonces are generated.
+    ///
+    /// Defaults to [`AeadNonceMode::Random`] if unspecified.
+    #[serde(default)]
+    pub nonce: AeadNonceMode,
+}
+
+/// An authenticated encryption with associated data (AEAD) algorithm.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[non_exhaustive]
+pub enum AeadAlgorithm {
+    /// AES-256 in Galois/Counter Mode, with a 12 byte nonce and 16 byte tag.
+    #[serde(rename = "aes-256-gcm")]
+    #[display("aes-256-gcm")]
+    Aes256Gcm,
+    /// ChaCha20-Poly1305 ([RFC 8439](https://datatracker.ietf.org/doc/html/rfc8439)), with a 12 byte nonce and 16 byte tag.
+    #[serde(rename = "chacha20-poly1305")]
+    #[display("chacha20-poly1305")]
+    ChaCha20Poly1305,
+}
+
+/// The `aead` codec nonce generation mode.
+///
+/// In either mode, the nonce is stored at the start of each encoded chunk.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display, Default)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AeadNonceMode {
+    /// A random nonce is generated for each encoded chunk.
+    #[default]
+    #[display("random")]
+    Random,
+    /// The nonce is derived from a keyed hash of the chunk plaintext.
+    ///
+    /// Encoding is deterministic: chunks with identical content have identical encoded bytes.
+    /// This reveals which chunks are equal to anyone with read access to the store, even without the key.
+    #[display("derived")]
+    Derived,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_aead_valid1() {
+        let configuration = serde_json::from_str::<AeadCodecConfiguration>(
+            r#"{"algorithm":"chacha20-poly1305","nonce":"derived"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            configuration,
+            AeadCodecConfiguration::V0(AeadCodecConfigurationV0 {
+                algorithm: AeadAlgorithm::ChaCha20Poly1305,
+                nonce: AeadNonceMode::Derived,
+            })
+        );
+    }
+
+    #[test]
+    fn codec_aead_valid_default_nonce() {
+        let configuration =
+            serde_json::from_str::<AeadCodecConfiguration>(r#"{"algorithm":"aes-256-gcm"}"#)
+                .unwrap();
+        assert_eq!(
+            configuration.to_string(),
+            r#"{"algorithm":"aes-256-gcm","nonce":"random"}"#
+        );
+    }
+
+    #[test]
+    fn codec_aead_invalid() {
+        assert!(serde_json::from_str::<AeadCodecConfiguration>(r"{}").is_err());
+        assert!(
+            serde_json::from_str::<AeadCodecConfiguration>(r#"{"algorithm":"aes-128-cbc"}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<AeadCodecConfiguration>(
+            r#"{"algorithm":"aes-256-gcm","key":"0011"}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `bitshuffle` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum BitshuffleCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(BitshuffleCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for BitshuffleCodecConfiguration {}
+
+/// The `bitshuffle` compressor applied to each bit-shuffled block.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BitshuffleCompressor {
+    /// No compression.
+    #[default]
+    None,
+    /// [LZ4](https://lz4.org/) block compression, matching the HDF5 bitshuffle filter.
+    LZ4,
+}
+
+/// `bitshuffle` codec configuration parameters (version 0.0 draft).
+///
+/// ### Example: bit shuffle 4 byte elements and compress each block with LZ4
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "elementsize": 4,
+///     "blocksize": 0,
+///     "cname": "lz4"
+/// }
+/// # "#;
+/// # use zarrs_metadata_ext::codec::bitshuffle::BitshuffleCodecConfigurationV0;
+/// # let configuration: BitshuffleCodecConfigurationV0 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct BitshuffleCodecConfigurationV0 {
+    /// The element size in bytes.
+    ///
+    /// Defaults to the size of the data type if unspecified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elementsize: Option<usize>,
+    /// The number of elements in each block. Automatically determined if 0.
+    ///
+    /// Must be a multiple of 8 if non-zero.
+    #[serde(default)]
+    pub blocksize: usize,
+    /// The compressor applied to each block.
+    ///
+    /// Defaults to none if unspecified.
+    #[serde(default)]
+    pub cname: BitshuffleCompressor,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_bitshuffle_valid1() {
+        let json = r#"
+        {
+            "elementsize": 4,
+            "blocksize": 0,
+            "cname": "lz4"
+        }"#;
+        let configuration = serde_json::from_str::<BitshuffleCodecConfiguration>(json).unwrap();
+        let BitshuffleCodecConfiguration::V0(configuration) = configuration;
+        assert_eq!(configuration.cname, BitshuffleCompressor::LZ4);
+    }
+
+    #[test]
+    fn codec_bitshuffle_valid_defaults() {
+        let json = "{}";
+        let configuration = serde_json::from_str::<BitshuffleCodecConfigurationV0>(json).unwrap();
+        assert_eq!(configuration.elementsize, None);
+        assert_eq!(configuration.blocksize, 0);
+        assert_eq!(configuration.cname, BitshuffleCompressor::None);
+    }
+
+    #[test]
+    fn codec_bitshuffle_invalid_cname() {
+        let json = r#"
+        {
+            "elementsize": 2,
+            "cname": "zstd"
+        }"#;
+        assert!(serde_json::from_str::<BitshuffleCodecConfiguration>(json).is_err());
+    }
+}
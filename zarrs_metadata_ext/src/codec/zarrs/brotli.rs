@@ -0,0 +1,184 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Deserializer, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `brotli` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum BrotliCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(BrotliCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for BrotliCodecConfiguration {}
+
+/// `brotli` codec configuration parameters (version 0.0 draft).
+///
+/// ### Example: encode with a compression level of 11 and a 4 MiB window
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "level": 11,
+///     "lgwin": 22
+/// }
+/// # "#;
+/// # use zarrs_metadata_ext::codec::brotli::BrotliCodecConfigurationV0;
+/// # let configuration: BrotliCodecConfigurationV0 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct BrotliCodecConfigurationV0 {
+    /// The compression level (quality).
+    pub level: BrotliCompressionLevel,
+    /// The base 2 logarithm of the sliding window size.
+    pub lgwin: BrotliWindowLog,
+}
+
+/// An integer from 0 to 11 controlling the compression level.
+///
+/// A level of 0 is the fastest compression method and produces the least compression, while 11 is slowest and produces the most compression.
+#[derive(Serialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BrotliCompressionLevel(u32);
+
+/// An invalid brotli compression level.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid brotli compression level {0}, must be 0-11")]
+pub struct BrotliCompressionLevelError(u32);
+
+macro_rules! brotli_compression_level_try_from {
+    ( $t:ty ) => {
+        impl TryFrom<$t> for BrotliCompressionLevel {
+            type Error = BrotliCompressionLevelError;
+            fn try_from(level: $t) -> Result<Self, Self::Error> {
+                let level = u32::from(level);
+                if level <= 11 {
+                    Ok(Self(level))
+                } else {
+                    Err(BrotliCompressionLevelError(level))
+                }
+            }
+        }
+    };
+}
+
+brotli_compression_level_try_from!(u8);
+brotli_compression_level_try_from!(u16);
+brotli_compression_level_try_from!(u32);
+
+impl<'de> Deserialize<'de> for BrotliCompressionLevel {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let level = u32::deserialize(d)?;
+        if level <= 11 {
+            Ok(Self(level))
+        } else {
+            Err(serde::de::Error::custom(
+                "brotli compression level must be between 0 and 11",
+            ))
+        }
+    }
+}
+
+impl BrotliCompressionLevel {
+    /// The underlying integer compression level.
+    #[must_use]
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+/// An integer from 10 to 24 controlling the base 2 logarithm of the sliding window size.
+///
+/// Larger windows can improve the compression ratio at the expense of memory usage during encoding and decoding.
+#[derive(Serialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BrotliWindowLog(u32);
+
+/// An invalid brotli window log.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid brotli window log {0}, must be 10-24")]
+pub struct BrotliWindowLogError(u32);
+
+macro_rules! brotli_window_log_try_from {
+    ( $t:ty ) => {
+        impl TryFrom<$t> for BrotliWindowLog {
+            type Error = BrotliWindowLogError;
+            fn try_from(lgwin: $t) -> Result<Self, Self::Error> {
+                let lgwin = u32::from(lgwin);
+                if (10..=24).contains(&lgwin) {
+                    Ok(Self(lgwin))
+                } else {
+                    Err(BrotliWindowLogError(lgwin))
+                }
+            }
+        }
+    };
+}
+
+brotli_window_log_try_from!(u8);
+brotli_window_log_try_from!(u16);
+brotli_window_log_try_from!(u32);
+
+impl<'de> Deserialize<'de> for BrotliWindowLog {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let lgwin = u32::deserialize(d)?;
+        if (10..=24).contains(&lgwin) {
+            Ok(Self(lgwin))
+        } else {
+            Err(serde::de::Error::custom(
+                "brotli window log must be between 10 and 24",
+            ))
+        }
+    }
+}
+
+impl Default for BrotliWindowLog {
+    fn default() -> Self {
+        Self(22)
+    }
+}
+
+impl BrotliWindowLog {
+    /// The underlying integer window log.
+    #[must_use]
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_brotli_valid1() {
+        let json = r#"
+        {
+            "level": 11,
+            "lgwin": 22
+        }"#;
+        assert!(serde_json::from_str::<BrotliCodecConfiguration>(json).is_ok());
+    }
+
+    #[test]
+    fn codec_brotli_invalid_level() {
+        let json = r#"
+        {
+            "level": 12,
+            "lgwin": 22
+        }"#;
+        let codec_configuration = serde_json::from_str::<BrotliCodecConfiguration>(json);
+        assert!(codec_configuration.is_err());
+    }
+
+    #[test]
+    fn codec_brotli_invalid_lgwin() {
+        let json = r#"
+        {
+            "level": 5,
+            "lgwin": 25
+        }"#;
+        let codec_configuration = serde_json::from_str::<BrotliCodecConfiguration>(json);
+        assert!(codec_configuration.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `digest` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum DigestCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(DigestCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for DigestCodecConfiguration {}
+
+/// `digest` codec configuration parameters (version 0.0 draft).
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "zarrs.digest",
+///     "configuration": {
+///         "algorithm": "sha256"
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct DigestCodecConfigurationV0 {
+    /// The digest algorithm.
+    pub algorithm: DigestAlgorithm,
+    /// Whether the digest is keyed (HMAC-SHA256 or keyed BLAKE3).
+    ///
+    /// The key is supplied at runtime and is not part of the configuration.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub keyed: bool,
+}
+
+/// A cryptographic digest algorithm.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum DigestAlgorithm {
+    /// [SHA-256](https://csrc.nist.gov/pubs/fips/180-4/upd1/final), a 32 byte digest.
+    #[display("sha256")]
+    Sha256,
+    /// [BLAKE3](https://github.com/BLAKE3-team/BLAKE3-specs), a 32 byte digest.
+    #[display("blake3")]
+    Blake3,
+}
+
+impl DigestAlgorithm {
+    /// Return the size of the digest in bytes.
+    #[must_use]
+    pub const fn digest_size(&self) -> usize {
+        match self {
+            Self::Sha256 | Self::Blake3 => 32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_digest_valid1() {
+        let configuration =
+            serde_json::from_str::<DigestCodecConfiguration>(r#"{"algorithm":"sha256"}"#).unwrap();
+        assert_eq!(
+            configuration,
+            DigestCodecConfiguration::V0(DigestCodecConfigurationV0 {
+                algorithm: DigestAlgorithm::Sha256,
+                keyed: false,
+            })
+        );
+    }
+
+    #[test]
+    fn codec_digest_valid2() {
+        let configuration =
+            serde_json::from_str::<DigestCodecConfiguration>(r#"{"algorithm":"blake3"}"#).unwrap();
+        assert_eq!(configuration.to_string(), r#"{"algorithm":"blake3"}"#);
+    }
+
+    #[test]
+    fn codec_digest_valid_keyed() {
+        let configuration = serde_json::from_str::<DigestCodecConfiguration>(
+            r#"{"algorithm":"blake3","keyed":true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            configuration,
+            DigestCodecConfiguration::V0(DigestCodecConfigurationV0 {
+                algorithm: DigestAlgorithm::Blake3,
+                keyed: true,
+            })
+        );
+        assert_eq!(
+            configuration.to_string(),
+            r#"{"algorithm":"blake3","keyed":true}"#
+        );
+    }
+
+    #[test]
+    fn codec_digest_invalid1() {
+        assert!(serde_json::from_str::<DigestCodecConfiguration>(r"{}").is_err());
+        assert!(
+            serde_json::from_str::<DigestCodecConfiguration>(r#"{"algorithm":"md5"}"#).is_err()
+        );
+    }
+}
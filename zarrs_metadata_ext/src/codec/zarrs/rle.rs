@@ -0,0 +1,20 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `rle` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum RleCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(RleCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for RleCodecConfiguration {}
+
+/// `rle` codec configuration parameters (version 0.0 draft).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct RleCodecConfigurationV0 {}
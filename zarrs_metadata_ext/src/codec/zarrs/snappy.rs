@@ -0,0 +1,39 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// A wrapper to handle various versions of `snappy` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum SnappyCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(SnappyCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for SnappyCodecConfiguration {}
+
+/// `snappy` codec configuration parameters (version 0.0 draft).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct SnappyCodecConfigurationV0 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_snappy_valid1() {
+        assert!(serde_json::from_str::<SnappyCodecConfiguration>("{}").is_ok());
+    }
+
+    #[test]
+    fn codec_snappy_invalid1() {
+        let json = r#"
+        {
+            "level": 1
+        }"#;
+        assert!(serde_json::from_str::<SnappyCodecConfiguration>(json).is_err());
+    }
+}
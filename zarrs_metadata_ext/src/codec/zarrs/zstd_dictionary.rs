@@ -0,0 +1,82 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+use crate::codec::zstd::ZstdCompressionLevel;
+
+/// A wrapper to handle various versions of `zstd_dictionary` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum ZstdDictionaryCodecConfiguration {
+    /// Version 0.0 draft.
+    V0(ZstdDictionaryCodecConfigurationV0),
+}
+
+impl ConfigurationSerialize for ZstdDictionaryCodecConfiguration {}
+
+/// `zstd_dictionary` codec configuration parameters (version 0.0 draft).
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "name": "zarrs.zstd_dictionary",
+///     "configuration": {
+///         "level": 3,
+///         "checksum": false,
+///         "dictionary": "zstd.dict"
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ZstdDictionaryCodecConfigurationV0 {
+    /// The compression level.
+    pub level: ZstdCompressionLevel,
+    /// A boolean that indicates whether to store a checksum when writing that will be verified when reading.
+    pub checksum: bool,
+    /// The key of the compression dictionary relative to the array, e.g. `"zstd.dict"`.
+    pub dictionary: String,
+}
+
+impl ZstdDictionaryCodecConfigurationV0 {
+    /// Create a new `zstd_dictionary` codec configuration.
+    #[must_use]
+    pub fn new(level: ZstdCompressionLevel, checksum: bool, dictionary: impl Into<String>) -> Self {
+        Self {
+            level,
+            checksum,
+            dictionary: dictionary.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_zstd_dictionary_valid() {
+        const JSON: &str = r#"{"level":3,"checksum":false,"dictionary":"zstd.dict"}"#;
+        let configuration = serde_json::from_str::<ZstdDictionaryCodecConfiguration>(JSON).unwrap();
+        assert_eq!(
+            configuration,
+            ZstdDictionaryCodecConfigurationV0::new(
+                ZstdCompressionLevel::new(3),
+                false,
+                "zstd.dict"
+            )
+            .into()
+        );
+        assert_eq!(configuration.to_string(), JSON);
+    }
+
+    #[test]
+    fn codec_zstd_dictionary_invalid() {
+        assert!(serde_json::from_str::<ZstdDictionaryCodecConfiguration>(
+            r#"{"level":3,"checksum":false}"#
+        )
+        .is_err());
+    }
+}
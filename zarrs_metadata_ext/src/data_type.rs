@@ -2,10 +2,15 @@
 //!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/index.html#array-metadata-data-type>.
 
+pub mod categorical;
+pub mod decimal;
 pub mod fixed_length_utf32;
+pub mod list;
+pub mod null_terminated_bytes;
 pub mod numpy_datetime64;
 pub mod numpy_timedelta64;
 pub mod optional;
+pub mod structured;
 
 mod numpy_time_unit;
 pub use numpy_time_unit::NumpyTimeUnit;
@@ -0,0 +1,105 @@
+//! `zarrs.categorical` data type metadata.
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// The `zarrs.categorical` data type configuration (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct CategoricalDataTypeConfigurationV1 {
+    /// The category table.
+    ///
+    /// Elements are stored as the index of their category in this table.
+    pub categories: Vec<String>,
+    /// The data type of the stored category codes.
+    pub code_data_type: CategoricalCodeDataType,
+}
+
+impl ConfigurationSerialize for CategoricalDataTypeConfigurationV1 {}
+
+/// The data type of the category codes of the `zarrs.categorical` data type.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum CategoricalCodeDataType {
+    /// `uint8` codes, supporting up to 256 categories.
+    #[display("uint8")]
+    UInt8,
+    /// `uint16` codes, supporting up to 65536 categories.
+    #[display("uint16")]
+    UInt16,
+    /// `uint32` codes, supporting up to 4294967296 categories.
+    #[display("uint32")]
+    UInt32,
+}
+
+impl CategoricalCodeDataType {
+    /// Returns the size of a code in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::UInt8 => 1,
+            Self::UInt16 => 2,
+            Self::UInt32 => 4,
+        }
+    }
+
+    /// Returns the smallest code data type supporting `num_categories` categories, if any.
+    #[must_use]
+    pub fn for_num_categories(num_categories: usize) -> Option<Self> {
+        let num_categories = u64::try_from(num_categories).ok()?;
+        if num_categories <= 1 << 8 {
+            Some(Self::UInt8)
+        } else if num_categories <= 1 << 16 {
+            Some(Self::UInt16)
+        } else if num_categories <= 1 << 32 {
+            Some(Self::UInt32)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_config() {
+        let config = r#"{"categories":["a","b","c"],"code_data_type":"uint8"}"#;
+        let configuration: CategoricalDataTypeConfigurationV1 =
+            serde_json::from_str(config).unwrap();
+        assert_eq!(configuration.categories, ["a", "b", "c"]);
+        assert_eq!(configuration.code_data_type, CategoricalCodeDataType::UInt8);
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), config);
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(serde_json::from_str::<CategoricalDataTypeConfigurationV1>(
+            r#"{"categories":["a"]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<CategoricalDataTypeConfigurationV1>(
+            r#"{"categories":["a"],"code_data_type":"int8"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn code_data_type_for_num_categories() {
+        assert_eq!(
+            CategoricalCodeDataType::for_num_categories(256),
+            Some(CategoricalCodeDataType::UInt8)
+        );
+        assert_eq!(
+            CategoricalCodeDataType::for_num_categories(257),
+            Some(CategoricalCodeDataType::UInt16)
+        );
+        assert_eq!(
+            CategoricalCodeDataType::for_num_categories(65537),
+            Some(CategoricalCodeDataType::UInt32)
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! `zarrs.decimal` data type metadata.
+
+use std::num::NonZeroU32;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// The `zarrs.decimal` data type configuration (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct DecimalDataTypeConfigurationV1 {
+    /// The maximum number of significant decimal digits.
+    pub precision: NonZeroU32,
+    /// The number of decimal digits after the decimal point.
+    pub scale: u32,
+}
+
+impl ConfigurationSerialize for DecimalDataTypeConfigurationV1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_config() {
+        let config: DecimalDataTypeConfigurationV1 =
+            serde_json::from_str(r#"{"precision":10,"scale":2}"#).unwrap();
+        assert_eq!(config.precision, NonZeroU32::new(10).unwrap());
+        assert_eq!(config.scale, 2);
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(
+            serde_json::from_str::<DecimalDataTypeConfigurationV1>(r#"{"precision":10}"#).is_err()
+        );
+        assert!(serde_json::from_str::<DecimalDataTypeConfigurationV1>(
+            r#"{"precision":0,"scale":0}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! `zarrs.list` data type metadata.
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::ConfigurationSerialize;
+
+/// The `zarrs.list` data type configuration (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ListDataTypeConfigurationV1 {
+    /// The data type of the list items.
+    pub data_type: MetadataV3,
+}
+
+impl ConfigurationSerialize for ListDataTypeConfigurationV1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_config() {
+        let config = r#"{"data_type":"int32"}"#;
+        let configuration: ListDataTypeConfigurationV1 = serde_json::from_str(config).unwrap();
+        assert_eq!(configuration.data_type.name(), "int32");
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), config);
+
+        let config = r#"{"data_type":{"name":"numpy.datetime64","configuration":{"unit":"s","scale_factor":1}}}"#;
+        let configuration: ListDataTypeConfigurationV1 = serde_json::from_str(config).unwrap();
+        assert_eq!(configuration.data_type.name(), "numpy.datetime64");
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), config);
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(serde_json::from_str::<ListDataTypeConfigurationV1>("{}").is_err());
+        assert!(serde_json::from_str::<ListDataTypeConfigurationV1>(
+            r#"{"data_type":"int32","length":2}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,41 @@
+//! `null_terminated_bytes` data type metadata.
+
+use std::num::NonZeroU64;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::ConfigurationSerialize;
+
+/// The `null_terminated_bytes` data type configuration (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct NullTerminatedBytesDataTypeConfigurationV1 {
+    /// The length of each element in bytes.
+    pub length_bytes: NonZeroU64,
+}
+
+impl ConfigurationSerialize for NullTerminatedBytesDataTypeConfigurationV1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_config() {
+        let config: NullTerminatedBytesDataTypeConfigurationV1 =
+            serde_json::from_str(r#"{"length_bytes":16}"#).unwrap();
+        assert_eq!(config.length_bytes, NonZeroU64::new(16).unwrap());
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(serde_json::from_str::<NullTerminatedBytesDataTypeConfigurationV1>(r"{}").is_err());
+        assert!(
+            serde_json::from_str::<NullTerminatedBytesDataTypeConfigurationV1>(
+                r#"{"length_bytes":0}"#
+            )
+            .is_err()
+        );
+    }
+}
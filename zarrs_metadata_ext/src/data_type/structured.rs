@@ -0,0 +1,64 @@
+//! `struct` data type metadata.
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use zarrs_metadata::v3::MetadataV3;
+use zarrs_metadata::ConfigurationSerialize;
+
+/// The `struct` data type configuration (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct StructDataTypeConfigurationV1 {
+    /// The fields of the struct, in order.
+    pub fields: Vec<StructDataTypeFieldV1>,
+}
+
+impl ConfigurationSerialize for StructDataTypeConfigurationV1 {}
+
+/// A field of the `struct` data type (version 1.0).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct StructDataTypeFieldV1 {
+    /// The field name.
+    pub name: String,
+    /// The field data type.
+    pub data_type: MetadataV3,
+    /// The shape of a subarray field.
+    ///
+    /// The field is a scalar if [`None`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape: Option<Vec<u64>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn struct_fields() {
+        let config = r#"{"fields":[{"name":"x","data_type":"uint64"},{"name":"y","data_type":"float32","shape":[2,3]},{"name":"z","data_type":{"name":"numpy.datetime64","configuration":{"unit":"s","scale_factor":1}}}]}"#;
+        let configuration: StructDataTypeConfigurationV1 = serde_json::from_str(config).unwrap();
+        assert_eq!(configuration.fields.len(), 3);
+        assert_eq!(configuration.fields[0].data_type.name(), "uint64");
+        assert_eq!(configuration.fields[1].shape, Some(vec![2, 3]));
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), config);
+    }
+
+    #[test]
+    fn struct_nested() {
+        let config = r#"{"fields":[{"name":"inner","data_type":{"name":"struct","configuration":{"fields":[{"name":"a","data_type":"int8"}]}}}]}"#;
+        let configuration: StructDataTypeConfigurationV1 = serde_json::from_str(config).unwrap();
+        let inner: StructDataTypeConfigurationV1 = configuration.fields[0]
+            .data_type
+            .to_typed_configuration()
+            .unwrap();
+        assert_eq!(inner.fields[0].name, "a");
+    }
+
+    #[test]
+    fn struct_missing_fields() {
+        assert!(serde_json::from_str::<StructDataTypeConfigurationV1>("{}").is_err());
+    }
+}